@@ -1,11 +1,11 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::config::Config;
-use crate::skill::{self, Skill};
+use crate::skill::{self, DescriptionIssue, Skill};
 
 const MARKER_FILE: &str = ".managed-by-loadout";
 
@@ -19,6 +19,15 @@ pub enum Severity {
 }
 
 impl Severity {
+    pub fn parse_severity(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "error" => Some(Self::Error),
+            "warning" => Some(Self::Warning),
+            "info" => Some(Self::Info),
+            _ => None,
+        }
+    }
+
     pub fn color(&self) -> colored::Color {
         match self {
             Severity::Error => colored::Color::Red,
@@ -36,6 +45,66 @@ impl Severity {
     }
 }
 
+/// A mechanical rewrite of a SKILL.md file that resolves a finding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    /// The SKILL.md file to rewrite
+    pub path: PathBuf,
+    pub kind: FixKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixKind {
+    /// Append a newline to a file that lacks one
+    AppendTrailingNewline,
+    /// Replace the frontmatter `name` value
+    SetFrontmatterName(String),
+}
+
+impl Fix {
+    /// Transform file content, returning `None` when the fix no longer applies
+    pub fn apply_to(&self, content: &str) -> Option<String> {
+        match &self.kind {
+            FixKind::AppendTrailingNewline => {
+                if content.ends_with('\n') {
+                    None
+                } else {
+                    Some(format!("{}\n", content))
+                }
+            }
+            FixKind::SetFrontmatterName(name) => {
+                let mut lines: Vec<String> = content.lines().map(String::from).collect();
+                if lines.first().map(|l| l.trim()) != Some("---") {
+                    return None;
+                }
+                let end = lines.iter().skip(1).position(|l| l.trim() == "---")? + 1;
+                let name_line = lines[1..end].iter().position(|l| l.starts_with("name:"))? + 1;
+                lines[name_line] = format!("name: {}", name);
+
+                let mut rewritten = lines.join("\n");
+                if content.ends_with('\n') {
+                    rewritten.push('\n');
+                }
+                Some(rewritten)
+            }
+        }
+    }
+
+    /// Rewrite the file in place, returning whether anything changed
+    pub fn apply(&self) -> Result<bool> {
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        match self.apply_to(&content) {
+            Some(rewritten) => {
+                fs::write(&self.path, rewritten)
+                    .with_context(|| format!("Failed to write {}", self.path.display()))?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Finding {
     pub severity: Severity,
@@ -44,20 +113,50 @@ pub struct Finding {
     pub path: Option<PathBuf>,
     /// Key for suppression matching: "check-type:source:detail"
     pub suppress_key: String,
+    /// Automatic rewrite applied by `check --fix`
+    pub autofix: Option<Fix>,
+    /// 1-indexed line within `path` the finding points at
+    pub line: Option<usize>,
 }
 
 impl Finding {
-    fn error(message: impl Into<String>, fix: impl Into<String>, key: impl Into<String>) -> Self {
+    fn with_autofix(mut self, fix: Fix) -> Self {
+        self.autofix = Some(fix);
+        self
+    }
+
+    fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Stable identifier for the check that produced this finding
+    ///
+    /// Taken from the check-type prefix of the suppression key, e.g. `dangling`.
+    pub fn rule_id(&self) -> &str {
+        self.suppress_key
+            .split(':')
+            .next()
+            .unwrap_or(&self.suppress_key)
+    }
+
+    pub(crate) fn error(
+        message: impl Into<String>,
+        fix: impl Into<String>,
+        key: impl Into<String>,
+    ) -> Self {
         Self {
             severity: Severity::Error,
             message: message.into(),
             fix: fix.into(),
             path: None,
             suppress_key: key.into(),
+            autofix: None,
+            line: None,
         }
     }
 
-    fn error_with_path(
+    pub(crate) fn error_with_path(
         message: impl Into<String>,
         fix: impl Into<String>,
         key: impl Into<String>,
@@ -69,20 +168,28 @@ impl Finding {
             fix: fix.into(),
             path: Some(path),
             suppress_key: key.into(),
+            autofix: None,
+            line: None,
         }
     }
 
-    fn warning(message: impl Into<String>, fix: impl Into<String>, key: impl Into<String>) -> Self {
+    pub(crate) fn warning(
+        message: impl Into<String>,
+        fix: impl Into<String>,
+        key: impl Into<String>,
+    ) -> Self {
         Self {
             severity: Severity::Warning,
             message: message.into(),
             fix: fix.into(),
             path: None,
             suppress_key: key.into(),
+            autofix: None,
+            line: None,
         }
     }
 
-    fn warning_with_path(
+    pub(crate) fn warning_with_path(
         message: impl Into<String>,
         fix: impl Into<String>,
         key: impl Into<String>,
@@ -94,16 +201,41 @@ impl Finding {
             fix: fix.into(),
             path: Some(path),
             suppress_key: key.into(),
+            autofix: None,
+            line: None,
         }
     }
 
-    fn info(message: impl Into<String>, fix: impl Into<String>, key: impl Into<String>) -> Self {
+    pub(crate) fn info(
+        message: impl Into<String>,
+        fix: impl Into<String>,
+        key: impl Into<String>,
+    ) -> Self {
         Self {
             severity: Severity::Info,
             message: message.into(),
             fix: fix.into(),
             path: None,
             suppress_key: key.into(),
+            autofix: None,
+            line: None,
+        }
+    }
+
+    pub(crate) fn info_with_path(
+        message: impl Into<String>,
+        fix: impl Into<String>,
+        key: impl Into<String>,
+        path: PathBuf,
+    ) -> Self {
+        Self {
+            severity: Severity::Info,
+            message: message.into(),
+            fix: fix.into(),
+            path: Some(path),
+            suppress_key: key.into(),
+            autofix: None,
+            line: None,
         }
     }
 }
@@ -117,25 +249,14 @@ pub fn check(
 
     // Discover all skills across all sources
     let all_skills = skill::discover_all(&config.sources.skills)?;
-    let skill_map: HashMap<String, &Skill> = all_skills
-        .iter()
-        .map(|s| (s.frontmatter.name.clone(), s))
-        .collect();
+    let skill_map: HashMap<String, &Skill> =
+        all_skills.iter().map(|s| (s.name.clone(), s)).collect();
 
     // Build set of known skill names for filtering
     let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
 
     // Extract cross-references from all skills
-    let mut crossrefs: HashMap<String, Vec<skill::CrossRef>> = HashMap::new();
-    for skill in &all_skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
-        if !refs.is_empty() {
-            crossrefs.insert(skill.name.clone(), refs);
-        }
-    }
+    let crossrefs = skill::extract_all_references(&all_skills, &config.detection.detectors())?;
 
     // Check 1: Dangling references
     findings.extend(check_dangling_references(&crossrefs, &skill_map));
@@ -164,6 +285,48 @@ pub fn check(
     // Check 9: Untagged/unpipelined skills
     findings.extend(check_missing_metadata(&all_skills));
 
+    // Check 10: Missing trailing newline
+    findings.extend(check_trailing_newline(&all_skills)?);
+
+    // Check 11: Duplicate skill names across sources
+    findings.extend(check_duplicate_names(&all_skills));
+
+    // Check 12: Pipeline order consistency
+    findings.extend(check_pipeline_order(&all_skills, &known_skills));
+
+    // Check 13: Pipeline order collisions
+    findings.extend(check_pipeline_order_collisions(&all_skills));
+
+    // Check 14: Self-references
+    findings.extend(check_self_references(&crossrefs, &skill_map));
+
+    // Check 15: Empty or overlong descriptions
+    findings.extend(check_description_length(
+        &all_skills,
+        config.validation.max_description_len,
+    ));
+
+    // Check 16: Malformed argument hints
+    findings.extend(check_argument_hints(&all_skills));
+
+    // Check 17: Pipeline dependencies on skills with model invocation disabled
+    findings.extend(check_disabled_pipeline_dependencies(&all_skills));
+
+    // Check 18: Required body sections
+    findings.extend(check_required_sections(
+        &all_skills,
+        &config.check.required_sections,
+    )?);
+
+    // Check 19: Half-finished pipeline stages
+    findings.extend(check_incomplete_pipeline_stages(&all_skills));
+
+    // Check 20: References to deprecated skills
+    findings.extend(check_deprecated_references(&crossrefs, &skill_map));
+
+    // Check 21: Metadata paths to missing files
+    findings.extend(check_missing_metadata_files(&all_skills));
+
     // Sort by severity (errors first)
     findings.sort_by_key(|f| f.severity);
     findings.reverse(); // Reverse to get errors first
@@ -198,7 +361,7 @@ fn check_dangling_references(
     for (source_skill, refs) in crossrefs {
         for crossref in refs {
             if !skill_map.contains_key(&crossref.target) {
-                findings.push(Finding::error(
+                let mut finding = Finding::error(
                     format!(
                         "Skill '{}' references non-existent skill '{}' (line {})",
                         source_skill, crossref.target, crossref.line
@@ -208,9 +371,122 @@ fn check_dangling_references(
                         crossref.target, crossref.line
                     ),
                     format!("dangling:{}:{}", source_skill, crossref.target),
-                ));
+                )
+                .at_line(crossref.line);
+                if let Some(source) = skill_map.get(source_skill) {
+                    finding.path = Some(source.skill_file.clone());
+                }
+                findings.push(finding);
+            }
+        }
+    }
+
+    findings
+}
+
+/// Warn when a skill that is not itself deprecated references one that is
+///
+/// Reported once per (skill, deprecated target) pair, at the first line.
+fn check_deprecated_references(
+    crossrefs: &HashMap<String, Vec<skill::CrossRef>>,
+    skill_map: &HashMap<String, &Skill>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let is_deprecated = |name: &str| {
+        skill_map
+            .get(name)
+            .is_some_and(|s| s.frontmatter.deprecated.is_some())
+    };
+
+    let mut sources: Vec<&String> = crossrefs.keys().collect();
+    sources.sort();
+
+    for source_skill in sources {
+        if is_deprecated(source_skill) {
+            continue;
+        }
+
+        let mut refs: Vec<&skill::CrossRef> = crossrefs[source_skill]
+            .iter()
+            .filter(|r| !r.self_reference && is_deprecated(&r.target))
+            .collect();
+        refs.sort_by(|a, b| a.target.cmp(&b.target).then(a.line.cmp(&b.line)));
+        refs.dedup_by(|a, b| a.target == b.target);
+
+        for r in refs {
+            let replacement = skill_map[&r.target]
+                .frontmatter
+                .deprecated
+                .as_ref()
+                .and_then(|d| d.replacement.as_deref());
+            let fix = match replacement {
+                Some(replacement) => {
+                    format!("Reference '{}' instead of '{}'", replacement, r.target)
+                }
+                None => format!("Remove the reference to '{}'", r.target),
+            };
+
+            let mut finding = Finding::warning(
+                format!(
+                    "Skill '{}' references deprecated skill '{}'",
+                    source_skill, r.target
+                ),
+                fix,
+                format!("deprecated-reference:{}:{}", source_skill, r.target),
+            )
+            .at_line(r.line);
+            if let Some(source) = skill_map.get(source_skill) {
+                finding.path = Some(source.skill_file.clone());
             }
+            findings.push(finding);
+        }
+    }
+
+    findings
+}
+
+fn check_self_references(
+    crossrefs: &HashMap<String, Vec<skill::CrossRef>>,
+    skill_map: &HashMap<String, &Skill>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let mut sources: Vec<&String> = crossrefs.keys().collect();
+    sources.sort();
+
+    for source_skill in sources {
+        let mut lines: Vec<usize> = crossrefs[source_skill]
+            .iter()
+            .filter(|r| r.self_reference)
+            .map(|r| r.line)
+            .collect();
+        lines.sort();
+        lines.dedup();
+
+        let Some(&first) = lines.first() else {
+            continue;
+        };
+        let line_list: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        let label = if lines.len() == 1 { "line" } else { "lines" };
+
+        let mut finding = Finding::warning(
+            format!(
+                "Skill '{}' references itself ({} {})",
+                source_skill,
+                label,
+                line_list.join(", ")
+            ),
+            format!(
+                "Remove the reference to '{}' or point it at the intended skill",
+                source_skill
+            ),
+            format!("self-reference:{}", source_skill),
+        )
+        .at_line(first);
+        if let Some(source) = skill_map.get(source_skill) {
+            finding.path = Some(source.skill_file.clone());
         }
+        findings.push(finding);
     }
 
     findings
@@ -245,25 +521,37 @@ fn check_orphaned_skills(config: &Config, all_skills: &[Skill]) -> Vec<Finding>
     findings
 }
 
+/// Compare each skill's frontmatter name against its directory basename
+///
+/// The comparison is case-sensitive. Intentional mismatches can be allowlisted
+/// through `[check].ignore` with the `name-mismatch:<name>` key.
 fn check_name_directory_mismatch(all_skills: &[Skill]) -> Vec<Finding> {
     let mut findings = Vec::new();
 
     for skill in all_skills {
         if let Some(dir_name) = skill.path.file_name() {
-            if dir_name != skill.name.as_str() {
-                findings.push(Finding::error_with_path(
-                    format!(
-                        "Skill name '{}' does not match directory name '{}'",
-                        skill.name,
-                        dir_name.to_string_lossy()
-                    ),
-                    format!(
-                        "Rename directory to '{}' or update frontmatter name field",
-                        skill.name
-                    ),
-                    format!("name-mismatch:{}", skill.name),
-                    skill.path.clone(),
-                ));
+            let dir_name = dir_name.to_string_lossy();
+            if dir_name != skill.frontmatter.name {
+                findings.push(
+                    Finding::warning_with_path(
+                        format!(
+                            "Frontmatter name '{}' does not match directory name '{}' in {}",
+                            skill.frontmatter.name,
+                            dir_name,
+                            skill.skill_file.display()
+                        ),
+                        format!(
+                            "Set the frontmatter name to '{}' (`loadout check --fix`), or rename the directory to '{}'",
+                            dir_name, skill.frontmatter.name
+                        ),
+                        format!("name-mismatch:{}", skill.name),
+                        skill.skill_file.clone(),
+                    )
+                    .with_autofix(Fix {
+                        path: skill.skill_file.clone(),
+                        kind: FixKind::SetFrontmatterName(dir_name.into_owned()),
+                    }),
+                );
             }
         }
     }
@@ -374,6 +662,11 @@ fn check_placeholder_descriptions(all_skills: &[Skill]) -> Vec<Finding> {
     for skill in all_skills {
         let desc = &skill.frontmatter.description;
 
+        // Empty descriptions are reported by check_description_length
+        if desc.trim().is_empty() {
+            continue;
+        }
+
         if PLACEHOLDER_DESCRIPTIONS.iter().any(|p| desc.contains(p)) {
             findings.push(Finding::warning_with_path(
                 format!(
@@ -409,10 +702,97 @@ fn check_placeholder_descriptions(all_skills: &[Skill]) -> Vec<Finding> {
     findings
 }
 
-fn check_pipeline_integrity(all_skills: &[Skill], known_skills: &HashSet<String>) -> Vec<Finding> {
+fn check_description_length(all_skills: &[Skill], max_len: usize) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for skill in all_skills {
+        match skill.frontmatter.description_issue(max_len) {
+            Some(DescriptionIssue::Empty) => findings.push(Finding::error_with_path(
+                format!("Skill '{}' has an empty description", skill.name),
+                format!(
+                    "Edit {}/SKILL.md and write a description",
+                    skill.path.display()
+                ),
+                format!("empty-description:{}", skill.name),
+                skill.path.clone(),
+            )),
+            Some(DescriptionIssue::TooLong { len, max }) => {
+                findings.push(Finding::warning_with_path(
+                    format!(
+                        "Skill '{}' description is {} chars (max {})",
+                        skill.name, len, max
+                    ),
+                    format!(
+                        "Edit {}/SKILL.md and shorten the description",
+                        skill.path.display()
+                    ),
+                    format!("long-description:{}", skill.name),
+                    skill.path.clone(),
+                ))
+            }
+            None => {}
+        }
+    }
+
+    findings
+}
+
+fn check_disabled_pipeline_dependencies(all_skills: &[Skill]) -> Vec<Finding> {
+    let disabled: HashMap<&str, &Skill> = all_skills
+        .iter()
+        .filter(|s| s.frontmatter.disable_model_invocation == Some(true))
+        .map(|s| (s.name.as_str(), s))
+        .collect();
+
+    let mut findings = Vec::new();
+    for (dependent, dependency) in skill::pipeline_edges(all_skills) {
+        let Some(target) = disabled.get(dependency.as_str()) else {
+            continue;
+        };
+        findings.push(Finding::warning_with_path(
+            format!(
+                "Skill '{}' depends on '{}' in a pipeline, but '{}' disables model invocation",
+                dependent, dependency, dependency
+            ),
+            format!(
+                "Remove disable-model-invocation from {}/SKILL.md or drop it from the pipeline",
+                target.path.display()
+            ),
+            format!("disabled-dependency:{}:{}", dependent, dependency),
+            target.skill_file.clone(),
+        ));
+    }
+
+    findings
+}
+
+fn check_argument_hints(all_skills: &[Skill]) -> Vec<Finding> {
     let mut findings = Vec::new();
 
-    // Build a map of pipeline declarations: pipeline_name -> skill_name -> PipelineStage
+    for skill in all_skills {
+        let Some(hint) = &skill.frontmatter.argument_hint else {
+            continue;
+        };
+        if let Err(e) = skill::parse_argument_hint(hint) {
+            findings.push(Finding::warning_with_path(
+                format!(
+                    "Skill '{}' has malformed argument-hint token {}",
+                    skill.name, e
+                ),
+                "Use <required>, [optional], -f/--flag, and | between alternatives".to_string(),
+                format!("argument-hint:{}", skill.name),
+                skill.skill_file.clone(),
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Map of pipeline declarations: pipeline_name -> skill_name -> PipelineStage
+fn collect_pipelines(
+    all_skills: &[Skill],
+) -> HashMap<String, HashMap<String, &skill::PipelineStage>> {
     let mut pipeline_map: HashMap<String, HashMap<String, &skill::PipelineStage>> = HashMap::new();
     for skill in all_skills {
         if let Some(pipeline) = &skill.frontmatter.pipeline {
@@ -424,6 +804,12 @@ fn check_pipeline_integrity(all_skills: &[Skill], known_skills: &HashSet<String>
             }
         }
     }
+    pipeline_map
+}
+
+fn check_pipeline_integrity(all_skills: &[Skill], known_skills: &HashSet<String>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let pipeline_map = collect_pipelines(all_skills);
 
     // Check each pipeline for integrity
     for (pipeline_name, stages) in &pipeline_map {
@@ -465,78 +851,82 @@ fn check_pipeline_integrity(all_skills: &[Skill], known_skills: &HashSet<String>
                     }
                 }
             }
-
-            // Check for asymmetric after/before declarations
-            if let Some(after) = &stage.after {
-                for dep in after {
-                    if let Some(dep_stage) = stages.get(dep) {
-                        // dep should have before: [skill_name]
-                        let has_reciprocal = dep_stage
-                            .before
-                            .as_ref()
-                            .map(|b| b.contains(skill_name))
-                            .unwrap_or(false);
-                        if !has_reciprocal {
-                            findings.push(Finding::warning(
-                                format!(
-                                    "Pipeline '{}': '{}' declares after: ['{}'] but '{}' doesn't declare before: ['{}']",
-                                    pipeline_name, skill_name, dep, dep, skill_name
-                                ),
-                                format!(
-                                    "Add before: ['{}'] to skill '{}' in pipeline '{}'",
-                                    skill_name, dep, pipeline_name
-                                ),
-                                format!("pipeline-gap:{}:{}:{}", pipeline_name, skill_name, dep),
-                            ));
-                        }
-                    }
-                }
-            }
         }
+
+        findings.extend(check_pipeline_symmetry(pipeline_name, stages));
     }
 
     findings
 }
 
-fn check_missing_metadata(all_skills: &[Skill]) -> Vec<Finding> {
-    // Only check when the library is partially annotated — at least one skill
-    // has tags or pipeline. This avoids noise for users who haven't adopted
-    // the metadata scheme.
-    let any_annotated = all_skills.iter().any(|s| {
-        s.frontmatter
-            .tags
-            .as_ref()
-            .map(|t| !t.is_empty())
-            .unwrap_or(false)
-            || s.frontmatter.pipeline.is_some()
-    });
-
-    if !any_annotated {
-        return Vec::new();
-    }
-
+/// Check that after/before declarations between pipeline members agree
+///
+/// `b` declaring `after: [a]` and `a` declaring `before: [b]` state the same
+/// ordering; each should have its counterpart. An ordering declared in both
+/// directions (`a` before `b` and `b` before `a`) is a contradiction.
+/// Constraints naming non-members are left to the other pipeline checks.
+fn check_pipeline_symmetry(
+    pipeline_name: &str,
+    stages: &HashMap<String, &skill::PipelineStage>,
+) -> Vec<Finding> {
     let mut findings = Vec::new();
 
-    for skill in all_skills {
-        let has_tags = skill
-            .frontmatter
-            .tags
-            .as_ref()
-            .map(|t| !t.is_empty())
-            .unwrap_or(false);
-        let has_pipeline = skill.frontmatter.pipeline.is_some();
+    // (earlier, later) -> (declared by earlier's before, declared by later's after)
+    let mut declared: BTreeMap<(&str, &str), (bool, bool)> = BTreeMap::new();
+    for (skill_name, stage) in stages {
+        for dep in stage.after.iter().flatten() {
+            if stages.contains_key(dep) && dep != skill_name {
+                declared.entry((dep, skill_name)).or_default().1 = true;
+            }
+        }
+        for dep in stage.before.iter().flatten() {
+            if stages.contains_key(dep) && dep != skill_name {
+                declared.entry((skill_name, dep)).or_default().0 = true;
+            }
+        }
+    }
 
-        if !has_tags && !has_pipeline {
-            findings.push(Finding::info(
+    for (&(earlier, later), &(in_before, in_after)) in &declared {
+        if declared.contains_key(&(later, earlier)) {
+            if earlier < later {
+                findings.push(Finding::error(
+                    format!(
+                        "Pipeline '{}': '{}' and '{}' are each declared to run before the other",
+                        pipeline_name, earlier, later
+                    ),
+                    format!(
+                        "Keep one ordering between '{}' and '{}' and remove the other's after/before entries",
+                        earlier, later
+                    ),
+                    format!(
+                        "pipeline-contradiction:{}:{}:{}",
+                        pipeline_name, earlier, later
+                    ),
+                ));
+            }
+        } else if !in_before {
+            findings.push(Finding::warning(
                 format!(
-                    "Skill '{}' has no tags and isn't in any pipeline",
-                    skill.name
+                    "Pipeline '{}': '{}' declares after: ['{}'] but '{}' doesn't declare before: ['{}']",
+                    pipeline_name, later, earlier, earlier, later
                 ),
                 format!(
-                    "Add tags: [<tag>] or pipeline metadata to {}/SKILL.md",
-                    skill.path.display()
+                    "Add before: ['{}'] to skill '{}' in pipeline '{}'",
+                    later, earlier, pipeline_name
                 ),
-                format!("no-metadata:{}", skill.name),
+                format!("pipeline-gap:{}:{}:{}", pipeline_name, later, earlier),
+            ));
+        } else if !in_after {
+            findings.push(Finding::warning(
+                format!(
+                    "Pipeline '{}': '{}' declares before: ['{}'] but '{}' doesn't declare after: ['{}']",
+                    pipeline_name, earlier, later, later, earlier
+                ),
+                format!(
+                    "Add after: ['{}'] to skill '{}' in pipeline '{}'",
+                    earlier, later, pipeline_name
+                ),
+                format!("pipeline-gap:{}:{}:{}", pipeline_name, earlier, later),
             ));
         }
     }
@@ -544,32 +934,424 @@ fn check_missing_metadata(all_skills: &[Skill]) -> Vec<Finding> {
     findings
 }
 
-pub fn print_findings(findings: &[Finding]) {
-    if findings.is_empty() {
-        println!("{}", "No issues found.".green());
-        return;
-    }
+/// Check that after/before constraints agree with each pipeline's numeric order
+///
+/// A skill listed in another's `after` must have a lower order, and one listed
+/// in `before` a higher order. Constraints naming an existing skill that isn't
+/// part of the same pipeline are flagged separately.
+fn check_pipeline_order(all_skills: &[Skill], known_skills: &HashSet<String>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut reported: HashSet<String> = HashSet::new();
 
-    // Group by severity
-    let mut by_severity: HashMap<Severity, Vec<&Finding>> = HashMap::new();
-    for finding in findings {
-        by_severity
-            .entry(finding.severity)
-            .or_default()
-            .push(finding);
-    }
+    let mut pipelines: Vec<_> = collect_pipelines(all_skills).into_iter().collect();
+    pipelines.sort_by(|a, b| a.0.cmp(&b.0));
 
-    // Print in order: Error -> Warning -> Info
-    for severity in [Severity::Error, Severity::Warning, Severity::Info] {
-        if let Some(findings) = by_severity.get(&severity) {
-            println!(
-                "\n{} ({} found)",
-                severity.label().color(severity.color()).bold(),
-                findings.len()
-            );
+    for (pipeline_name, stages) in &pipelines {
+        let mut members: Vec<_> = stages.iter().collect();
+        members.sort_by(|a, b| a.0.cmp(b.0));
 
-            for finding in findings {
-                if let Some(path) = &finding.path {
+        for (skill_name, stage) in members {
+            // Normalize both directions to (earlier, later) pairs
+            let mut constraints: Vec<(&str, &str, &str)> = Vec::new();
+            for dep in stage.after.iter().flatten() {
+                constraints.push(("after", dep, skill_name));
+            }
+            for dep in stage.before.iter().flatten() {
+                constraints.push(("before", skill_name, dep));
+            }
+
+            for (field, earlier, later) in constraints {
+                let peer = if field == "after" { earlier } else { later };
+
+                let (Some(earlier_stage), Some(later_stage)) =
+                    (stages.get(earlier), stages.get(later))
+                else {
+                    // Missing skills are reported by the integrity check
+                    if known_skills.contains(peer)
+                        && reported
+                            .insert(format!("peer:{}:{}:{}", pipeline_name, skill_name, peer))
+                    {
+                        findings.push(Finding::warning(
+                            format!(
+                                "Pipeline '{}': skill '{}' declares {}: ['{}'] but '{}' isn't part of the pipeline",
+                                pipeline_name, skill_name, field, peer, peer
+                            ),
+                            format!(
+                                "Add pipeline '{}' to skill '{}', or remove it from the {} list",
+                                pipeline_name, peer, field
+                            ),
+                            format!("pipeline-peer:{}:{}:{}", pipeline_name, skill_name, peer),
+                        ));
+                    }
+                    continue;
+                };
+
+                if earlier_stage.order >= later_stage.order
+                    && reported.insert(format!("order:{}:{}:{}", pipeline_name, earlier, later))
+                {
+                    findings.push(Finding::error(
+                        format!(
+                            "Pipeline '{}': '{}' (order {}) must run before '{}' (order {}), but its order is not lower",
+                            pipeline_name, earlier, earlier_stage.order, later, later_stage.order
+                        ),
+                        format!(
+                            "Give '{}' a lower order than '{}', or drop the after/before constraint",
+                            earlier, later
+                        ),
+                        format!("pipeline-order:{}:{}:{}", pipeline_name, earlier, later),
+                    ));
+                }
+            }
+        }
+    }
+
+    findings
+}
+
+/// Report pipeline stages that share an order value
+///
+/// Shared orders are read as parallel alternatives, so this is informational:
+/// it surfaces accidental collisions without failing the check.
+fn check_pipeline_order_collisions(all_skills: &[Skill]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let mut pipelines: Vec<_> = collect_pipelines(all_skills).into_iter().collect();
+    pipelines.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (pipeline_name, stages) in &pipelines {
+        let mut by_order: HashMap<u32, Vec<&str>> = HashMap::new();
+        for (skill_name, stage) in stages {
+            by_order.entry(stage.order).or_default().push(skill_name);
+        }
+
+        let mut collisions: Vec<(u32, Vec<&str>)> = by_order
+            .into_iter()
+            .filter(|(_, skills)| skills.len() > 1)
+            .collect();
+        collisions.sort();
+
+        for (order, mut skills) in collisions {
+            skills.sort();
+            findings.push(Finding::info(
+                format!(
+                    "Pipeline '{}': {} share order {}",
+                    pipeline_name,
+                    skills.join(", "),
+                    order
+                ),
+                "Give each stage a distinct order unless they are parallel alternatives"
+                    .to_string(),
+                format!("pipeline-collision:{}:{}", pipeline_name, order),
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Report pipeline entries with a blank stage or a placeholder order of 0
+///
+/// Frontmatter parsing rejects both, so this backstops skills that skip it
+/// (such as entries loaded from the reference cache). An order of 0 is only
+/// suspicious when another member of the pipeline uses a positive order.
+fn check_incomplete_pipeline_stages(all_skills: &[Skill]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let mut pipelines: Vec<_> = collect_pipelines(all_skills).into_iter().collect();
+    pipelines.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (pipeline_name, stages) in &pipelines {
+        let any_positive = stages.values().any(|stage| stage.order > 0);
+
+        let mut members: Vec<_> = stages.iter().collect();
+        members.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (skill_name, stage) in members {
+            if stage.stage.trim().is_empty() {
+                findings.push(Finding::error(
+                    format!(
+                        "Pipeline '{}': skill '{}' has an empty stage",
+                        pipeline_name, skill_name
+                    ),
+                    format!(
+                        "Name the stage of '{}' in pipeline '{}'",
+                        skill_name, pipeline_name
+                    ),
+                    format!("pipeline-stage:{}:{}", pipeline_name, skill_name),
+                ));
+            }
+            if stage.order == 0 && any_positive {
+                findings.push(Finding::warning(
+                    format!(
+                        "Pipeline '{}': skill '{}' has order 0 while other stages use positive orders",
+                        pipeline_name, skill_name
+                    ),
+                    format!(
+                        "Give '{}' its position in pipeline '{}' (orders start at 1)",
+                        skill_name, pipeline_name
+                    ),
+                    format!("pipeline-zero-order:{}:{}", pipeline_name, skill_name),
+                ));
+            }
+        }
+    }
+
+    findings
+}
+
+fn check_missing_metadata(all_skills: &[Skill]) -> Vec<Finding> {
+    // Only check when the library is partially annotated — at least one skill
+    // has tags or pipeline. This avoids noise for users who haven't adopted
+    // the metadata scheme.
+    let any_annotated = all_skills.iter().any(|s| {
+        s.frontmatter
+            .tags
+            .as_ref()
+            .map(|t| !t.is_empty())
+            .unwrap_or(false)
+            || s.frontmatter.pipeline.is_some()
+    });
+
+    if !any_annotated {
+        return Vec::new();
+    }
+
+    let mut findings = Vec::new();
+
+    for skill in all_skills {
+        let has_tags = skill
+            .frontmatter
+            .tags
+            .as_ref()
+            .map(|t| !t.is_empty())
+            .unwrap_or(false);
+        let has_pipeline = skill.frontmatter.pipeline.is_some();
+
+        if !has_tags && !has_pipeline {
+            findings.push(Finding::info(
+                format!(
+                    "Skill '{}' has no tags and isn't in any pipeline",
+                    skill.name
+                ),
+                format!(
+                    "Add tags: [<tag>] or pipeline metadata to {}/SKILL.md",
+                    skill.path.display()
+                ),
+                format!("no-metadata:{}", skill.name),
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Extensions that mark a metadata value as a file path, alongside a `/`
+const ASSET_EXTENSIONS: &[&str] = &[
+    "sh", "bash", "py", "js", "ts", "rb", "md", "txt", "json", "yaml", "yml", "toml", "html",
+    "csv", "tmpl", "j2",
+];
+
+/// Whether a metadata value looks like a path to a supporting file
+///
+/// Single words containing `/` or ending in a known extension count; URLs
+/// and anything with whitespace don't.
+fn is_path_like(value: &str) -> bool {
+    let value = value.trim();
+    if value.is_empty() || value.contains("://") || value.contains(char::is_whitespace) {
+        return false;
+    }
+
+    value.contains('/')
+        || Path::new(value)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ASSET_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+fn check_missing_metadata_files(all_skills: &[Skill]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for skill in all_skills {
+        // Archived skills have no directory on disk to resolve paths against
+        if skill.content.is_some() {
+            continue;
+        }
+        let Some(metadata) = &skill.frontmatter.metadata else {
+            continue;
+        };
+
+        let mut keys: Vec<&String> = metadata.keys().collect();
+        keys.sort();
+        for key in keys {
+            let value = metadata[key].trim();
+            if !is_path_like(value) || skill.path.join(value).exists() {
+                continue;
+            }
+            findings.push(Finding::warning_with_path(
+                format!(
+                    "Skill '{}' metadata '{}' points to missing file '{}'",
+                    skill.name, key, value
+                ),
+                format!(
+                    "Create {} or update metadata.{}",
+                    skill.path.join(value).display(),
+                    key
+                ),
+                format!("missing-file:{}:{}", skill.name, key),
+                skill.skill_file.clone(),
+            ));
+        }
+    }
+
+    findings
+}
+
+fn check_trailing_newline(all_skills: &[Skill]) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    for skill in all_skills {
+        let content = skill.read_content()?;
+        if !content.ends_with('\n') {
+            let finding = Finding::info_with_path(
+                format!(
+                    "Skill '{}' SKILL.md does not end with a newline",
+                    skill.name
+                ),
+                "Run `loadout check --fix` to append one".to_string(),
+                format!("trailing-newline:{}", skill.name),
+                skill.skill_file.clone(),
+            );
+            // Skills read from an archive can't be rewritten in place
+            findings.push(match skill.content {
+                Some(_) => finding,
+                None => finding.with_autofix(Fix {
+                    path: skill.skill_file.clone(),
+                    kind: FixKind::AppendTrailingNewline,
+                }),
+            });
+        }
+    }
+
+    Ok(findings)
+}
+
+fn check_required_sections(all_skills: &[Skill], required: &[String]) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+    if required.is_empty() {
+        return Ok(findings);
+    }
+
+    for skill in all_skills {
+        let headings: HashSet<String> = skill
+            .sections()?
+            .into_iter()
+            .map(|(_, heading, _)| heading.to_lowercase())
+            .collect();
+
+        for section in required {
+            if !headings.contains(&section.to_lowercase()) {
+                findings.push(Finding::warning_with_path(
+                    format!(
+                        "Skill '{}' is missing required section '{}'",
+                        skill.name, section
+                    ),
+                    format!(
+                        "Add a '## {}' section to {}",
+                        section,
+                        skill.skill_file.display()
+                    ),
+                    format!("missing-section:{}:{}", skill.name, section),
+                    skill.skill_file.clone(),
+                ));
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+fn check_duplicate_names(all_skills: &[Skill]) -> Vec<Finding> {
+    let mut by_name: HashMap<&str, Vec<&PathBuf>> = HashMap::new();
+    for skill in all_skills {
+        by_name
+            .entry(skill.name.as_str())
+            .or_default()
+            .push(&skill.skill_file);
+    }
+
+    let mut duplicates: Vec<(&str, Vec<&PathBuf>)> = by_name
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .collect();
+    duplicates.sort();
+
+    duplicates
+        .into_iter()
+        .map(|(name, paths)| {
+            let listed: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+            Finding::error_with_path(
+                format!(
+                    "Skill '{}' is defined in {} places: {}",
+                    name,
+                    paths.len(),
+                    listed.join(", ")
+                ),
+                "Rename or remove the duplicates; the first source in [sources].skills wins"
+                    .to_string(),
+                format!("duplicate:{}", name),
+                // Point at the first shadowed copy
+                paths[1].clone(),
+            )
+        })
+        .collect()
+}
+
+/// Apply automatic fixes, returning how many files were rewritten
+///
+/// Error-level fixes change skill identity, so they only apply with `force`.
+pub fn apply_fixes(findings: &[Finding], force: bool) -> Result<usize> {
+    let mut fixed = 0;
+
+    for finding in findings {
+        if let Some(autofix) = &finding.autofix {
+            if finding.severity == Severity::Error && !force {
+                continue;
+            }
+            if autofix.apply()? {
+                fixed += 1;
+            }
+        }
+    }
+
+    Ok(fixed)
+}
+
+pub fn print_findings(findings: &[Finding]) {
+    if findings.is_empty() {
+        println!("{}", "No issues found.".green());
+        return;
+    }
+
+    // Group by severity
+    let mut by_severity: HashMap<Severity, Vec<&Finding>> = HashMap::new();
+    for finding in findings {
+        by_severity
+            .entry(finding.severity)
+            .or_default()
+            .push(finding);
+    }
+
+    // Print in order: Error -> Warning -> Info
+    for severity in [Severity::Error, Severity::Warning, Severity::Info] {
+        if let Some(findings) = by_severity.get(&severity) {
+            println!(
+                "\n{} ({} found)",
+                severity.label().color(severity.color()).bold(),
+                findings.len()
+            );
+
+            for finding in findings {
+                if let Some(path) = &finding.path {
                     println!(
                         "  {} {}",
                         "•".color(severity.color()),
@@ -591,11 +1373,124 @@ pub fn print_findings(findings: &[Finding]) {
         }
     }
 
+    let fixable = findings.iter().filter(|f| f.autofix.is_some()).count();
+    if fixable > 0 {
+        println!(
+            "\n{}",
+            format!(
+                "{} finding(s) can be fixed automatically with `loadout check --fix`",
+                fixable
+            )
+            .dimmed()
+        );
+    }
+
     println!();
 }
 
-pub fn exit_code(findings: &[Finding]) -> i32 {
-    if findings.iter().any(|f| f.severity == Severity::Error) {
+/// SARIF base id that artifact URIs inside the checked root are relative to
+const SARIF_SRCROOT: &str = "%SRCROOT%";
+
+/// Serialize findings as a SARIF 2.1.0 log for code-scanning integrations
+///
+/// Paths under `root` (the repository checkout) become URIs relative to
+/// `%SRCROOT%` so code scanning can map them to files; other paths stay
+/// absolute `file://` URIs.
+pub fn to_sarif(findings: &[Finding], root: &Path) -> String {
+    let mut rule_ids: Vec<&str> = findings.iter().map(|f| f.rule_id()).collect();
+    rule_ids.sort();
+    rule_ids.dedup();
+    let rules: Vec<serde_json::Value> = rule_ids
+        .iter()
+        .map(|id| serde_json::json!({ "id": id }))
+        .collect();
+
+    let results: Vec<serde_json::Value> = findings
+        .iter()
+        .map(|finding| {
+            let level = match finding.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "note",
+            };
+            let mut result = serde_json::json!({
+                "ruleId": finding.rule_id(),
+                "level": level,
+                "message": {
+                    "text": format!("{}. {}", finding.message, finding.fix),
+                },
+            });
+
+            if let Some(path) = &finding.path {
+                // Skill-level findings carry the skill directory; point at its SKILL.md
+                let file = if path.is_dir() && path.join("SKILL.md").is_file() {
+                    path.join("SKILL.md")
+                } else {
+                    path.clone()
+                };
+                let artifact = match file.strip_prefix(root) {
+                    Ok(relative) => serde_json::json!({
+                        "uri": encode_uri_path(relative),
+                        "uriBaseId": SARIF_SRCROOT,
+                    }),
+                    Err(_) => serde_json::json!({ "uri": file_uri(&file) }),
+                };
+                let mut location = serde_json::json!({ "artifactLocation": artifact });
+                if let Some(line) = finding.line {
+                    location["region"] = serde_json::json!({ "startLine": line });
+                }
+                result["locations"] = serde_json::json!([{ "physicalLocation": location }]);
+            }
+
+            result
+        })
+        .collect();
+
+    let log = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "loadout",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "originalUriBaseIds": {
+                SARIF_SRCROOT: {
+                    "uri": format!("file://{}/", encode_uri_path(root).trim_end_matches('/')),
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
+/// `file://` URI of an absolute path
+fn file_uri(path: &Path) -> String {
+    format!("file://{}", encode_uri_path(path))
+}
+
+/// Join a path's components with `/`, percent-encoding bytes URIs don't allow
+fn encode_uri_path(path: &Path) -> String {
+    let mut encoded = String::new();
+    for byte in path.to_string_lossy().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Exit non-zero when any finding is at or above `threshold`
+pub fn exit_code(findings: &[Finding], threshold: Severity) -> i32 {
+    if findings.iter().any(|f| f.severity >= threshold) {
         1
     } else {
         0
@@ -628,64 +1523,387 @@ mod tests {
                 compatibility: None,
                 metadata: None,
                 tags: None,
+                aliases: None,
+                deprecated: None,
                 pipeline: None,
             },
+            content: None,
+        }
+    }
+
+    // Helper to create a skill with one pipeline stage
+    fn pipeline_skill(
+        name: &str,
+        pipeline: &str,
+        order: u32,
+        after: &[&str],
+        before: &[&str],
+    ) -> Skill {
+        let to_list = |names: &[&str]| {
+            (!names.is_empty()).then(|| names.iter().map(|n| n.to_string()).collect())
+        };
+
+        let mut skill = test_skill(name, "Pipeline skill");
+        let mut stages = HashMap::new();
+        stages.insert(
+            pipeline.to_string(),
+            skill::PipelineStage {
+                stage: format!("stage-{}", order),
+                order,
+                after: to_list(after),
+                before: to_list(before),
+            },
+        );
+        skill.frontmatter.pipeline = Some(stages);
+        skill
+    }
+
+    #[test]
+    fn should_warn_when_active_skill_references_deprecated_one() {
+        // Given: draft and old-draft both reference old-edit, which is deprecated
+        let mut old_edit = test_skill("old-edit", "Old editor");
+        old_edit.frontmatter.deprecated = Some(skill::DeprecationInfo {
+            replacement: Some("edit".to_string()),
+            ..Default::default()
+        });
+        let mut old_draft = test_skill("old-draft", "Old drafter");
+        old_draft.frontmatter.deprecated = Some(Default::default());
+        let draft = test_skill("draft", "Drafter");
+        let skills = [old_edit, old_draft, draft];
+        let skill_map: HashMap<String, &Skill> =
+            skills.iter().map(|s| (s.name.clone(), s)).collect();
+
+        let crossref = |target: &str, line: usize| skill::CrossRef {
+            target: target.to_string(),
+            line,
+            method: skill::DetectionMethod::XmlCrossref,
+            self_reference: false,
+        };
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "draft".to_string(),
+            vec![crossref("old-edit", 7), crossref("old-edit", 3)],
+        );
+        crossrefs.insert("old-draft".to_string(), vec![crossref("old-edit", 2)]);
+
+        // When
+        let findings = check_deprecated_references(&crossrefs, &skill_map);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(
+            findings[0].suppress_key,
+            "deprecated-reference:draft:old-edit"
+        );
+        assert_eq!(findings[0].line, Some(3));
+        assert!(findings[0].fix.contains("'edit'"));
+    }
+
+    #[test]
+    fn should_flag_empty_pipeline_stage() {
+        // Given
+        let mut draft = pipeline_skill("draft", "release", 1, &[], &[]);
+        if let Some(stages) = draft.frontmatter.pipeline.as_mut() {
+            stages.get_mut("release").unwrap().stage = "  ".to_string();
         }
+        let skills = vec![draft, pipeline_skill("publish", "release", 2, &[], &[])];
+
+        // When
+        let findings = check_incomplete_pipeline_stages(&skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(findings[0].suppress_key, "pipeline-stage:release:draft");
+    }
+
+    #[test]
+    fn should_warn_about_zero_order_among_positive_siblings() {
+        // Given: release has a zero-order outlier, staging is all zeros
+        let skills = vec![
+            pipeline_skill("draft", "release", 0, &[], &[]),
+            pipeline_skill("edit", "release", 1, &[], &[]),
+            pipeline_skill("publish", "release", 2, &[], &[]),
+            pipeline_skill("stage", "staging", 0, &[], &[]),
+        ];
+
+        // When
+        let findings = check_incomplete_pipeline_stages(&skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("'release'"));
+        assert_eq!(
+            findings[0].suppress_key,
+            "pipeline-zero-order:release:draft"
+        );
+    }
+
+    #[test]
+    fn should_detect_dangling_references() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-a".to_string(),
+            vec![skill::CrossRef {
+                target: "nonexistent".to_string(),
+                line: 10,
+                method: skill::DetectionMethod::XmlCrossref,
+                self_reference: false,
+            }],
+        );
+
+        let skill_map: HashMap<String, &Skill> = HashMap::new();
+
+        // When
+        let findings = check_dangling_references(&crossrefs, &skill_map);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].message.contains("nonexistent"));
+        assert!(findings[0].fix.contains("loadout new nonexistent"));
+    }
+
+    #[test]
+    fn should_report_one_warning_for_self_referencing_skill() {
+        // Given
+        let content = "<see ref=\"skill-a\">Me</see>\nInvoke the skill-a skill again.\n<see ref=\"skill-b\">Other</see>\n";
+        let known: HashSet<String> = ["skill-a", "skill-b"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let refs = skill::extract_references_with_filter(
+            content,
+            "skill-a",
+            Some(&known),
+            None,
+            &skill::builtin_detectors(None),
+        );
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), refs);
+        let skill_map: HashMap<String, &Skill> = HashMap::new();
+
+        // When
+        let findings = check_self_references(&crossrefs, &skill_map);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(
+            findings[0].message,
+            "Skill 'skill-a' references itself (lines 1, 2)"
+        );
+        assert_eq!(findings[0].line, Some(1));
+        assert_eq!(findings[0].rule_id(), "self-reference");
+    }
+
+    #[test]
+    fn should_resolve_references_to_prefixed_skills() {
+        // Given: a prefixed source where bar references a/foo
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for (name, body) in [("foo", ""), ("bar", "<see ref=\"a/foo\">x</see>\n")] {
+            fs::create_dir_all(source.join(name)).unwrap();
+            fs::write(
+                source.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Prefixed skill\n---\n{body}"),
+            )
+            .unwrap();
+        }
+        let config = Config {
+            sources: crate::config::Sources {
+                skills: vec![crate::config::SkillSource {
+                    path: source,
+                    prefix: Some("a".to_string()),
+                }],
+                templates: vec![],
+            },
+            global: crate::config::Global {
+                targets: vec![],
+                skills: vec!["a/foo".to_string(), "a/bar".to_string()],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let findings = check(&config, None, false).unwrap();
+
+        // Then
+        let errors: Vec<&str> = findings
+            .iter()
+            .filter(|f| f.severity == Severity::Error)
+            .map(|f| f.message.as_str())
+            .collect();
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+    }
+
+    #[test]
+    fn should_detect_orphaned_skills() {
+        // Given
+        let config = Config {
+            sources: crate::config::Sources {
+                skills: vec![PathBuf::from("/test/skills").into()],
+                templates: vec![],
+            },
+            global: crate::config::Global {
+                targets: vec![],
+                skills: vec!["skill-a".to_string()],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        let skills = vec![
+            test_skill("skill-a", "Active skill"),
+            test_skill("skill-b", "Orphaned skill"),
+        ];
+
+        // When
+        let findings = check_orphaned_skills(&config, &skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("skill-b"));
+        assert!(findings[0].fix.contains("loadout.toml"));
+    }
+
+    #[test]
+    fn should_treat_slashes_and_known_extensions_as_paths() {
+        assert!(is_path_like("scripts/build"));
+        assert!(is_path_like("run.sh"));
+        assert!(is_path_like("Template.J2"));
+        assert!(!is_path_like("1.0.2"));
+        assert!(!is_path_like("https://example.com/docs"));
+        assert!(!is_path_like("see the docs/ folder"));
+        assert!(!is_path_like("team-writing"));
+    }
+
+    #[test]
+    fn should_warn_about_metadata_paths_to_missing_files() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills/draft");
+        fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        fs::write(skill_dir.join("scripts/lint.sh"), "#!/bin/sh\n").unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: draft\ndescription: Draft a post\nmetadata:\n  lint: scripts/lint.sh\n  template: templates/post.md\n  owner: writing\n---\n",
+        )
+        .unwrap();
+        let skills = skill::discover_all(&[temp.path().join("skills").into()]).unwrap();
+
+        // When
+        let findings = check_missing_metadata_files(&skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("'template'"));
+        assert!(findings[0].message.contains("templates/post.md"));
+        assert_eq!(findings[0].suppress_key, "missing-file:draft:template");
+    }
+
+    #[test]
+    fn should_detect_duplicate_names_across_sources() {
+        // Given: two source directories both defining shared-skill
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut sources = Vec::new();
+        for source in ["team", "personal"] {
+            let skill_dir = temp.path().join(source).join("shared-skill");
+            fs::create_dir_all(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                "---\nname: shared-skill\ndescription: Shared between sources\n---\n",
+            )
+            .unwrap();
+            sources.push(temp.path().join(source).into());
+        }
+        let skills = skill::discover_all(&sources).unwrap();
+
+        // When
+        let findings = check_duplicate_names(&skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].message.contains("team"));
+        assert!(findings[0].message.contains("personal"));
+    }
+
+    #[test]
+    fn should_not_report_same_name_under_different_prefixes() {
+        // Given: two prefixed sources both shipping foo
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut sources = Vec::new();
+        for prefix in ["a", "b"] {
+            let skill_dir = temp.path().join(prefix).join("foo");
+            fs::create_dir_all(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                "---\nname: foo\ndescription: Vendored skill\n---\n",
+            )
+            .unwrap();
+            sources.push(crate::config::SkillSource {
+                path: temp.path().join(prefix),
+                prefix: Some(prefix.to_string()),
+            });
+        }
+        let skills = skill::discover_all(&sources).unwrap();
+
+        // When
+        let findings = check_duplicate_names(&skills);
+
+        // Then
+        assert!(findings.is_empty());
     }
 
     #[test]
-    fn should_detect_dangling_references() {
+    fn should_warn_when_frontmatter_name_differs_from_directory() {
         // Given
-        let mut crossrefs = HashMap::new();
-        crossrefs.insert(
-            "skill-a".to_string(),
-            vec![skill::CrossRef {
-                target: "nonexistent".to_string(),
-                line: 10,
-                method: skill::DetectionMethod::XmlCrossref,
-            }],
-        );
-
-        let skill_map: HashMap<String, &Skill> = HashMap::new();
+        let mut skill = test_skill("foobar", "Mismatched skill");
+        skill.path = PathBuf::from("/test/skills/foo-bar");
+        skill.skill_file = PathBuf::from("/test/skills/foo-bar/SKILL.md");
+        let matching = test_skill("skill-a", "Matching skill");
 
         // When
-        let findings = check_dangling_references(&crossrefs, &skill_map);
+        let findings = check_name_directory_mismatch(&[skill, matching]);
 
         // Then
         assert_eq!(findings.len(), 1);
-        assert_eq!(findings[0].severity, Severity::Error);
-        assert!(findings[0].message.contains("nonexistent"));
-        assert!(findings[0].fix.contains("loadout new nonexistent"));
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("'foobar'"));
+        assert!(findings[0].message.contains("'foo-bar'"));
+        assert!(findings[0]
+            .message
+            .contains("/test/skills/foo-bar/SKILL.md"));
+        assert_eq!(
+            findings[0].autofix.as_ref().map(|f| &f.kind),
+            Some(&FixKind::SetFrontmatterName("foo-bar".to_string()))
+        );
     }
 
     #[test]
-    fn should_detect_orphaned_skills() {
+    fn should_treat_name_case_difference_as_mismatch() {
         // Given
-        let config = Config {
-            sources: crate::config::Sources {
-                skills: vec![PathBuf::from("/test/skills")],
-            },
-            global: crate::config::Global {
-                targets: vec![],
-                skills: vec!["skill-a".to_string()],
-            },
-            projects: HashMap::new(),
-            check: Default::default(),
-        };
-
-        let skills = vec![
-            test_skill("skill-a", "Active skill"),
-            test_skill("skill-b", "Orphaned skill"),
-        ];
+        let mut skill = test_skill("foo-bar", "Case mismatch");
+        skill.path = PathBuf::from("/test/skills/Foo-Bar");
 
         // When
-        let findings = check_orphaned_skills(&config, &skills);
+        let findings = check_name_directory_mismatch(&[skill]);
 
         // Then
         assert_eq!(findings.len(), 1);
-        assert_eq!(findings[0].severity, Severity::Warning);
-        assert!(findings[0].message.contains("skill-b"));
-        assert!(findings[0].fix.contains("loadout.toml"));
     }
 
     #[test]
@@ -706,6 +1924,106 @@ mod tests {
         assert!(findings.iter().any(|f| f.message.contains("skill-b")));
     }
 
+    #[test]
+    fn should_warn_about_missing_required_sections() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_file = temp.path().join("SKILL.md");
+        fs::write(
+            &skill_file,
+            "---\nname: skill-a\ndescription: Test\n---\n# Skill A\n\n## usage\n\nRun it.\n",
+        )
+        .unwrap();
+        let mut skill = test_skill("skill-a", "A proper description");
+        skill.skill_file = skill_file;
+        let required = vec!["Usage".to_string(), "Examples".to_string()];
+
+        // When
+        let findings = check_required_sections(&[skill], &required).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(findings[0].suppress_key, "missing-section:skill-a:Examples");
+    }
+
+    #[test]
+    fn should_flag_empty_and_overlong_descriptions() {
+        // Given
+        let skills = vec![
+            test_skill("skill-a", ""),
+            test_skill("skill-b", "A proper description"),
+            test_skill("skill-c", &"a".repeat(30)),
+        ];
+
+        // When
+        let findings = check_description_length(&skills, 20);
+
+        // Then
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(findings[0].suppress_key, "empty-description:skill-a");
+        assert_eq!(findings[1].severity, Severity::Warning);
+        assert!(findings[1].message.contains("30 chars (max 20)"));
+    }
+
+    #[test]
+    fn should_flag_pipeline_dependency_with_model_invocation_disabled() {
+        // Given: publish runs after draft, which disables model invocation
+        use crate::skill::frontmatter::PipelineStage;
+
+        let mut draft = test_skill("draft", "A proper description");
+        draft.frontmatter.disable_model_invocation = Some(true);
+        let mut publish = test_skill("publish", "A proper description");
+        publish.frontmatter.pipeline = Some(HashMap::from([(
+            "blog".to_string(),
+            PipelineStage {
+                stage: "publish".to_string(),
+                order: 2,
+                after: Some(vec!["draft".to_string()]),
+                before: None,
+            },
+        )]));
+
+        // When
+        let findings = check_disabled_pipeline_dependencies(&[draft, publish]);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(
+            findings[0].suppress_key,
+            "disabled-dependency:publish:draft"
+        );
+        assert!(findings[0].message.contains("'publish' depends on 'draft'"));
+    }
+
+    #[test]
+    fn should_flag_malformed_argument_hint() {
+        // Given
+        let mut valid = test_skill("skill-a", "A proper description");
+        valid.frontmatter.argument_hint = Some("<file> [mode]".to_string());
+        let mut malformed = test_skill("skill-b", "A proper description");
+        malformed.frontmatter.argument_hint = Some("<file] [mode]".to_string());
+
+        // When
+        let findings = check_argument_hints(&[valid, malformed]);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].suppress_key, "argument-hint:skill-b");
+        assert!(findings[0].message.contains("'<file]'"));
+    }
+
+    #[test]
+    fn should_not_report_empty_description_as_short() {
+        // Given
+        let skills = vec![test_skill("skill-a", "")];
+
+        // When/Then
+        assert!(check_placeholder_descriptions(&skills).is_empty());
+    }
+
     #[test]
     fn should_detect_pipeline_integrity_issues() {
         // Given: skill-a declares after: [skill-b] but skill-b doesn't declare before: [skill-a]
@@ -730,6 +2048,8 @@ mod tests {
                     compatibility: None,
                     metadata: None,
                     tags: None,
+                    aliases: None,
+                    deprecated: None,
                     pipeline: Some({
                         let mut m = HashMap::new();
                         m.insert(
@@ -744,6 +2064,7 @@ mod tests {
                         m
                     }),
                 },
+                content: None,
             },
             Skill {
                 name: "skill-b".to_string(),
@@ -763,6 +2084,8 @@ mod tests {
                     compatibility: None,
                     metadata: None,
                     tags: None,
+                    aliases: None,
+                    deprecated: None,
                     pipeline: Some({
                         let mut m = HashMap::new();
                         m.insert(
@@ -777,6 +2100,7 @@ mod tests {
                         m
                     }),
                 },
+                content: None,
             },
         ];
 
@@ -791,6 +2115,164 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn should_accept_pipeline_whose_constraints_name_members() {
+        // Given
+        let skills = vec![
+            pipeline_skill("build", "release", 1, &[], &["deploy"]),
+            pipeline_skill("deploy", "release", 2, &["build"], &[]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let mut findings = check_pipeline_integrity(&skills, &known_skills);
+        findings.extend(check_pipeline_order(&skills, &known_skills));
+
+        // Then
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn should_flag_before_without_matching_after() {
+        // Given: build runs before deploy, but deploy doesn't say so
+        let skills = vec![
+            pipeline_skill("build", "release", 1, &[], &["deploy"]),
+            pipeline_skill("deploy", "release", 2, &[], &[]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_integrity(&skills, &known_skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(
+            findings[0].suppress_key,
+            "pipeline-gap:release:build:deploy"
+        );
+        assert!(findings[0]
+            .message
+            .contains("doesn't declare after: ['build']"));
+    }
+
+    #[test]
+    fn should_flag_contradicting_before_declarations_once() {
+        // Given: build and deploy each claim to run before the other
+        let skills = vec![
+            pipeline_skill("build", "release", 1, &[], &["deploy"]),
+            pipeline_skill("deploy", "release", 2, &["build"], &["build"]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_integrity(&skills, &known_skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(
+            findings[0].suppress_key,
+            "pipeline-contradiction:release:build:deploy"
+        );
+    }
+
+    #[test]
+    fn should_flag_after_naming_nonexistent_skill_once() {
+        // Given: deploy runs after a skill no source provides
+        let skills = vec![pipeline_skill(
+            "deploy",
+            "release",
+            2,
+            &["nonexistent"],
+            &[],
+        )];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let mut findings = check_pipeline_integrity(&skills, &known_skills);
+        findings.extend(check_pipeline_order(&skills, &known_skills));
+
+        // Then: reported as missing, not again as a non-member
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(
+            findings[0].suppress_key,
+            "pipeline-missing:release:deploy:nonexistent"
+        );
+    }
+
+    #[test]
+    fn should_report_order_contradicting_after_constraint_once() {
+        // Given: deploy runs after build, yet build has the higher order
+        let skills = vec![
+            pipeline_skill("build", "release", 3, &[], &["deploy"]),
+            pipeline_skill("deploy", "release", 2, &["build"], &[]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_order(&skills, &known_skills);
+
+        // Then: both declarations describe the same pair
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].message.contains("'build' (order 3)"));
+        assert!(findings[0].message.contains("'deploy' (order 2)"));
+    }
+
+    #[test]
+    fn should_accept_order_consistent_with_constraints() {
+        // Given
+        let skills = vec![
+            pipeline_skill("build", "release", 1, &[], &["deploy"]),
+            pipeline_skill("deploy", "release", 2, &["build"], &[]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_order(&skills, &known_skills);
+
+        // Then
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn should_flag_constraint_on_skill_outside_pipeline() {
+        // Given: lint exists but only belongs to another pipeline
+        let skills = vec![
+            pipeline_skill("deploy", "release", 2, &["lint"], &[]),
+            pipeline_skill("lint", "ci", 1, &[], &[]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_order(&skills, &known_skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("isn't part of the pipeline"));
+    }
+
+    #[test]
+    fn should_report_pipeline_order_collisions() {
+        // Given: two skills both at order 2 in the same pipeline
+        let skills = vec![
+            pipeline_skill("build", "release", 1, &[], &[]),
+            pipeline_skill("deploy", "release", 2, &[], &[]),
+            pipeline_skill("notify", "release", 2, &[], &[]),
+        ];
+
+        // When
+        let findings = check_pipeline_order_collisions(&skills);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert!(findings[0].message.contains("deploy, notify share order 2"));
+    }
+
     #[test]
     fn should_detect_missing_metadata_when_library_is_partially_annotated() {
         // Given: one tagged skill and one with no metadata
@@ -814,8 +2296,11 @@ mod tests {
                 compatibility: None,
                 metadata: None,
                 tags: Some(vec!["example".to_string()]),
+                aliases: None,
+                deprecated: None,
                 pipeline: None,
             },
+            content: None,
         };
 
         let skills = vec![
@@ -857,6 +2342,7 @@ mod tests {
                 target: "missing".to_string(),
                 line: 5,
                 method: skill::DetectionMethod::XmlCrossref,
+                self_reference: false,
             }],
         );
 
@@ -886,8 +2372,176 @@ mod tests {
         let no_findings = vec![];
 
         // When/Then
-        assert_eq!(exit_code(&findings_with_errors), 1);
-        assert_eq!(exit_code(&findings_warnings_only), 0);
-        assert_eq!(exit_code(&no_findings), 0);
+        assert_eq!(exit_code(&findings_with_errors, Severity::Error), 1);
+        assert_eq!(exit_code(&findings_warnings_only, Severity::Error), 0);
+        assert_eq!(exit_code(&no_findings, Severity::Error), 0);
+    }
+
+    #[test]
+    fn should_fail_on_warnings_when_threshold_is_warning() {
+        // Given
+        let findings_warnings_only = vec![Finding::warning(
+            "Something suspicious",
+            "Check it",
+            "test:warning",
+        )];
+        let findings_info_only = vec![Finding::info(
+            "Just so you know",
+            "Consider it",
+            "test:info",
+        )];
+
+        // When/Then
+        assert_eq!(exit_code(&findings_warnings_only, Severity::Error), 0);
+        assert_eq!(exit_code(&findings_warnings_only, Severity::Warning), 1);
+        assert_eq!(exit_code(&findings_info_only, Severity::Warning), 0);
+        assert_eq!(exit_code(&findings_info_only, Severity::Info), 1);
+    }
+
+    #[test]
+    fn should_parse_severity_case_insensitive() {
+        // Given/When/Then
+        assert_eq!(Severity::parse_severity("ERROR"), Some(Severity::Error));
+        assert_eq!(Severity::parse_severity("warning"), Some(Severity::Warning));
+        assert_eq!(Severity::parse_severity("info"), Some(Severity::Info));
+        assert!(Severity::parse_severity("fatal").is_none());
+    }
+
+    #[test]
+    fn should_append_trailing_newline_fix() {
+        // Given
+        let fix = Fix {
+            path: PathBuf::from("/test/skills/skill-a/SKILL.md"),
+            kind: FixKind::AppendTrailingNewline,
+        };
+
+        // When/Then
+        assert_eq!(
+            fix.apply_to("---\nname: skill-a\n---\nBody"),
+            Some("---\nname: skill-a\n---\nBody\n".to_string())
+        );
+        assert_eq!(fix.apply_to("Body\n"), None);
+    }
+
+    #[test]
+    fn should_rewrite_frontmatter_name_only() {
+        // Given
+        let fix = Fix {
+            path: PathBuf::from("/test/skills/skill-a/SKILL.md"),
+            kind: FixKind::SetFrontmatterName("skill-a".to_string()),
+        };
+        let content = "---\nname: wrong\ndescription: Test\n---\n\nname: body text\n";
+
+        // When
+        let rewritten = fix.apply_to(content);
+
+        // Then
+        assert_eq!(
+            rewritten,
+            Some("---\nname: skill-a\ndescription: Test\n---\n\nname: body text\n".to_string())
+        );
+    }
+
+    #[test]
+    fn should_apply_error_fixes_only_when_forced() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_file = temp.path().join("SKILL.md");
+        fs::write(&skill_file, "---\nname: wrong\ndescription: Test\n---").unwrap();
+        let findings = vec![
+            Finding::error("Name mismatch", "Rename", "name-mismatch:wrong").with_autofix(Fix {
+                path: skill_file.clone(),
+                kind: FixKind::SetFrontmatterName("right".to_string()),
+            }),
+            Finding::info("No newline", "Append", "trailing-newline:wrong").with_autofix(Fix {
+                path: skill_file.clone(),
+                kind: FixKind::AppendTrailingNewline,
+            }),
+        ];
+
+        // When
+        let unforced = apply_fixes(&findings, false).unwrap();
+        let after_unforced = fs::read_to_string(&skill_file).unwrap();
+        let forced = apply_fixes(&findings, true).unwrap();
+
+        // Then
+        assert_eq!(unforced, 1);
+        assert_eq!(after_unforced, "---\nname: wrong\ndescription: Test\n---\n");
+        assert_eq!(forced, 1);
+        assert_eq!(
+            fs::read_to_string(&skill_file).unwrap(),
+            "---\nname: right\ndescription: Test\n---\n"
+        );
+    }
+
+    #[test]
+    fn should_derive_rule_id_from_suppress_key() {
+        // Given
+        let finding = Finding::warning("Orphaned", "Enable it", "orphaned:skill-a");
+
+        // When/Then
+        assert_eq!(finding.rule_id(), "orphaned");
+    }
+
+    #[test]
+    fn should_serialize_findings_as_sarif() {
+        // Given
+        let findings = vec![
+            Finding::error_with_path(
+                "Dangling reference",
+                "Remove it",
+                "dangling:skill-a:missing",
+                PathBuf::from("/test/skills/skill-a/SKILL.md"),
+            )
+            .at_line(12),
+            Finding::info("No metadata", "Add tags", "no-metadata:skill-b"),
+        ];
+
+        // When
+        let sarif: serde_json::Value =
+            serde_json::from_str(&to_sarif(&findings, Path::new("/test"))).unwrap();
+
+        // Then
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = &sarif["runs"][0]["results"];
+        assert_eq!(results[0]["ruleId"], "dangling");
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            "skills/skill-a/SKILL.md"
+        );
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(
+            sarif["runs"][0]["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///test/"
+        );
+        assert_eq!(location["region"]["startLine"], 12);
+        assert_eq!(results[1]["level"], "note");
+        assert!(results[1].get("locations").is_none());
+    }
+
+    #[test]
+    fn should_use_absolute_file_uri_for_paths_outside_root() {
+        // Given
+        let findings = vec![Finding::warning_with_path(
+            "Broken symlink",
+            "Remove it",
+            "broken-symlink:old skill",
+            PathBuf::from("/home/user/.claude/skills/old skill"),
+        )];
+
+        // When
+        let sarif: serde_json::Value =
+            serde_json::from_str(&to_sarif(&findings, Path::new("/test"))).unwrap();
+
+        // Then
+        let artifact =
+            &sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(
+            artifact["uri"],
+            "file:///home/user/.claude/skills/old%20skill"
+        );
+        assert!(artifact.get("uriBaseId").is_none());
     }
 }
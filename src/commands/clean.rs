@@ -1,6 +1,9 @@
 //! Clean command implementation
 
-use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
 use colored::Colorize;
 
 use crate::config::Config;
@@ -8,86 +11,124 @@ use crate::linker;
 
 const PROJECT_SUBDIRS: &[&str] = &[".claude/skills", ".opencode/skills", ".agents/skills"];
 
+/// Files `clean` removes, or would remove in dry-run mode
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CleanSummary {
+    /// Symlinks and marker files, grouped by target directory
+    pub paths: Vec<PathBuf>,
+    /// Total size of those files, measured without following symlinks
+    pub bytes: u64,
+}
+
+impl CleanSummary {
+    /// Number of files to remove
+    pub fn files(&self) -> usize {
+        self.paths.len()
+    }
+}
+
 /// Remove all managed symlinks from target directories
-pub fn clean(config: &Config, dry_run: bool) -> Result<()> {
+///
+/// With `target`, only that global target is cleaned; it must be one of
+/// `config.global.targets`.
+pub fn clean(config: &Config, dry_run: bool, target: Option<&Path>) -> Result<()> {
+    let targets = clean_targets(config, target)?;
+    let summary = clean_plan(config, target)?;
+
     if dry_run {
         println!("{}", "[DRY RUN MODE]".yellow().bold());
         println!();
-    }
 
-    let mut total_removed = 0;
-
-    // Clean global targets
-    println!("{}", "--- Global scope ---".cyan().bold());
-    for target in &config.global.targets {
-        if dry_run {
-            if linker::is_managed(target) {
-                println!(
-                    "  {} would clean: {}",
-                    "[dry-run]".yellow(),
-                    target.display()
-                );
-            }
-        } else {
-            let removed = linker::clean_target(target)?;
-            if !removed.is_empty() {
-                println!(
-                    "  {} {} (removed {} symlinks)",
-                    "cleaned:".green(),
-                    target.display(),
-                    removed.len()
-                );
-                total_removed += removed.len();
-            }
+        for path in &summary.paths {
+            println!("  {} {}", "would remove:".yellow(), path.display());
         }
-    }
 
-    // Clean project targets
-    for project_path in config.projects.keys() {
         println!();
         println!(
-            "{} {}",
-            "--- Project:".cyan().bold(),
-            project_path.display()
+            "Would remove {} files ({} bytes)",
+            summary.files(),
+            summary.bytes
         );
+        return Ok(());
+    }
 
-        for subdir in PROJECT_SUBDIRS {
-            let target = project_path.join(subdir);
-            if dry_run {
-                if linker::is_managed(&target) {
-                    println!(
-                        "  {} would clean: {}",
-                        "[dry-run]".yellow(),
-                        target.display()
-                    );
-                }
-            } else {
-                let removed = linker::clean_target(&target)?;
-                if !removed.is_empty() {
-                    println!(
-                        "  {} {} (removed {} symlinks)",
-                        "cleaned:".green(),
-                        target.display(),
-                        removed.len()
-                    );
-                    total_removed += removed.len();
-                }
-            }
+    for target in targets {
+        let removed = linker::clean_target(&target)?;
+        if !removed.is_empty() {
+            println!(
+                "  {} {} (removed {} symlinks)",
+                "cleaned:".green(),
+                target.display(),
+                removed.len()
+            );
         }
     }
 
-    if !dry_run {
-        println!();
-        println!(
-            "{} {}",
-            "Done.".green().bold(),
-            format!("Removed {} symlinks", total_removed).dimmed()
-        );
-    }
+    println!();
+    println!(
+        "{} {}",
+        "Done.".green().bold(),
+        format!(
+            "Removed {} files ({} bytes)",
+            summary.files(),
+            summary.bytes
+        )
+        .dimmed()
+    );
 
     Ok(())
 }
 
+/// Collect the files `clean` would remove and their total size
+pub fn clean_plan(config: &Config, target: Option<&Path>) -> Result<CleanSummary> {
+    let mut summary = CleanSummary::default();
+
+    for target in clean_targets(config, target)? {
+        for path in linker::clean_candidates(&target)? {
+            let metadata = fs::symlink_metadata(&path)
+                .context(format!("Failed to read metadata: {}", path.display()))?;
+            summary.bytes += metadata.len();
+            summary.paths.push(path);
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Global targets followed by every project target, projects in path order
+///
+/// `only` narrows this to a single global target, erroring if it isn't one.
+fn clean_targets(config: &Config, only: Option<&Path>) -> Result<Vec<PathBuf>> {
+    if let Some(only) = only {
+        if !config.global.targets.iter().any(|t| t == only) {
+            let valid: Vec<String> = config
+                .global
+                .targets
+                .iter()
+                .map(|t| t.display().to_string())
+                .collect();
+            anyhow::bail!(
+                "'{}' is not a configured target. Valid targets: {}",
+                only.display(),
+                valid.join(", ")
+            );
+        }
+        return Ok(vec![only.to_path_buf()]);
+    }
+
+    let mut projects: Vec<_> = config.projects.keys().collect();
+    projects.sort();
+
+    let mut targets = config.global.targets.clone();
+    for project_path in projects {
+        for subdir in PROJECT_SUBDIRS {
+            targets.push(project_path.join(subdir));
+        }
+    }
+
+    Ok(targets)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,7 +144,8 @@ mod tests {
 
         Config {
             sources: Sources {
-                skills: vec![skill_source],
+                skills: vec![skill_source.into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![global_target],
@@ -121,6 +163,9 @@ mod tests {
                 projects
             },
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         }
     }
 
@@ -142,7 +187,7 @@ mod tests {
         create_managed_target(&global_target, "test-skill");
 
         // When
-        clean(&config, false).unwrap();
+        clean(&config, false, None).unwrap();
 
         // Then
         assert!(!global_target.join("test-skill").exists());
@@ -159,7 +204,7 @@ mod tests {
         create_managed_target(&project_target, "test-skill");
 
         // When
-        clean(&config, false).unwrap();
+        clean(&config, false, None).unwrap();
 
         // Then
         assert!(!project_target.join("test-skill").exists());
@@ -176,13 +221,70 @@ mod tests {
         create_managed_target(&global_target, "test-skill");
 
         // When
-        clean(&config, true).unwrap();
+        clean(&config, true, None).unwrap();
 
         // Then - symlink still exists
         assert!(global_target.join("test-skill").exists());
         assert!(linker::is_managed(&global_target));
     }
 
+    #[test]
+    fn should_report_files_and_bytes_to_reclaim() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let global_target = temp.path().join("global");
+        create_managed_target(&global_target, "test-skill");
+        let marker = "{\"skills\":[\"test-skill\"]}"; // 25 bytes
+        fs::write(global_target.join(".managed-by-loadout"), marker).unwrap();
+        let link_len = temp.path().join("skill-source").as_os_str().len() as u64;
+
+        // When
+        let summary = clean_plan(&config, None).unwrap();
+
+        // Then
+        assert_eq!(summary.files(), 2);
+        assert_eq!(summary.bytes, link_len + 25);
+        assert_eq!(summary.paths[0], global_target.join("test-skill"));
+    }
+
+    #[test]
+    fn should_clean_only_the_given_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        let global_target = temp.path().join("global");
+        let second_target = temp.path().join("second");
+        config.global.targets.push(second_target.clone());
+
+        create_managed_target(&global_target, "test-skill");
+        create_managed_target(&second_target, "test-skill");
+
+        // When
+        clean(&config, false, Some(&global_target)).unwrap();
+
+        // Then
+        assert!(!global_target.join("test-skill").exists());
+        assert!(second_target.join("test-skill").exists());
+        assert!(linker::is_managed(&second_target));
+    }
+
+    #[test]
+    fn should_reject_target_not_in_config() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let unknown = temp.path().join("elsewhere");
+
+        // When
+        let result = clean(&config, false, Some(&unknown));
+
+        // Then
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("is not a configured target"));
+        assert!(message.contains(&temp.path().join("global").display().to_string()));
+    }
+
     #[test]
     fn should_skip_unmanaged_directories() {
         // Given
@@ -195,7 +297,7 @@ mod tests {
         fs::write(global_target.join("some-file.txt"), "content").unwrap();
 
         // When
-        clean(&config, false).unwrap();
+        clean(&config, false, None).unwrap();
 
         // Then - file still exists
         assert!(global_target.join("some-file.txt").exists());
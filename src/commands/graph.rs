@@ -1,17 +1,47 @@
-use anyhow::Result;
-use std::collections::HashMap;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
 use std::fs;
+use std::path::{Path, PathBuf};
 
-use crate::config::Config;
-use crate::graph::SkillGraph;
-use crate::skill;
+use crate::config::{Config, SkillSource};
+use crate::graph::{DotOptions, EdgeKind, NodeOrder, RankDir, SkillGraph};
+use crate::skill::{self, ReferenceDetector, Skill};
+
+/// Number of skills shown by `--format hubs`
+const HUB_LIMIT: usize = 10;
+
+/// Share of score that follows references in `--format pagerank`
+const PAGERANK_DAMPING: f64 = 0.85;
+
+/// Update rounds run by `--format pagerank`
+const PAGERANK_ITERATIONS: usize = 50;
+
+/// Quiet period after the last SKILL.md change before `--watch` re-renders
+#[cfg(feature = "watch")]
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
 
 #[derive(Debug, Clone, Copy)]
 pub enum OutputFormat {
     Dot,
     Text,
     Json,
+    Yaml,
+    Toml,
     Mermaid,
+    D2,
+    TopoSort,
+    Centrality,
+    PageRank,
+    Cycles,
+    BreakCycles,
+    Hubs,
+    Mutual,
+    Stats,
+    Diamonds,
+    Html,
+    Swimlane,
+    Components,
+    Markdown,
 }
 
 impl OutputFormat {
@@ -20,93 +50,528 @@ impl OutputFormat {
             "dot" => Some(Self::Dot),
             "text" => Some(Self::Text),
             "json" => Some(Self::Json),
+            "yaml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
             "mermaid" => Some(Self::Mermaid),
+            "d2" => Some(Self::D2),
+            "topo" => Some(Self::TopoSort),
+            "centrality" => Some(Self::Centrality),
+            "pagerank" => Some(Self::PageRank),
+            "cycles" => Some(Self::Cycles),
+            "break-cycles" => Some(Self::BreakCycles),
+            "hubs" => Some(Self::Hubs),
+            "mutual" => Some(Self::Mutual),
+            "stats" => Some(Self::Stats),
+            "diamonds" => Some(Self::Diamonds),
+            "html" => Some(Self::Html),
+            "swimlane" => Some(Self::Swimlane),
+            "components" => Some(Self::Components),
+            "markdown" => Some(Self::Markdown),
             _ => None,
         }
     }
+
+    /// Parse a comma-separated list of formats, e.g. `dot,json,mermaid`
+    pub fn parse_formats(s: &str) -> Option<Vec<Self>> {
+        s.split(',').map(|f| Self::parse_format(f.trim())).collect()
+    }
+
+    /// File extension used when writing this format to `--output-dir`
+    ///
+    /// Plain-text reports get a `<format>.txt` extension so they don't
+    /// overwrite each other.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::Text => "txt",
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+            Self::Mermaid => "mmd",
+            Self::D2 => "d2",
+            Self::TopoSort => "topo.txt",
+            Self::Centrality => "centrality.txt",
+            Self::PageRank => "pagerank.txt",
+            Self::Cycles => "cycles.txt",
+            Self::BreakCycles => "break-cycles.txt",
+            Self::Hubs => "hubs.txt",
+            Self::Mutual => "mutual.txt",
+            Self::Stats => "stats.txt",
+            Self::Diamonds => "diamonds.txt",
+            Self::Html => "html",
+            Self::Swimlane => "swimlane.dot",
+            Self::Components => "components.txt",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+/// Parse an `--edges` value into the edge kinds to keep
+pub fn parse_edge_selection(s: &str) -> Option<Vec<EdgeKind>> {
+    match s.to_lowercase().as_str() {
+        "crossref" => Some(vec![EdgeKind::CrossRef]),
+        "pipeline" => Some(vec![EdgeKind::Pipeline]),
+        "all" => Some(vec![EdgeKind::CrossRef, EdgeKind::Pipeline]),
+        _ => None,
+    }
+}
+
+/// Parse an `--order` value into the node order for exports
+pub fn parse_node_order(s: &str) -> Option<NodeOrder> {
+    match s.to_lowercase().as_str() {
+        "alpha" => Some(NodeOrder::Alpha),
+        "topo" => Some(NodeOrder::Topo),
+        _ => None,
+    }
 }
 
-/// Optional filter for graph command
-pub enum GraphFilter {
-    None,
+/// Parse a `--rankdir` value into the DOT layout direction
+pub fn parse_rank_dir(s: &str) -> Option<RankDir> {
+    match s.to_lowercase().as_str() {
+        "lr" => Some(RankDir::LeftRight),
+        "tb" => Some(RankDir::TopBottom),
+        _ => None,
+    }
+}
+
+/// A single graph filter clause
+///
+/// Clauses are applied in order, so the kept skill sets intersect.
+pub enum FilterClause {
     Pipeline(String),
     Tag(String),
+    ExcludeTag(String),
+    ExcludeDeprecated,
+    Impact(String),
+    Around(String, usize),
+    Edges(Vec<EdgeKind>),
+    FromRoots,
 }
 
-pub fn graph(config: &Config, format: OutputFormat, filter: GraphFilter) -> Result<()> {
-    use std::collections::HashSet;
+pub fn graph(
+    config: &Config,
+    format: OutputFormat,
+    order: NodeOrder,
+    dot: DotOptions,
+    filters: &[FilterClause],
+    output: Option<&Path>,
+) -> Result<()> {
+    let (all_skills, full_graph) =
+        build_graph(&config.sources.skills, &config.detection.detectors())?;
 
-    // Discover all skills
-    let all_skills = skill::discover_all(&config.sources.skills)?;
+    let summaries = impact_summaries(&full_graph, filters);
+    let skill_graph = apply_filters(full_graph, &all_skills, filters)?;
+    let rendered = render_graph(&skill_graph, &all_skills, format, order, dot, filters)?;
+    write_output(&rendered, output)?;
 
-    // Build set of known skill names for filtering
-    let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
+    // The summary goes to stderr so piped dot/json output stays parseable
+    for summary in summaries {
+        eprint!("{}", summary);
+    }
+
+    Ok(())
+}
 
-    // Extract cross-references
-    let mut crossrefs = HashMap::new();
-    for skill in &all_skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
-        if !refs.is_empty() {
-            crossrefs.insert(skill.name.clone(), refs);
+/// One `--impact` summary per impact filter, taken on the unfiltered graph
+///
+/// Other filters may drop the skill or its dependents from the rendered
+/// graph; that shouldn't hide what removing the skill would break.
+fn impact_summaries(full_graph: &SkillGraph, filters: &[FilterClause]) -> Vec<String> {
+    filters
+        .iter()
+        .filter_map(|filter| match filter {
+            FilterClause::Impact(name) => Some(impact_summary(name, &full_graph.impact_of(name))),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Impacted skills and their count for `--impact`, in the shape of `list --impact`
+fn impact_summary(skill_name: &str, affected: &[String]) -> String {
+    let mut summary = format!("--- Impact of removing {}\n", skill_name);
+    if affected.is_empty() {
+        summary.push_str("\nNo skills depend on this skill.\n");
+    } else {
+        summary.push_str(&format!("\n{} skills depend on it:\n\n", affected.len()));
+        for name in affected {
+            summary.push_str(&format!("  • {}\n", name));
         }
     }
+    summary
+}
+
+/// Build the graph once and write each format to `dir/graph.<ext>`
+///
+/// Discovery dominates on large repos, so this is much faster than one run
+/// per format. The directory is created if needed. Returns the written paths
+/// in format order.
+pub fn graph_to_dir(
+    config: &Config,
+    formats: &[OutputFormat],
+    order: NodeOrder,
+    dot: DotOptions,
+    filters: &[FilterClause],
+    dir: &Path,
+) -> Result<Vec<PathBuf>> {
+    let (all_skills, full_graph) =
+        build_graph(&config.sources.skills, &config.detection.detectors())?;
+    let skill_graph = apply_filters(full_graph, &all_skills, filters)?;
+
+    fs::create_dir_all(dir).context(format!(
+        "Failed to create output directory: {}",
+        dir.display()
+    ))?;
+
+    formats
+        .iter()
+        .map(|&format| {
+            let rendered = render_graph(&skill_graph, &all_skills, format, order, dot, filters)?;
+            let path = dir.join(format!("graph.{}", format.extension()));
+            write_output(&rendered, Some(&path))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Re-render the graph whenever a SKILL.md under a configured source changes
+///
+/// Renders once up front, then runs until interrupted with Ctrl-C. Bursts of
+/// changes are debounced into one render, terminal output is cleared between
+/// renders, and render errors are reported without ending the watch. Skills
+/// go through the discovery cache, so a re-render only reads the SKILL.md
+/// files that changed.
+#[cfg(feature = "watch")]
+pub fn watch(
+    config: &Config,
+    format: OutputFormat,
+    order: NodeOrder,
+    dot: DotOptions,
+    filters: &[FilterClause],
+    output: Option<&Path>,
+) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::io::IsTerminal;
+    use std::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).context("Failed to start file watcher")?;
+    for source in &config.sources.skills {
+        if source.path.exists() {
+            watcher
+                .watch(&source.path, RecursiveMode::Recursive)
+                .context(format!("Failed to watch {}", source.path.display()))?;
+        }
+    }
+
+    let cache_path = crate::config::cache_path()?;
+    let clear = output.is_none() && std::io::stdout().is_terminal();
+    loop {
+        if clear {
+            print!("\x1B[2J\x1B[H");
+        }
+        match render_cached(config, format, order, dot, filters, &cache_path) {
+            Ok(rendered) => write_output(&rendered, output)?,
+            Err(e) => eprintln!("Error: {:#}", e),
+        }
 
-    // Build the full graph (with pipeline edges and dedup)
+        // Wait for a SKILL.md change, then let the burst settle
+        loop {
+            match rx.recv().context("File watcher stopped")? {
+                Ok(event) if touches_skill_file(&event) => break,
+                Ok(_) => {}
+                Err(e) => eprintln!("Warning: File watcher error: {}", e),
+            }
+        }
+        while rx.recv_timeout(WATCH_DEBOUNCE).is_ok() {}
+    }
+}
+
+#[cfg(not(feature = "watch"))]
+pub fn watch(
+    _config: &Config,
+    _format: OutputFormat,
+    _order: NodeOrder,
+    _dot: DotOptions,
+    _filters: &[FilterClause],
+    _output: Option<&Path>,
+) -> Result<()> {
+    anyhow::bail!("Watch mode requires the watch feature (install with --features watch)")
+}
+
+/// Whether a watcher event changed a SKILL.md
+///
+/// Access events are ignored since rendering itself reads every SKILL.md.
+#[cfg(feature = "watch")]
+fn touches_skill_file(event: &notify::Event) -> bool {
+    !matches!(event.kind, notify::EventKind::Access(_))
+        && event
+            .paths
+            .iter()
+            .any(|p| p.file_name().is_some_and(|n| n == skill::SKILL_FILE_NAME))
+}
+
+/// Write rendered output to a file, or to stdout when no file is given
+fn write_output(rendered: &str, output: Option<&Path>) -> Result<()> {
+    match output {
+        Some(path) => fs::write(path, format!("{}\n", rendered))
+            .context(format!("Failed to write graph output: {}", path.display())),
+        None => {
+            println!("{}", rendered);
+            Ok(())
+        }
+    }
+}
+
+/// Build, filter, and render the configured graph, discovering skills
+/// through the cache at `cache_path`
+#[cfg(feature = "watch")]
+fn render_cached(
+    config: &Config,
+    format: OutputFormat,
+    order: NodeOrder,
+    dot: DotOptions,
+    filters: &[FilterClause],
+    cache_path: &Path,
+) -> Result<String> {
+    let (all_skills, crossrefs) = skill::discover_all_cached(
+        &config.sources.skills,
+        &config.detection.detectors(),
+        cache_path,
+    )?;
     let full_graph = SkillGraph::from_skills(&crossrefs, &all_skills);
+    let skill_graph = apply_filters(full_graph, &all_skills, filters)?;
+    render_graph(&skill_graph, &all_skills, format, order, dot, filters)
+}
 
-    // Apply filter
-    let skill_graph = match &filter {
-        GraphFilter::None => full_graph,
-        GraphFilter::Pipeline(name) => {
-            // Verify pipeline exists
-            let exists = all_skills.iter().any(|s| {
-                s.frontmatter
-                    .pipeline
-                    .as_ref()
-                    .map(|p| p.contains_key(name.as_str()))
-                    .unwrap_or(false)
+/// Render an already filtered graph in one format
+fn render_graph(
+    skill_graph: &SkillGraph,
+    all_skills: &[Skill],
+    format: OutputFormat,
+    order: NodeOrder,
+    dot: DotOptions,
+    filters: &[FilterClause],
+) -> Result<String> {
+    if let Some(name) = dot.highlight {
+        if !skill_graph.node_names().iter().any(|n| n == name) {
+            anyhow::bail!("Skill '{}' to highlight is not in the graph", name);
+        }
+    }
+
+    let output = match format {
+        OutputFormat::Dot => skill_graph.to_dot_with(order, &dot),
+        OutputFormat::Text => skill_graph.to_text(order),
+        OutputFormat::Json => skill_graph.to_json(order),
+        OutputFormat::Yaml => skill_graph.to_yaml(order),
+        OutputFormat::Toml => skill_graph.to_toml(order),
+        OutputFormat::Mermaid => skill_graph.to_mermaid_with(dot.highlight),
+        OutputFormat::D2 => skill_graph.to_d2(),
+        OutputFormat::Centrality => skill_graph.to_centrality_text(),
+        OutputFormat::PageRank => {
+            skill_graph.to_pagerank_text(PAGERANK_DAMPING, PAGERANK_ITERATIONS)
+        }
+        OutputFormat::Cycles => skill_graph.to_cycles_text(),
+        OutputFormat::BreakCycles => skill_graph.to_break_cycles_text(),
+        OutputFormat::Hubs => skill_graph.to_hubs_text(HUB_LIMIT),
+        OutputFormat::Mutual => skill_graph.to_mutual_text(),
+        OutputFormat::Stats => skill_graph.to_stats_text(),
+        OutputFormat::Diamonds => skill_graph.to_diamonds_text(),
+        OutputFormat::Html => skill_graph.to_html(),
+        OutputFormat::Components => skill_graph.to_components_text(),
+        OutputFormat::Markdown => skill_graph.to_markdown_report(),
+        OutputFormat::Swimlane => {
+            let pipeline = filters.iter().find_map(|clause| match clause {
+                FilterClause::Pipeline(name) => Some(name),
+                _ => None,
             });
-            if !exists {
-                let mut available: Vec<String> = HashSet::<String>::new().into_iter().collect();
-                for s in &all_skills {
-                    if let Some(p) = &s.frontmatter.pipeline {
-                        for name in p.keys() {
-                            available.push(name.clone());
-                        }
-                    }
-                }
-                available.sort();
-                available.dedup();
+            let Some(pipeline) = pipeline else {
+                anyhow::bail!("Swimlane format requires --pipeline <name>");
+            };
+            skill_graph.to_swimlane(&skill::pipeline_stages(all_skills, pipeline))
+        }
+        OutputFormat::TopoSort => match skill_graph.topological_order() {
+            Ok(order) => order.join("\n"),
+            Err(cycles) => {
+                let members: Vec<String> = cycles.iter().map(|c| c.join(", ")).collect();
                 anyhow::bail!(
-                    "Pipeline '{}' not found. Available: {}",
-                    name,
-                    available.join(", ")
+                    "Cannot order skills: dependency cycle among {}",
+                    members.join("; ")
                 );
             }
-            full_graph.filter_pipeline(&all_skills, name)
-        }
-        GraphFilter::Tag(tag) => full_graph.filter_tag(&all_skills, tag),
+        },
     };
 
-    // Output in requested format
-    let output = match format {
-        OutputFormat::Dot => skill_graph.to_dot(),
-        OutputFormat::Text => skill_graph.to_text(),
-        OutputFormat::Json => skill_graph.to_json(),
-        OutputFormat::Mermaid => skill_graph.to_mermaid(),
-    };
+    Ok(output)
+}
+
+/// Check that the filtered graph has no cycles, for CI gates
+///
+/// Prints each cycle the way `--format cycles` does, or a success line when
+/// there are none. Returns whether the graph is acyclic.
+pub fn check_acyclic(config: &Config, filters: &[FilterClause]) -> Result<bool> {
+    let (all_skills, full_graph) =
+        build_graph(&config.sources.skills, &config.detection.detectors())?;
+    let skill_graph = apply_filters(full_graph, &all_skills, filters)?;
+
+    let (acyclic, report) = acyclic_report(&skill_graph);
+    print!("{}", report);
+    Ok(acyclic)
+}
+
+/// Whether a graph is acyclic, with the report `check_acyclic` prints
+fn acyclic_report(graph: &SkillGraph) -> (bool, String) {
+    let cycles = graph.cycles();
+    if cycles.is_empty() {
+        let report = format!("Graph is acyclic ({} skills)\n", graph.node_names().len());
+        return (true, report);
+    }
+
+    let report = format!(
+        "Found {} cycle(s):\n{}",
+        cycles.len(),
+        graph.to_cycles_text()
+    );
+    (false, report)
+}
+
+/// Narrow the graph by each filter clause in turn
+fn apply_filters(
+    full_graph: SkillGraph,
+    all_skills: &[Skill],
+    filters: &[FilterClause],
+) -> Result<SkillGraph> {
+    // Build set of known skill names for filtering
+    let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
 
-    println!("{}", output);
+    let mut skill_graph = full_graph;
+    for clause in filters {
+        skill_graph = match clause {
+            FilterClause::Pipeline(name) => {
+                // Verify pipeline exists
+                let exists = all_skills.iter().any(|s| {
+                    s.frontmatter
+                        .pipeline
+                        .as_ref()
+                        .map(|p| p.contains_key(name.as_str()))
+                        .unwrap_or(false)
+                });
+                if !exists {
+                    let mut available: Vec<String> = Vec::new();
+                    for s in all_skills {
+                        if let Some(p) = &s.frontmatter.pipeline {
+                            for name in p.keys() {
+                                available.push(name.clone());
+                            }
+                        }
+                    }
+                    available.sort();
+                    available.dedup();
+                    anyhow::bail!(
+                        "Pipeline '{}' not found. Available: {}",
+                        name,
+                        available.join(", ")
+                    );
+                }
+                skill_graph.filter_pipeline(all_skills, name)
+            }
+            FilterClause::Tag(tag) => skill_graph.filter_tag(all_skills, tag),
+            FilterClause::ExcludeTag(tag) => skill_graph.exclude_tag(all_skills, tag),
+            FilterClause::ExcludeDeprecated => skill_graph.exclude_deprecated(all_skills),
+            FilterClause::Impact(name) => {
+                if !known_skills.contains(name) {
+                    anyhow::bail!("Skill '{}' not found in any source", name);
+                }
+                skill_graph.filter_impact(name)
+            }
+            FilterClause::Around(name, depth) => {
+                if !known_skills.contains(name) {
+                    anyhow::bail!("Skill '{}' not found in any source", name);
+                }
+                skill_graph.neighborhood(name, *depth)
+            }
+            FilterClause::Edges(kinds) => skill_graph.filter_edges(kinds),
+            FilterClause::FromRoots => skill_graph.reachable_from_roots(),
+        };
+    }
+
+    Ok(skill_graph)
+}
+
+/// Compare the configured skill graph against the graph from other source directories
+pub fn diff(config: &Config, other_sources: &[PathBuf]) -> Result<()> {
+    let other_sources: Vec<SkillSource> = other_sources.iter().cloned().map(Into::into).collect();
+    let detectors = config.detection.detectors();
+    let (_, current) = build_graph(&config.sources.skills, &detectors)?;
+    let (_, other) = build_graph(&other_sources, &detectors)?;
+
+    println!("{}", current.diff(&other).to_text());
 
     Ok(())
 }
 
+/// Discover skills and build the full graph (with pipeline edges and dedup)
+fn build_graph(
+    sources: &[SkillSource],
+    detectors: &[Box<dyn ReferenceDetector>],
+) -> Result<(Vec<Skill>, SkillGraph)> {
+    let (all_skills, crossrefs) = skill::analyze(sources, detectors)?;
+
+    let graph = SkillGraph::from_skills(&crossrefs, &all_skills);
+    Ok((all_skills, graph))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{DetectionConfig, Global, Sources};
+    use crate::skill::frontmatter::{Frontmatter, PipelineStage};
+    use std::collections::HashMap;
+
+    // Helper to create a skill with optional tags and pipeline membership
+    fn test_skill(name: &str, tags: &[&str], pipeline: Option<&str>) -> Skill {
+        Skill {
+            name: name.to_string(),
+            path: PathBuf::from(format!("/test/skills/{}", name)),
+            skill_file: PathBuf::from(format!("/test/skills/{}/SKILL.md", name)),
+            frontmatter: Frontmatter {
+                name: name.to_string(),
+                description: format!("{} description", name),
+                disable_model_invocation: None,
+                user_invocable: None,
+                allowed_tools: None,
+                context: None,
+                agent: None,
+                model: None,
+                argument_hint: None,
+                license: None,
+                compatibility: None,
+                metadata: None,
+                tags: Some(tags.iter().map(|t| t.to_string()).collect()),
+                aliases: None,
+                deprecated: None,
+                pipeline: pipeline.map(|p| {
+                    let mut stages = HashMap::new();
+                    stages.insert(
+                        p.to_string(),
+                        PipelineStage {
+                            stage: "step".to_string(),
+                            order: 1,
+                            after: None,
+                            before: None,
+                        },
+                    );
+                    stages
+                }),
+            },
+            content: None,
+        }
+    }
+
+    // Helper to make a pipeline member run after another skill
+    fn depend(skill: &mut Skill, pipeline: &str, dependency: &str) {
+        let stages = skill.frontmatter.pipeline.as_mut().unwrap();
+        stages.get_mut(pipeline).unwrap().after = Some(vec![dependency.to_string()]);
+    }
 
     #[test]
     fn should_parse_output_format_case_insensitive() {
@@ -127,10 +592,374 @@ mod tests {
             OutputFormat::parse_format("json"),
             Some(OutputFormat::Json)
         ));
+        assert!(matches!(
+            OutputFormat::parse_format("yaml"),
+            Some(OutputFormat::Yaml)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("toml"),
+            Some(OutputFormat::Toml)
+        ));
         assert!(matches!(
             OutputFormat::parse_format("mermaid"),
             Some(OutputFormat::Mermaid)
         ));
+        assert!(matches!(
+            OutputFormat::parse_format("d2"),
+            Some(OutputFormat::D2)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("topo"),
+            Some(OutputFormat::TopoSort)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("centrality"),
+            Some(OutputFormat::Centrality)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("pagerank"),
+            Some(OutputFormat::PageRank)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("cycles"),
+            Some(OutputFormat::Cycles)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("break-cycles"),
+            Some(OutputFormat::BreakCycles)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("hubs"),
+            Some(OutputFormat::Hubs)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("mutual"),
+            Some(OutputFormat::Mutual)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("stats"),
+            Some(OutputFormat::Stats)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("diamonds"),
+            Some(OutputFormat::Diamonds)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("html"),
+            Some(OutputFormat::Html)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("swimlane"),
+            Some(OutputFormat::Swimlane)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("components"),
+            Some(OutputFormat::Components)
+        ));
+        assert!(matches!(
+            OutputFormat::parse_format("markdown"),
+            Some(OutputFormat::Markdown)
+        ));
         assert!(OutputFormat::parse_format("invalid").is_none());
     }
+
+    #[test]
+    fn should_parse_comma_separated_formats() {
+        // Given/When
+        let formats = OutputFormat::parse_formats("dot, JSON,mermaid").unwrap();
+
+        // Then
+        let extensions: Vec<&str> = formats.iter().map(OutputFormat::extension).collect();
+        assert_eq!(extensions, vec!["dot", "json", "mmd"]);
+        assert!(OutputFormat::parse_formats("dot,,json").is_none());
+        assert!(OutputFormat::parse_formats("dot,png").is_none());
+    }
+
+    #[test]
+    fn should_parse_edge_selection() {
+        // Given/When/Then
+        assert_eq!(
+            parse_edge_selection("crossref"),
+            Some(vec![EdgeKind::CrossRef])
+        );
+        assert_eq!(
+            parse_edge_selection("Pipeline"),
+            Some(vec![EdgeKind::Pipeline])
+        );
+        assert_eq!(
+            parse_edge_selection("all"),
+            Some(vec![EdgeKind::CrossRef, EdgeKind::Pipeline])
+        );
+        assert!(parse_edge_selection("none").is_none());
+    }
+
+    #[test]
+    fn should_parse_node_order() {
+        // Given/When/Then
+        assert_eq!(parse_node_order("alpha"), Some(NodeOrder::Alpha));
+        assert_eq!(parse_node_order("TOPO"), Some(NodeOrder::Topo));
+        assert!(parse_node_order("random").is_none());
+    }
+
+    #[test]
+    fn should_parse_rank_dir() {
+        // Given/When/Then
+        assert_eq!(parse_rank_dir("LR"), Some(RankDir::LeftRight));
+        assert_eq!(parse_rank_dir("tb"), Some(RankDir::TopBottom));
+        assert!(parse_rank_dir("rl").is_none());
+    }
+
+    #[test]
+    fn should_build_graph_from_source_directories() {
+        // Given
+        let sources = vec![PathBuf::from("tests/fixtures/skills").into()];
+
+        // When
+        let (skills, graph) =
+            build_graph(&sources, &DetectionConfig::default().detectors()).unwrap();
+
+        // Then
+        assert_eq!(skills.len(), 3);
+        assert!(graph.diff(&graph).is_empty());
+    }
+
+    #[test]
+    fn should_write_graph_to_output_file() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("graph.mmd");
+
+        // When
+        write_output("graph TD", Some(&path)).unwrap();
+
+        // Then
+        assert_eq!(fs::read_to_string(&path).unwrap(), "graph TD\n");
+    }
+
+    #[test]
+    fn should_write_each_format_to_output_dir() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join("out");
+        let config = Config {
+            sources: Sources {
+                skills: vec![PathBuf::from("tests/fixtures/skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+        let formats = [OutputFormat::Dot, OutputFormat::Json, OutputFormat::Mermaid];
+
+        // When
+        let written = graph_to_dir(
+            &config,
+            &formats,
+            NodeOrder::Alpha,
+            DotOptions::default(),
+            &[],
+            &dir,
+        )
+        .unwrap();
+
+        // Then
+        let expected: Vec<PathBuf> = ["graph.dot", "graph.json", "graph.mmd"]
+            .iter()
+            .map(|name| dir.join(name))
+            .collect();
+        assert_eq!(written, expected);
+        assert!(fs::read_to_string(&expected[0])
+            .unwrap()
+            .starts_with("digraph"));
+        assert!(serde_json::from_str::<serde_json::Value>(
+            &fs::read_to_string(&expected[1]).unwrap()
+        )
+        .is_ok());
+        assert!(fs::read_to_string(&expected[2])
+            .unwrap()
+            .starts_with("graph"));
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn should_only_rerender_for_skill_file_changes() {
+        use notify::event::{AccessKind, ModifyKind};
+        use notify::{Event, EventKind};
+
+        // Given
+        let modify = |path: &str| {
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(path))
+        };
+        let read = Event::new(EventKind::Access(AccessKind::Any))
+            .add_path(PathBuf::from("skills/draft/SKILL.md"));
+
+        // When/Then
+        assert!(touches_skill_file(&modify("skills/draft/SKILL.md")));
+        assert!(!touches_skill_file(&modify("skills/draft/notes.md")));
+        assert!(!touches_skill_file(&read));
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn should_render_same_graph_through_cache() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache_path = temp.path().join("cache/skills.json");
+        let config = Config {
+            sources: Sources {
+                skills: vec![PathBuf::from("tests/fixtures/skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+        let (format, order, dot) = (OutputFormat::Text, NodeOrder::Alpha, DotOptions::default());
+        let (all_skills, skill_graph) =
+            build_graph(&config.sources.skills, &config.detection.detectors()).unwrap();
+        let uncached = render_graph(&skill_graph, &all_skills, format, order, dot, &[]).unwrap();
+
+        // When
+        let cold = render_cached(&config, format, order, dot, &[], &cache_path).unwrap();
+        let warm = render_cached(&config, format, order, dot, &[], &cache_path).unwrap();
+
+        // Then
+        assert!(cache_path.exists());
+        assert_eq!(cold, uncached);
+        assert_eq!(warm, cold);
+    }
+
+    #[test]
+    fn should_summarize_impact_on_unfiltered_graph() {
+        // Given: draft references edit, and a tag filter that keeps neither
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for (name, body) in [("edit", ""), ("draft", "<see ref=\"edit\">x</see>\n")] {
+            fs::create_dir_all(source.join(name)).unwrap();
+            fs::write(
+                source.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n---\n{body}"),
+            )
+            .unwrap();
+        }
+        let (all_skills, full_graph) =
+            build_graph(&[source.into()], &DetectionConfig::default().detectors()).unwrap();
+        let filters = [
+            FilterClause::Tag("unused".to_string()),
+            FilterClause::Impact("edit".to_string()),
+        ];
+
+        // When
+        let summaries = impact_summaries(&full_graph, &filters);
+        let filtered = apply_filters(full_graph, &all_skills, &filters).unwrap();
+
+        // Then
+        assert_eq!(filtered.node_count(), 0);
+        assert_eq!(summaries.len(), 1);
+        assert!(summaries[0].contains("1 skills depend on it"));
+        assert!(summaries[0].contains("  • draft"));
+    }
+
+    #[test]
+    fn should_summarize_impacted_skills_with_count() {
+        // Given
+        let affected = vec!["draft".to_string(), "publish".to_string()];
+
+        // When
+        let summary = impact_summary("edit", &affected);
+        let none = impact_summary("publish", &[]);
+
+        // Then
+        assert_eq!(
+            summary,
+            "--- Impact of removing edit\n\n2 skills depend on it:\n\n  • draft\n  • publish\n"
+        );
+        assert!(none.ends_with("No skills depend on this skill.\n"));
+    }
+
+    #[test]
+    fn should_report_acyclic_graph() {
+        // Given: audit runs after scan
+        let mut skills = vec![
+            test_skill("audit", &[], Some("release")),
+            test_skill("scan", &[], Some("release")),
+        ];
+        depend(&mut skills[0], "release", "scan");
+        let graph = SkillGraph::from_skills(&HashMap::new(), &skills);
+
+        // When
+        let (acyclic, report) = acyclic_report(&graph);
+
+        // Then
+        assert!(acyclic);
+        assert_eq!(report, "Graph is acyclic (2 skills)\n");
+    }
+
+    #[test]
+    fn should_report_cycles_when_graph_is_cyclic() {
+        // Given: audit and scan each run after the other
+        let mut skills = vec![
+            test_skill("audit", &[], Some("release")),
+            test_skill("scan", &[], Some("release")),
+        ];
+        depend(&mut skills[0], "release", "scan");
+        depend(&mut skills[1], "release", "audit");
+        let graph = SkillGraph::from_skills(&HashMap::new(), &skills);
+
+        // When
+        let (acyclic, report) = acyclic_report(&graph);
+
+        // Then
+        assert!(!acyclic);
+        assert_eq!(report, "Found 1 cycle(s):\naudit → scan → audit\n");
+    }
+
+    #[test]
+    fn should_intersect_pipeline_and_tag_filters() {
+        // Given
+        let skills = vec![
+            test_skill("audit", &["security"], Some("release")),
+            test_skill("publish", &["ops"], Some("release")),
+            test_skill("scan", &["security"], None),
+        ];
+        let graph = SkillGraph::from_skills(&HashMap::new(), &skills);
+        let filters = vec![
+            FilterClause::Pipeline("release".to_string()),
+            FilterClause::Tag("security".to_string()),
+        ];
+
+        // When
+        let filtered = apply_filters(graph, &skills, &filters).unwrap();
+
+        // Then
+        assert_eq!(filtered.roots, vec!["audit"]);
+    }
+
+    #[test]
+    fn should_reject_unknown_pipeline_filter() {
+        // Given
+        let skills = vec![test_skill("audit", &[], Some("release"))];
+        let graph = SkillGraph::from_skills(&HashMap::new(), &skills);
+        let filters = vec![FilterClause::Pipeline("deploy".to_string())];
+
+        // When
+        let result = apply_filters(graph, &skills, &filters);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert_eq!(err, "Pipeline 'deploy' not found. Available: release");
+    }
 }
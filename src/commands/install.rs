@@ -1,7 +1,9 @@
 //! Install command implementation
 
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use colored::Colorize;
@@ -12,86 +14,255 @@ use crate::skill;
 
 const PROJECT_SUBDIRS: &[&str] = &[".claude/skills", ".opencode/skills", ".agents/skills"];
 
+/// A single filesystem operation planned by `install`
+///
+/// `target` is the managed directory holding the manifest. It is the link's
+/// parent only for unprefixed skills; `prefix/name` links sit one level deeper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallAction {
+    /// Create a symlink where nothing exists yet
+    Create {
+        skill: String,
+        source: PathBuf,
+        target: PathBuf,
+        link: PathBuf,
+    },
+    /// Replace an existing entry at the link path with a symlink
+    Overwrite {
+        skill: String,
+        source: PathBuf,
+        target: PathBuf,
+        link: PathBuf,
+    },
+    /// Record an already correct link in a target whose marker predates manifests
+    Record {
+        skill: String,
+        source: PathBuf,
+        target: PathBuf,
+        link: PathBuf,
+    },
+    /// Remove a link recorded in a target's manifest whose skill is no longer enabled
+    Remove {
+        skill: String,
+        target: PathBuf,
+        link: PathBuf,
+    },
+}
+
+impl InstallAction {
+    /// Verb used when reporting the action ("create", "overwrite")
+    fn verb(&self) -> &'static str {
+        match self {
+            InstallAction::Create { .. } => "create",
+            InstallAction::Overwrite { .. } => "overwrite",
+            InstallAction::Record { .. } => "record",
+            InstallAction::Remove { .. } => "remove",
+        }
+    }
+}
+
+impl fmt::Display for InstallAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstallAction::Create { source, link, .. }
+            | InstallAction::Overwrite { source, link, .. }
+            | InstallAction::Record { source, link, .. } => {
+                write!(f, "{} -> {}", link.display(), source.display())
+            }
+            InstallAction::Remove { link, .. } => write!(f, "{}", link.display()),
+        }
+    }
+}
+
+/// A link `install` wants to exist, before comparing against the filesystem
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LinkCandidate {
+    skill: String,
+    source: PathBuf,
+    target: PathBuf,
+    link: PathBuf,
+}
+
 /// Install skills by creating symlinks in target directories
 ///
 /// This function:
 /// - Discovers all skills from configured source directories
-/// - Links global skills to global target directories
-/// - Links project skills to project-local target directories
-/// - Respects project `inherit` setting for global skills
-pub fn install(config: &Config, dry_run: bool) -> Result<()> {
+/// - Plans links for global skills in global target directories
+/// - Plans links for project skills in project-local target directories
+/// - Aborts on conflicting links unless `force` is set
+/// - Prunes previously installed links for disabled skills unless `no_prune` is set
+/// - Prints the plan in dry-run mode, applies it otherwise
+pub fn install(config: &Config, dry_run: bool, force: bool, no_prune: bool) -> Result<()> {
     // Discover all available skills
     let skills = skill::discover_all(&config.sources.skills)
         .context("Failed to discover skills from source directories")?;
 
     let skill_map = skill::build_skill_map(skills);
 
+    let actions = plan(config, &skill_map, force, !no_prune)?;
+
     if dry_run {
         println!("{}", "[DRY RUN MODE]".yellow().bold());
         println!();
+        print_plan(&actions);
+        return Ok(());
     }
 
-    // Link global skills
-    install_global_skills(config, &skill_map, dry_run)?;
+    apply(&actions)?;
 
-    // Link project skills
-    install_project_skills(config, &skill_map, dry_run)?;
+    println!();
+    println!("{}", "Done.".green().bold());
 
-    if !dry_run {
-        println!();
-        println!("{}", "Done.".green().bold());
+    Ok(())
+}
+
+/// Drop enabled skills whose `compatibility` doesn't list `target`
+///
+/// Filters the global and per-project skill lists. Skills that no source
+/// provides are kept so they are still reported as missing.
+pub fn retain_compatible(config: &mut Config, target: &str) -> Result<()> {
+    let skills = skill::discover_all(&config.sources.skills)
+        .context("Failed to discover skills from source directories")?;
+    let skill_map = skill::build_skill_map(skills);
+
+    let compatible = |name: &String| {
+        skill_map
+            .get(name)
+            .is_none_or(|s| s.frontmatter.is_compatible_with(target))
+    };
+    config.global.skills.retain(compatible);
+    for project in config.projects.values_mut() {
+        project.skills.retain(compatible);
     }
 
     Ok(())
 }
 
-/// Install global skills to global target directories
-fn install_global_skills(
+/// Compute every filesystem operation `install` would perform
+///
+/// Links that already point at the right skill directory produce no action.
+/// Global targets come first, then projects in path order. When two skills
+/// would be linked at the same path, or one inside the other's link, planning
+/// fails before anything is written; with `force` the last one wins and each
+/// conflict is warned about.
+///
+/// With `prune`, links recorded in a target's manifest that no enabled skill
+/// claims any more are planned for removal. Nothing outside the manifest is
+/// ever removed. A target with a legacy marker has no manifest, so nothing
+/// is pruned there; its correct links are recorded so later installs can.
+pub fn plan(
     config: &Config,
     skill_map: &HashMap<String, skill::Skill>,
-    dry_run: bool,
-) -> Result<()> {
-    println!("{}", "--- Global scope ---".cyan().bold());
+    force: bool,
+    prune: bool,
+) -> Result<Vec<InstallAction>> {
+    let mut candidates = Vec::new();
+    let mut targets = Vec::new();
 
+    // Link global skills
     for target in &config.global.targets {
-        println!("Target: {}", target.display());
-
+        targets.push(target.clone());
         for skill_name in &config.global.skills {
-            install_skill(skill_name, skill_map, target, dry_run)?;
+            plan_skill(skill_name, skill_map, target, &mut candidates)?;
         }
     }
 
-    Ok(())
-}
-
-/// Install project-specific skills to project-local target directories
-fn install_project_skills(
-    config: &Config,
-    skill_map: &HashMap<String, skill::Skill>,
-    dry_run: bool,
-) -> Result<()> {
-    for (project_path, project_config) in &config.projects {
-        println!();
-        println!(
-            "{} {}",
-            "--- Project:".cyan().bold(),
-            project_path.display()
-        );
+    // Link project skills
+    let mut projects: Vec<_> = config.projects.iter().collect();
+    projects.sort_by(|a, b| a.0.cmp(b.0));
 
+    for (project_path, project_config) in projects {
         for subdir in PROJECT_SUBDIRS {
             let target = project_path.join(subdir);
-            println!("Target: {}", target.display());
+            targets.push(target.clone());
 
             // Link global skills if inherit is true
             if project_config.inherit {
                 for skill_name in &config.global.skills {
-                    install_skill(skill_name, skill_map, &target, dry_run)?;
+                    plan_skill(skill_name, skill_map, &target, &mut candidates)?;
                 }
             }
 
             // Link project-specific skills
             for skill_name in &project_config.skills {
-                install_skill(skill_name, skill_map, &target, dry_run)?;
+                plan_skill(skill_name, skill_map, &target, &mut candidates)?;
+            }
+        }
+    }
+
+    let candidates = resolve_conflicts(candidates, force)?;
+
+    let mut actions = Vec::new();
+    if prune {
+        actions.extend(plan_prune(&targets, &candidates)?);
+    }
+    actions.extend(candidates.into_iter().filter_map(classify));
+
+    Ok(actions)
+}
+
+/// Execute a plan produced by [`plan`]
+pub fn apply(actions: &[InstallAction]) -> Result<()> {
+    if actions.is_empty() {
+        println!("All skills already installed.");
+        return Ok(());
+    }
+
+    for action in actions {
+        match action {
+            InstallAction::Create {
+                skill,
+                source,
+                target,
+                ..
+            }
+            | InstallAction::Overwrite {
+                skill,
+                source,
+                target,
+                ..
+            } => {
+                linker::link_skill(skill, source, target).context(format!(
+                    "Failed to link skill '{}' to {}",
+                    skill,
+                    target.display()
+                ))?;
+
+                println!("  {} {} -> {}", "linked:".green(), skill, target.display());
+            }
+            InstallAction::Record {
+                skill,
+                source,
+                target,
+                ..
+            } => {
+                // Linking over a correct symlink only records it in the manifest
+                linker::link_skill(skill, source, target).context(format!(
+                    "Failed to record skill '{}' in {}",
+                    skill,
+                    target.display()
+                ))?;
+
+                println!(
+                    "  {} {} in {}",
+                    "recorded:".green(),
+                    skill,
+                    target.display()
+                );
+            }
+            InstallAction::Remove { skill, target, .. } => {
+                linker::unlink_skill(skill, target).context(format!(
+                    "Failed to remove skill '{}' from {}",
+                    skill,
+                    target.display()
+                ))?;
+
+                println!(
+                    "  {} {} from {}",
+                    "removed:".yellow(),
+                    skill,
+                    target.display()
+                );
             }
         }
     }
@@ -99,43 +270,208 @@ fn install_project_skills(
     Ok(())
 }
 
-/// Install a single skill to a target directory
-fn install_skill(
+/// Print a plan with `would <verb>` prefixes
+fn print_plan(actions: &[InstallAction]) {
+    if actions.is_empty() {
+        println!("Nothing to do: all skills already installed.");
+        return;
+    }
+
+    for action in actions {
+        println!(
+            "  {} {}",
+            format!("would {:<9}", action.verb()).yellow(),
+            action
+        );
+    }
+}
+
+/// Collect the link for a single skill in a target directory
+fn plan_skill(
     skill_name: &str,
     skill_map: &HashMap<String, skill::Skill>,
     target: &Path,
-    dry_run: bool,
+    candidates: &mut Vec<LinkCandidate>,
 ) -> Result<()> {
     let skill = skill_map.get(skill_name).context(format!(
         "Skill '{}' not found in source directories",
         skill_name
     ))?;
 
-    if dry_run {
-        println!(
-            "  {} {} -> {}",
-            "[dry-run]".yellow(),
-            skill.path.display(),
-            target.join(skill_name).display()
-        );
-    } else {
-        linker::link_skill(skill_name, &skill.path, target).context(format!(
-            "Failed to link skill '{}' to {}",
-            skill_name,
-            target.display()
-        ))?;
-
-        println!(
-            "  {} {} -> {}",
-            "linked:".green(),
+    // An archived skill's directory only exists inside the archive, so a
+    // symlink to it would dangle
+    if skill.content.is_some() {
+        anyhow::bail!(
+            "Skill '{}' comes from archive path {} and can't be linked; extract the archive and add the directory as a source to install it",
             skill_name,
-            target.display()
+            skill.path.display()
         );
     }
 
+    candidates.push(LinkCandidate {
+        skill: skill_name.to_string(),
+        source: skill.path.clone(),
+        target: target.to_path_buf(),
+        link: target.join(skill_name),
+    });
+
     Ok(())
 }
 
+/// Group candidates whose links collide and reject groups claimed by different skills
+///
+/// Two links collide when they're the same path or one lies inside the
+/// other, e.g. a skill `vendor` and a prefixed skill `vendor/foo`, whose link
+/// would go through the first one's. Identical candidates (the same skill
+/// reached through both global and project lists) collapse into one. With
+/// `force`, the last candidate of a colliding group wins and a warning names
+/// every skill involved.
+fn resolve_conflicts(candidates: Vec<LinkCandidate>, force: bool) -> Result<Vec<LinkCandidate>> {
+    let mut groups: Vec<Vec<LinkCandidate>> = Vec::new();
+
+    for candidate in candidates {
+        let collides = |group: &Vec<LinkCandidate>| {
+            group
+                .iter()
+                .any(|c| c.link.starts_with(&candidate.link) || candidate.link.starts_with(&c.link))
+        };
+
+        // Merge every group this link collides with into the first of them
+        let colliding: Vec<usize> = (0..groups.len())
+            .filter(|&i| collides(&groups[i]))
+            .collect();
+        match colliding.split_first() {
+            None => groups.push(vec![candidate]),
+            Some((&first, rest)) => {
+                for &i in rest.iter().rev() {
+                    let group = groups.remove(i);
+                    groups[first].extend(group);
+                }
+                if !groups[first].contains(&candidate) {
+                    groups[first].push(candidate);
+                }
+            }
+        }
+    }
+
+    let mut resolved = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for mut group in groups {
+        if group.len() > 1 {
+            let outer = group
+                .iter()
+                .map(|c| c.link.clone())
+                .min_by_key(|link| link.components().count())
+                .unwrap_or_default();
+            let claimants: Vec<String> = group
+                .iter()
+                .map(|c| {
+                    if c.link == outer {
+                        format!("{} ({})", c.skill, c.source.display())
+                    } else {
+                        format!(
+                            "{} at {} ({})",
+                            c.skill,
+                            c.link.display(),
+                            c.source.display()
+                        )
+                    }
+                })
+                .collect();
+            let line = format!("{}: {}", outer.display(), claimants.join(", "));
+
+            if !force {
+                conflicts.push(line);
+                continue;
+            }
+            eprintln!(
+                "{} conflict at {}, using '{}'",
+                "⚠".yellow(),
+                line,
+                group.last().map(|c| c.skill.as_str()).unwrap_or_default()
+            );
+        }
+        if let Some(winner) = group.pop() {
+            resolved.push(winner);
+        }
+    }
+
+    if !conflicts.is_empty() {
+        anyhow::bail!(
+            "{} conflicting link(s), nothing was installed (use --force to resolve last-writer-wins):\n  {}",
+            conflicts.len(),
+            conflicts.join("\n  ")
+        );
+    }
+
+    Ok(resolved)
+}
+
+/// Plan removal of manifest-recorded links no candidate claims any more
+fn plan_prune(targets: &[PathBuf], candidates: &[LinkCandidate]) -> Result<Vec<InstallAction>> {
+    let wanted: BTreeSet<&Path> = candidates.iter().map(|c| c.link.as_path()).collect();
+    let mut seen = BTreeSet::new();
+    let mut actions = Vec::new();
+
+    for target in targets {
+        if !seen.insert(target) {
+            continue;
+        }
+
+        for skill in linker::read_manifest(target)? {
+            let link = target.join(&skill);
+            if !wanted.contains(link.as_path()) && link.is_symlink() {
+                actions.push(InstallAction::Remove {
+                    skill,
+                    target: target.clone(),
+                    link,
+                });
+            }
+        }
+    }
+
+    Ok(actions)
+}
+
+/// Turn a candidate into an action, or nothing if the link is already correct
+///
+/// A correct link in a target with a legacy marker is still recorded, which
+/// replaces the marker with a manifest.
+fn classify(candidate: LinkCandidate) -> Option<InstallAction> {
+    let LinkCandidate {
+        skill,
+        source,
+        target,
+        link,
+    } = candidate;
+
+    if link.is_symlink() && fs::read_link(&link).ok().as_deref() == Some(source.as_path()) {
+        return linker::has_legacy_marker(&target).then_some(InstallAction::Record {
+            skill,
+            source,
+            target,
+            link,
+        });
+    }
+
+    if link.exists() || link.is_symlink() {
+        Some(InstallAction::Overwrite {
+            skill,
+            source,
+            target,
+            link,
+        })
+    } else {
+        Some(InstallAction::Create {
+            skill,
+            source,
+            target,
+            link,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,7 +486,8 @@ mod tests {
 
         Config {
             sources: Sources {
-                skills: vec![skill_source],
+                skills: vec![skill_source.into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![global_target],
@@ -168,6 +505,9 @@ mod tests {
                 projects
             },
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         }
     }
 
@@ -193,6 +533,31 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn should_retain_only_compatible_skills() {
+        // Given: test-skill targets opencode only, another-skill declares nothing
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        fs::write(
+            temp.path().join("skills/test-skill/SKILL.md"),
+            "---\nname: test-skill\ndescription: Test skill\ncompatibility: opencode\n---\n",
+        )
+        .unwrap();
+        let mut config = create_test_config(&temp);
+        config.global.skills.push("missing-skill".to_string());
+
+        // When
+        let mut claude = config.clone();
+        retain_compatible(&mut claude, "claude-code").unwrap();
+        retain_compatible(&mut config, "opencode").unwrap();
+
+        // Then
+        assert_eq!(claude.global.skills, vec!["missing-skill"]);
+        assert_eq!(config.global.skills, vec!["test-skill", "missing-skill"]);
+        let project = &claude.projects[&temp.path().join("project")];
+        assert_eq!(project.skills, vec!["another-skill"]);
+    }
+
     #[test]
     fn should_install_global_skills() {
         // Given
@@ -201,7 +566,7 @@ mod tests {
         let config = create_test_config(&temp);
 
         // When
-        install(&config, false).unwrap();
+        install(&config, false, false, false).unwrap();
 
         // Then
         let global_target = temp.path().join("global");
@@ -217,7 +582,7 @@ mod tests {
         let config = create_test_config(&temp);
 
         // When
-        install(&config, false).unwrap();
+        install(&config, false, false, false).unwrap();
 
         // Then
         let project_target = temp.path().join("project/.claude/skills");
@@ -237,7 +602,7 @@ mod tests {
         config.projects.get_mut(&project_path).unwrap().inherit = false;
 
         // When
-        install(&config, false).unwrap();
+        install(&config, false, false, false).unwrap();
 
         // Then
         let project_target = temp.path().join("project/.claude/skills");
@@ -253,7 +618,7 @@ mod tests {
         let config = create_test_config(&temp);
 
         // When
-        install(&config, false).unwrap();
+        install(&config, false, false, false).unwrap();
 
         // Then
         for subdir in PROJECT_SUBDIRS {
@@ -271,13 +636,341 @@ mod tests {
         let config = create_test_config(&temp);
 
         // When
-        install(&config, true).unwrap();
+        install(&config, true, false, false).unwrap();
 
         // Then
         let global_target = temp.path().join("global");
         assert!(!global_target.exists());
     }
 
+    #[test]
+    fn should_plan_links_for_every_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let config = create_test_config(&temp);
+        let skills = skill::discover_all(&config.sources.skills).unwrap();
+        let skill_map = skill::build_skill_map(skills);
+
+        // When
+        let actions = plan(&config, &skill_map, false, true).unwrap();
+
+        // Then
+        assert_eq!(actions.len(), 7); // 1 global + 2 per project subdir
+        assert_eq!(
+            actions[0],
+            InstallAction::Create {
+                skill: "test-skill".to_string(),
+                source: temp.path().join("skills/test-skill"),
+                target: temp.path().join("global"),
+                link: temp.path().join("global/test-skill"),
+            }
+        );
+        assert!(actions.contains(&InstallAction::Create {
+            skill: "another-skill".to_string(),
+            source: temp.path().join("skills/another-skill"),
+            target: temp.path().join("project/.agents/skills"),
+            link: temp.path().join("project/.agents/skills/another-skill"),
+        }));
+    }
+
+    #[test]
+    fn should_plan_nothing_when_already_installed() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let config = create_test_config(&temp);
+        install(&config, false, false, false).unwrap();
+        let skills = skill::discover_all(&config.sources.skills).unwrap();
+        let skill_map = skill::build_skill_map(skills);
+
+        // When
+        let actions = plan(&config, &skill_map, false, true).unwrap();
+
+        // Then
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn should_link_prefixed_skill_inside_target_and_converge() {
+        // Given: foo from a source prefixed `vendor`
+        let temp = TempDir::new().unwrap();
+        let vendor_dir = temp.path().join("vendor-skills/foo");
+        fs::create_dir_all(&vendor_dir).unwrap();
+        fs::write(
+            vendor_dir.join("SKILL.md"),
+            "---\nname: foo\ndescription: Vendored skill\n---\n",
+        )
+        .unwrap();
+        let mut config = create_test_config(&temp);
+        config.projects.clear();
+        config.sources.skills = vec![crate::config::SkillSource {
+            path: temp.path().join("vendor-skills"),
+            prefix: Some("vendor".to_string()),
+        }];
+        config.global.skills = vec!["vendor/foo".to_string()];
+        let global_target = temp.path().join("global");
+
+        // When
+        install(&config, false, false, false).unwrap();
+
+        // Then
+        let link = global_target.join("vendor/foo");
+        assert_eq!(fs::read_link(&link).unwrap(), vendor_dir);
+        assert!(!global_target.join("vendor/vendor").exists());
+        assert!(linker::read_manifest(&global_target)
+            .unwrap()
+            .contains("vendor/foo"));
+        assert!(!linker::is_managed(&global_target.join("vendor")));
+        let skill_map =
+            skill::build_skill_map(skill::discover_all(&config.sources.skills).unwrap());
+        assert!(plan(&config, &skill_map, false, true).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "archives")]
+    #[test]
+    fn should_refuse_to_install_archived_skill() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let archive = temp.path().join("bundle.tar.gz");
+        crate::skill::archive::write_tar_gz(
+            &archive,
+            &[(
+                "test-skill/SKILL.md",
+                "---\nname: test-skill\ndescription: Archived skill\n---\n",
+            )],
+        );
+        let mut config = create_test_config(&temp);
+        config.projects.clear();
+        config.sources.skills = vec![archive.clone().into()];
+
+        // When
+        let result = install(&config, false, false, false);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("test-skill"));
+        assert!(err.contains(&archive.display().to_string()));
+        assert!(!temp.path().join("global/test-skill").is_symlink());
+    }
+
+    #[test]
+    fn should_plan_overwrite_for_stale_symlink() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let mut config = create_test_config(&temp);
+        config.projects.clear();
+        let global_target = temp.path().join("global");
+        fs::create_dir_all(&global_target).unwrap();
+        std::os::unix::fs::symlink(
+            temp.path().join("elsewhere"),
+            global_target.join("test-skill"),
+        )
+        .unwrap();
+        let skills = skill::discover_all(&config.sources.skills).unwrap();
+        let skill_map = skill::build_skill_map(skills);
+
+        // When
+        let actions = plan(&config, &skill_map, false, true).unwrap();
+
+        // Then
+        assert_eq!(
+            actions,
+            vec![InstallAction::Overwrite {
+                skill: "test-skill".to_string(),
+                source: temp.path().join("skills/test-skill"),
+                target: global_target.clone(),
+                link: global_target.join("test-skill"),
+            }]
+        );
+    }
+
+    #[test]
+    fn should_prune_links_of_disabled_skill() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let mut config = create_test_config(&temp);
+        install(&config, false, false, false).unwrap();
+        let project_path = temp.path().join("project");
+        let user_link = project_path.join(".claude/skills/user-link");
+        std::os::unix::fs::symlink(temp.path().join("skills/another-skill"), &user_link).unwrap();
+
+        // When
+        config
+            .projects
+            .get_mut(&project_path)
+            .unwrap()
+            .skills
+            .clear();
+        install(&config, false, false, false).unwrap();
+
+        // Then
+        for subdir in PROJECT_SUBDIRS {
+            let target = project_path.join(subdir);
+            assert!(!target.join("another-skill").exists());
+            assert!(target.join("test-skill").is_symlink());
+        }
+        assert!(temp.path().join("global/test-skill").is_symlink());
+        assert!(user_link.is_symlink()); // not in the manifest, left alone
+    }
+
+    #[test]
+    fn should_not_prune_legacy_marker_target_and_record_its_links() {
+        // Given: a pre-manifest marker next to a user's own link
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let mut config = create_test_config(&temp);
+        config.projects.clear();
+        let global_target = temp.path().join("global");
+        fs::create_dir_all(&global_target).unwrap();
+        fs::write(global_target.join(".managed-by-loadout"), "").unwrap();
+        std::os::unix::fs::symlink(
+            temp.path().join("skills/test-skill"),
+            global_target.join("test-skill"),
+        )
+        .unwrap();
+        let own_link = global_target.join("my-own-link");
+        std::os::unix::fs::symlink(temp.path().join("skills"), &own_link).unwrap();
+        let skill_map =
+            skill::build_skill_map(skill::discover_all(&config.sources.skills).unwrap());
+
+        // When
+        let actions = plan(&config, &skill_map, false, true).unwrap();
+        apply(&actions).unwrap();
+
+        // Then
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].verb(), "record");
+        assert!(own_link.is_symlink());
+        assert!(linker::read_manifest(&global_target)
+            .unwrap()
+            .contains("test-skill"));
+        assert!(plan(&config, &skill_map, false, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_keep_links_of_disabled_skill_with_no_prune() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let mut config = create_test_config(&temp);
+        install(&config, false, false, false).unwrap();
+        let project_path = temp.path().join("project");
+
+        // When
+        config
+            .projects
+            .get_mut(&project_path)
+            .unwrap()
+            .skills
+            .clear();
+        install(&config, false, false, true).unwrap();
+
+        // Then
+        assert!(project_path
+            .join(".claude/skills/another-skill")
+            .is_symlink());
+    }
+
+    #[test]
+    fn should_reject_two_skills_linked_at_same_path() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let link = temp.path().join("global/shared");
+        let candidates = vec![
+            LinkCandidate {
+                skill: "team-shared".to_string(),
+                source: temp.path().join("team/shared"),
+                target: temp.path().join("global"),
+                link: link.clone(),
+            },
+            LinkCandidate {
+                skill: "personal-shared".to_string(),
+                source: temp.path().join("personal/shared"),
+                target: temp.path().join("global"),
+                link: link.clone(),
+            },
+        ];
+
+        // When
+        let result = resolve_conflicts(candidates, false);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains(&link.display().to_string()));
+        assert!(err.contains("team-shared"));
+        assert!(err.contains("personal-shared"));
+        assert!(err.contains("--force"));
+    }
+
+    #[test]
+    fn should_resolve_conflict_last_writer_wins_with_force() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let link = temp.path().join("global/shared");
+        let loser = LinkCandidate {
+            skill: "team-shared".to_string(),
+            source: temp.path().join("team/shared"),
+            target: temp.path().join("global"),
+            link: link.clone(),
+        };
+        let winner = LinkCandidate {
+            skill: "personal-shared".to_string(),
+            source: temp.path().join("personal/shared"),
+            target: temp.path().join("global"),
+            link,
+        };
+
+        // When
+        let resolved = resolve_conflicts(vec![loser, winner.clone()], true).unwrap();
+
+        // Then
+        assert_eq!(resolved, vec![winner]);
+    }
+
+    #[test]
+    fn should_reject_skill_linked_inside_another_skills_link() {
+        // Given: an unprefixed skill `vendor` and foo from a source prefixed `vendor`
+        let temp = TempDir::new().unwrap();
+        for (dir, name) in [("skills/vendor", "vendor"), ("vendor-skills/foo", "foo")] {
+            let skill_dir = temp.path().join(dir);
+            fs::create_dir_all(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test skill\n---\n", name),
+            )
+            .unwrap();
+        }
+        let mut config = create_test_config(&temp);
+        config.projects.clear();
+        config.sources.skills = vec![
+            temp.path().join("skills").into(),
+            crate::config::SkillSource {
+                path: temp.path().join("vendor-skills"),
+                prefix: Some("vendor".to_string()),
+            },
+        ];
+        config.global.skills = vec!["vendor".to_string(), "vendor/foo".to_string()];
+        let skill_map =
+            skill::build_skill_map(skill::discover_all(&config.sources.skills).unwrap());
+
+        // When
+        let result = plan(&config, &skill_map, false, true);
+        let forced = plan(&config, &skill_map, true, true).unwrap();
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains(&temp.path().join("global/vendor").display().to_string()));
+        assert!(err.contains("vendor/foo"));
+        assert_eq!(forced.len(), 1);
+        assert!(matches!(
+            &forced[0],
+            InstallAction::Create { skill, .. } if skill == "vendor/foo"
+        ));
+    }
+
     #[test]
     fn should_return_error_when_skill_not_found() {
         // Given
@@ -289,7 +982,7 @@ mod tests {
         config.global.skills.push("nonexistent".to_string());
 
         // When
-        let result = install(&config, false);
+        let result = install(&config, false, false, false);
 
         // Then
         assert!(result.is_err());
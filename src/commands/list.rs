@@ -1,39 +1,69 @@
 //! List command implementation
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use colored::Colorize;
-use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::config::{Config, Project, SkillSource};
+use crate::skill::{self, Skill};
+
+/// Ordering for skills within each scope of the default listing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortKey {
+    #[default]
+    Name,
+    Path,
+    Source,
+}
 
-use crate::config::Config;
-use crate::skill;
+impl SortKey {
+    pub fn parse_key(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "name" => Some(Self::Name),
+            "path" => Some(Self::Path),
+            "source" => Some(Self::Source),
+            _ => None,
+        }
+    }
+}
 
 pub enum ListMode {
-    Default,
-    Groups,
+    Default { sort: SortKey, show_pipelines: bool },
+    Count,
+    Groups { json: bool },
     Refs(String),
     Missing,
     Tags,
+    Agents,
     Tag(String),
     Pipelines,
     Pipeline(String),
+    Impact(String),
+    Orphans,
 }
 
 /// List enabled skills per scope
 pub fn list(config: &Config, mode: ListMode) -> Result<()> {
     match mode {
-        ListMode::Default => list_default(config),
-        ListMode::Groups => list_groups(config),
+        ListMode::Default {
+            sort,
+            show_pipelines,
+        } => list_default(config, sort, show_pipelines),
+        ListMode::Count => list_count(config),
+        ListMode::Groups { json } => list_groups(config, json),
         ListMode::Refs(skill_name) => list_refs(config, &skill_name),
         ListMode::Missing => list_missing(config),
         ListMode::Tags => list_tags(config),
+        ListMode::Agents => list_agents(config),
         ListMode::Tag(tag) => list_by_tag(config, &tag),
         ListMode::Pipelines => list_pipelines(config),
         ListMode::Pipeline(name) => list_pipeline(config, &name),
+        ListMode::Impact(skill_name) => list_impact(config, &skill_name),
+        ListMode::Orphans => list_orphans(config),
     }
 }
 
-fn list_default(config: &Config) -> Result<()> {
+fn list_default(config: &Config, sort: SortKey, show_pipelines: bool) -> Result<()> {
     // Discover all available skills
     let skills = skill::discover_all(&config.sources.skills)?;
     let skill_map = skill::build_skill_map(skills);
@@ -41,13 +71,20 @@ fn list_default(config: &Config) -> Result<()> {
     // List global skills
     println!("{}", "--- Global scope ---".cyan().bold());
     println!("Skills: {}", config.global.skills.len());
-    for skill_name in &config.global.skills {
-        if let Some(skill) = skill_map.get(skill_name) {
+    let entries = sorted_entries(
+        &config.global.skills,
+        &skill_map,
+        &config.sources.skills,
+        sort,
+    );
+    for (skill_name, found) in entries {
+        if let Some(skill) = found {
             println!(
-                "  {} {} ({})",
+                "  {} {} ({}){}",
                 "✓".green(),
                 skill_name,
-                skill.path.display().to_string().dimmed()
+                skill.path.display().to_string().dimmed(),
+                skill_suffix(skill, show_pipelines)
             );
         } else {
             println!("  {} {} {}", "✗".red(), skill_name, "(not found)".red());
@@ -63,19 +100,7 @@ fn list_default(config: &Config) -> Result<()> {
             project_path.display()
         );
 
-        let mut all_skills = Vec::new();
-
-        // Add global skills if inherited
-        if project_config.inherit {
-            all_skills.extend(config.global.skills.clone());
-        }
-
-        // Add project-specific skills
-        all_skills.extend(project_config.skills.clone());
-
-        // Deduplicate
-        all_skills.sort();
-        all_skills.dedup();
+        let all_skills = project_skill_names(config, project_config);
 
         println!(
             "Skills: {} (inherit: {})",
@@ -87,19 +112,21 @@ fn list_default(config: &Config) -> Result<()> {
             }
         );
 
-        for skill_name in &all_skills {
-            if let Some(skill) = skill_map.get(skill_name) {
+        let entries = sorted_entries(&all_skills, &skill_map, &config.sources.skills, sort);
+        for (skill_name, found) in entries {
+            if let Some(skill) = found {
                 let source = if config.global.skills.contains(skill_name) {
                     "global".dimmed()
                 } else {
                     "project".dimmed()
                 };
                 println!(
-                    "  {} {} ({}, {})",
+                    "  {} {} ({}, {}){}",
                     "✓".green(),
                     skill_name,
                     source,
-                    skill.path.display().to_string().dimmed()
+                    skill.path.display().to_string().dimmed(),
+                    skill_suffix(skill, show_pipelines)
                 );
             } else {
                 println!("  {} {} {}", "✗".red(), skill_name, "(not found)".red());
@@ -110,26 +137,146 @@ fn list_default(config: &Config) -> Result<()> {
     Ok(())
 }
 
-#[cfg(feature = "graph")]
-fn list_groups(config: &Config) -> Result<()> {
-    use crate::graph::SkillGraph;
-
+fn list_count(config: &Config) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
-    let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
-    let mut crossrefs = HashMap::new();
-
-    for skill in &skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
-        if !refs.is_empty() {
-            crossrefs.insert(skill.name.clone(), refs);
+    let skill_map = skill::build_skill_map(skills);
+
+    for line in count_lines(config, &skill_map) {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// `scope: N found, M missing` for the global scope, then each project in path order
+fn count_lines(config: &Config, skill_map: &HashMap<String, Skill>) -> Vec<String> {
+    let line = |scope: String, names: &[String]| {
+        let found = names.iter().filter(|n| skill_map.contains_key(*n)).count();
+        format!(
+            "{}: {} found, {} missing",
+            scope,
+            found,
+            names.len() - found
+        )
+    };
+
+    let mut projects: Vec<_> = config.projects.iter().collect();
+    projects.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut lines = vec![line("global".to_string(), &config.global.skills)];
+    for (project_path, project_config) in projects {
+        lines.push(line(
+            project_path.display().to_string(),
+            &project_skill_names(config, project_config),
+        ));
+    }
+    lines
+}
+
+/// `[pipelines: a, b]` for a skill that belongs to any pipeline, names sorted
+fn pipeline_annotation(skill: &Skill) -> Option<String> {
+    let pipelines = skill.frontmatter.pipeline.as_ref()?;
+    if pipelines.is_empty() {
+        return None;
+    }
+    let mut names: Vec<&str> = pipelines.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    Some(format!("[pipelines: {}]", names.join(", ")))
+}
+
+/// `[deprecated → replacement]`, or `[deprecated]` without a replacement
+fn deprecation_annotation(skill: &Skill) -> Option<String> {
+    let deprecated = skill.frontmatter.deprecated.as_ref()?;
+    Some(match &deprecated.replacement {
+        Some(replacement) => format!("[deprecated → {}]", replacement),
+        None => "[deprecated]".to_string(),
+    })
+}
+
+/// Annotations printed after a skill's path in the default listing
+fn skill_suffix(skill: &Skill, show_pipelines: bool) -> String {
+    let mut suffix = String::new();
+    if let Some(annotation) = deprecation_annotation(skill) {
+        suffix.push_str(&format!(" {}", annotation.dimmed()));
+    }
+    if let Some(annotation) = pipeline_annotation(skill).filter(|_| show_pipelines) {
+        suffix.push_str(&format!(" {}", annotation.yellow()));
+    }
+    suffix
+}
+
+/// Skills enabled for a project: its own plus inherited globals, deduplicated
+fn project_skill_names(config: &Config, project_config: &Project) -> Vec<String> {
+    let mut all_skills = Vec::new();
+
+    // Add global skills if inherited
+    if project_config.inherit {
+        all_skills.extend(config.global.skills.clone());
+    }
+
+    // Add project-specific skills
+    all_skills.extend(project_config.skills.clone());
+
+    // Deduplicate
+    all_skills.sort();
+    all_skills.dedup();
+    all_skills
+}
+
+/// Pair enabled skill names with their discovered skills, ordered by `sort`
+///
+/// Ties break on skill name. Missing skills have no path or source, so under
+/// those keys they sort ahead of found skills, still ordered by name.
+fn sorted_entries<'a>(
+    names: &'a [String],
+    skill_map: &'a HashMap<String, Skill>,
+    sources: &[SkillSource],
+    sort: SortKey,
+) -> Vec<(&'a String, Option<&'a Skill>)> {
+    let mut entries: Vec<(&String, Option<&Skill>)> = names
+        .iter()
+        .map(|name| (name, skill_map.get(name)))
+        .collect();
+
+    match sort {
+        SortKey::Name => entries.sort_by(|a, b| a.0.cmp(b.0)),
+        SortKey::Path => {
+            entries.sort_by(|a, b| {
+                let path_a = a.1.map(|s| &s.path);
+                let path_b = b.1.map(|s| &s.path);
+                path_a.cmp(&path_b).then_with(|| a.0.cmp(b.0))
+            });
+        }
+        SortKey::Source => {
+            // Position of the first configured source directory containing the skill
+            let source_index = |skill: Option<&Skill>| {
+                skill.and_then(|s| sources.iter().position(|src| s.path.starts_with(&src.path)))
+            };
+            entries.sort_by(|a, b| {
+                source_index(a.1)
+                    .cmp(&source_index(b.1))
+                    .then_with(|| a.0.cmp(b.0))
+            });
         }
     }
 
+    entries
+}
+
+#[cfg(feature = "graph")]
+fn list_groups(config: &Config, json: bool) -> Result<()> {
+    use crate::graph::SkillGraph;
+
+    let (skills, crossrefs) =
+        skill::analyze(&config.sources.skills, &config.detection.detectors())?;
+
     let graph = SkillGraph::from_crossrefs(&crossrefs);
 
+    if json {
+        println!("{}", groups_json(&skills, &graph.clusters));
+        return Ok(());
+    }
+
     println!("{}", "--- Skills by cluster ---".cyan().bold());
 
     if graph.clusters.is_empty() {
@@ -144,13 +291,13 @@ fn list_groups(config: &Config) -> Result<()> {
             println!("  • {}", name);
         }
     } else {
-        for (i, cluster) in graph.clusters.iter().enumerate() {
+        for (name, cluster) in graph.named_clusters() {
             println!(
                 "\n{} {}",
-                format!("Cluster {}:", i + 1).yellow().bold(),
+                format!("{}:", name).yellow().bold(),
                 format!("({} skills)", cluster.len()).dimmed()
             );
-            for skill in cluster {
+            for skill in &cluster {
                 println!("  • {}", skill);
             }
         }
@@ -174,10 +321,44 @@ fn list_groups(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Render clusters and the remaining skills as
+/// `{"clusters": [[names]], "unclustered": [names]}`, all sorted
+#[cfg(feature = "graph")]
+fn groups_json(skills: &[Skill], clusters: &[Vec<String>]) -> String {
+    let mut sorted: Vec<Vec<&String>> = clusters
+        .iter()
+        .map(|cluster| {
+            let mut names: Vec<&String> = cluster.iter().collect();
+            names.sort();
+            names
+        })
+        .collect();
+    sorted.sort();
+
+    let clustered: HashSet<&String> = clusters.iter().flatten().collect();
+    let mut unclustered: Vec<&String> = skills
+        .iter()
+        .map(|s| &s.name)
+        .filter(|name| !clustered.contains(name))
+        .collect();
+    unclustered.sort();
+
+    let output = serde_json::json!({
+        "clusters": sorted,
+        "unclustered": unclustered,
+    });
+    serde_json::to_string_pretty(&output).unwrap_or_default()
+}
+
 #[cfg(not(feature = "graph"))]
-fn list_groups(config: &Config) -> Result<()> {
+fn list_groups(config: &Config, json: bool) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
 
+    if json {
+        println!("{}", groups_json(&skills));
+        return Ok(());
+    }
+
     println!(
         "{}",
         "--- Skills (cluster detection unavailable) ---"
@@ -198,6 +379,20 @@ fn list_groups(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Render every skill as unclustered, in the same shape as the graph build
+#[cfg(not(feature = "graph"))]
+fn groups_json(skills: &[Skill]) -> String {
+    let mut names: Vec<&String> = skills.iter().map(|s| &s.name).collect();
+    names.sort();
+
+    let output = serde_json::json!({
+        "clusters": [],
+        "unclustered": names,
+        "note": "graph feature disabled",
+    });
+    serde_json::to_string_pretty(&output).unwrap_or_default()
+}
+
 fn list_refs(config: &Config, skill_name: &str) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
     let skill_map = skill::build_skill_map(skills.clone());
@@ -208,30 +403,25 @@ fn list_refs(config: &Config, skill_name: &str) -> Result<()> {
     }
 
     // Extract all cross-references
-    let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
-    let mut crossrefs: HashMap<String, Vec<skill::CrossRef>> = HashMap::new();
-    for skill in &skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
-        if !refs.is_empty() {
-            crossrefs.insert(skill.name.clone(), refs);
-        }
-    }
+    let crossrefs = skill::extract_all_references(&skills, &config.detection.detectors())?;
 
     // Find outgoing references (skills this skill references)
-    let outgoing: Vec<String> = crossrefs
+    let mut outgoing: Vec<(String, usize)> = crossrefs
         .get(skill_name)
-        .map(|refs| refs.iter().map(|r| r.target.clone()).collect())
+        .map(|refs| refs.iter().map(|r| (r.target.clone(), r.line)).collect())
         .unwrap_or_default();
+    outgoing.sort();
 
     // Find incoming references (skills that reference this skill)
-    let incoming: Vec<String> = crossrefs
+    let mut incoming: Vec<(String, usize)> = crossrefs
         .iter()
-        .filter(|(_, refs)| refs.iter().any(|r| r.target == skill_name))
-        .map(|(name, _)| name.clone())
+        .flat_map(|(name, refs)| {
+            refs.iter()
+                .filter(|r| r.target == skill_name)
+                .map(move |r| (name.clone(), r.line))
+        })
         .collect();
+    incoming.sort();
 
     println!(
         "{} {}",
@@ -243,8 +433,8 @@ fn list_refs(config: &Config, skill_name: &str) -> Result<()> {
     if outgoing.is_empty() {
         println!("  {}", "(none)".dimmed());
     } else {
-        for target in &outgoing {
-            println!("  → {}", target);
+        for (target, line) in &outgoing {
+            println!("  → {} {}", target, format!("(ref:line {})", line).dimmed());
         }
     }
 
@@ -252,8 +442,8 @@ fn list_refs(config: &Config, skill_name: &str) -> Result<()> {
     if incoming.is_empty() {
         println!("  {}", "(none)".dimmed());
     } else {
-        for source in &incoming {
-            println!("  ← {}", source);
+        for (source, line) in &incoming {
+            println!("  ← {} {}", source, format!("(ref:line {})", line).dimmed());
         }
     }
 
@@ -317,44 +507,135 @@ fn list_tags(config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn list_by_tag(config: &Config, tag: &str) -> Result<()> {
+fn list_agents(config: &Config) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
+    let groups = group_by_agent(&skills);
 
-    let matching: Vec<_> = skills
+    println!("{}", "--- Skills by agent ---".cyan().bold());
+    println!();
+
+    // Agent-less skills sort first in the map but print last
+    let assigned = groups
         .iter()
-        .filter(|s| {
-            s.frontmatter
-                .tags
-                .as_ref()
-                .map(|t| t.contains(&tag.to_string()))
-                .unwrap_or(false)
-        })
-        .collect();
+        .filter_map(|(a, s)| a.as_ref().map(|a| (a, s)));
+    for (agent, names) in assigned {
+        println!(
+            "  {} {} {}",
+            agent.yellow(),
+            format!("({})", names.len()).dimmed(),
+            names.join(", ").dimmed()
+        );
+    }
+    if let Some(names) = groups.get(&None) {
+        println!(
+            "  {} {} {}",
+            "(unassigned)".dimmed(),
+            format!("({})", names.len()).dimmed(),
+            names.join(", ").dimmed()
+        );
+    }
 
-    println!(
-        "{} {}",
-        "--- Skills tagged".cyan().bold(),
-        tag.cyan().bold()
-    );
+    Ok(())
+}
+
+/// Group skill names by their `agent` frontmatter value
+///
+/// Names within a group are sorted. Skills with no (or a blank) agent are
+/// grouped under `None`.
+pub fn group_by_agent(skills: &[Skill]) -> BTreeMap<Option<String>, Vec<String>> {
+    let mut groups: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+    for s in skills {
+        let agent = s
+            .frontmatter
+            .agent
+            .as_deref()
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .map(str::to_string);
+        groups.entry(agent).or_default().push(s.name.clone());
+    }
+
+    for names in groups.values_mut() {
+        names.sort();
+    }
+    groups
+}
+
+/// List enabled skills carrying a tag, grouped by scope
+fn list_by_tag(config: &Config, tag: &str) -> Result<()> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    let skill_map = skill::build_skill_map(skills);
 
+    for (i, (scope, matching)) in tagged_by_scope(config, &skill_map, tag).iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{}", scope.cyan().bold());
+        print_tagged(matching, tag);
+    }
+
+    Ok(())
+}
+
+/// Section headers with the enabled skills carrying `tag`, global scope first
+fn tagged_by_scope<'a>(
+    config: &Config,
+    skill_map: &'a HashMap<String, Skill>,
+    tag: &str,
+) -> Vec<(String, Vec<&'a Skill>)> {
+    let mut sections = vec![(
+        "--- Global scope ---".to_string(),
+        tagged_skills(&config.global.skills, skill_map, tag),
+    )];
+
+    for (project_path, project_config) in &config.projects {
+        let names = project_skill_names(config, project_config);
+        sections.push((
+            format!("--- Project: {}", project_path.display()),
+            tagged_skills(&names, skill_map, tag),
+        ));
+    }
+
+    sections
+}
+
+fn print_tagged(matching: &[&Skill], tag: &str) {
     if matching.is_empty() {
-        println!("\n{}", "No skills found with this tag.".dimmed());
-        return Ok(());
+        println!("{}", format!("No skills with tag '{}'", tag).dimmed());
+        return;
     }
 
-    println!();
-    for s in &matching {
-        let desc = &s.frontmatter.description;
-        let truncated: String = desc.chars().take(80).collect();
-        let suffix = if desc.len() > 80 { "..." } else { "" };
+    for s in matching {
         println!(
-            "  {} {}",
-            s.name.green(),
-            format!("— {}{}", truncated, suffix).dimmed()
+            "  {} {} ({})",
+            "✓".green(),
+            s.name,
+            s.path.display().to_string().dimmed()
         );
     }
+}
 
-    Ok(())
+/// Enabled skills whose frontmatter tags contain `tag`, sorted by name
+///
+/// Missing skills are skipped since their tags are unknown.
+fn tagged_skills<'a>(
+    names: &[String],
+    skill_map: &'a HashMap<String, Skill>,
+    tag: &str,
+) -> Vec<&'a Skill> {
+    let mut matching: Vec<&Skill> = names
+        .iter()
+        .filter_map(|name| skill_map.get(name))
+        .filter(|s| {
+            s.frontmatter
+                .tags
+                .as_ref()
+                .map(|t| t.iter().any(|t| t == tag))
+                .unwrap_or(false)
+        })
+        .collect();
+    matching.sort_by(|a, b| a.name.cmp(&b.name));
+    matching
 }
 
 fn list_pipelines(config: &Config) -> Result<()> {
@@ -390,17 +671,24 @@ fn list_pipelines(config: &Config) -> Result<()> {
     for name in names {
         let stages = &pipelines[name];
         let mut sorted = stages.clone();
-        sorted.sort_by_key(|s| s.2);
+        // Break order ties on skill name so output is stable
+        sorted.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| a.0.cmp(&b.0)));
 
         let stage_summary: Vec<String> = sorted
             .iter()
             .map(|(skill, stage, _)| format!("{} ({})", skill, stage))
             .collect();
 
+        let collisions = if has_order_collisions(stages) {
+            format!(" {}", "⚠ collisions".yellow())
+        } else {
+            String::new()
+        };
         println!(
-            "\n  {} {}",
+            "\n  {} {}{}",
             name.yellow().bold(),
-            format!("({} skills)", stages.len()).dimmed()
+            format!("({} skills)", stages.len()).dimmed(),
+            collisions
         );
         println!("  {}", stage_summary.join(" → ").dimmed());
     }
@@ -408,25 +696,23 @@ fn list_pipelines(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Whether two or more stages in a pipeline share an order value
+fn has_order_collisions(stages: &[(String, String, u32)]) -> bool {
+    let mut seen = HashSet::new();
+    stages.iter().any(|(_, _, order)| !seen.insert(*order))
+}
+
 fn list_pipeline(config: &Config, pipeline_name: &str) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
 
-    // Collect skills in this pipeline
-    let mut stages: Vec<(String, skill::PipelineStage)> = Vec::new();
-    let mut all_pipeline_names: HashSet<String> = HashSet::new();
-
-    for s in &skills {
-        if let Some(pipeline) = &s.frontmatter.pipeline {
-            for name in pipeline.keys() {
-                all_pipeline_names.insert(name.clone());
-            }
-            if let Some(stage) = pipeline.get(pipeline_name) {
-                stages.push((s.name.clone(), stage.clone()));
-            }
-        }
-    }
+    let stages = skill::pipeline_stages(&skills, pipeline_name);
 
     if stages.is_empty() {
+        let all_pipeline_names: HashSet<&str> = skills
+            .iter()
+            .filter_map(|s| s.frontmatter.pipeline.as_ref())
+            .flat_map(|p| p.keys().map(String::as_str))
+            .collect();
         let mut available: Vec<_> = all_pipeline_names.into_iter().collect();
         available.sort();
         if available.is_empty() {
@@ -439,9 +725,6 @@ fn list_pipeline(config: &Config, pipeline_name: &str) -> Result<()> {
         );
     }
 
-    // Sort by order
-    stages.sort_by_key(|(_, stage)| stage.order);
-
     println!(
         "{} {}",
         "--- Pipeline:".cyan().bold(),
@@ -487,31 +770,104 @@ fn list_pipeline(config: &Config, pipeline_name: &str) -> Result<()> {
     Ok(())
 }
 
-fn list_missing(config: &Config) -> Result<()> {
+#[cfg(feature = "graph")]
+fn list_impact(config: &Config, skill_name: &str) -> Result<()> {
+    let affected = impact_of_removing(config, skill_name)?;
+
+    println!(
+        "{} {}",
+        "--- Impact of removing".cyan().bold(),
+        skill_name.cyan().bold()
+    );
+
+    if affected.is_empty() {
+        println!("\n{}", "No skills depend on this skill.".green());
+    } else {
+        println!(
+            "\n{} skills depend on it:\n",
+            affected.len().to_string().yellow().bold()
+        );
+        for name in &affected {
+            println!("  • {}", name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Names of the skills that transitively depend on a skill, sorted
+#[cfg(feature = "graph")]
+fn impact_of_removing(config: &Config, skill_name: &str) -> Result<Vec<String>> {
+    use crate::graph::SkillGraph;
+
     let skills = skill::discover_all(&config.sources.skills)?;
-    let skill_map = skill::build_skill_map(skills.clone());
-    let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    if !skills.iter().any(|s| s.name == skill_name) {
+        anyhow::bail!("Skill '{}' not found in any source", skill_name);
+    }
 
-    // Extract all cross-references
-    let mut all_referenced: HashSet<String> = HashSet::new();
-    for skill in &skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)
-            .context(format!("Failed to read {}", skill_md.display()))?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
-        for r in refs {
-            all_referenced.insert(r.target);
+    let crossrefs = skill::extract_all_references(&skills, &config.detection.detectors())?;
+
+    let graph = SkillGraph::from_skills(&crossrefs, &skills);
+    Ok(graph.impact_of(skill_name))
+}
+
+#[cfg(not(feature = "graph"))]
+fn list_impact(_config: &Config, _skill_name: &str) -> Result<()> {
+    anyhow::bail!("Impact analysis requires the graph feature (install with --features graph)")
+}
+
+#[cfg(feature = "graph")]
+fn list_orphans(config: &Config) -> Result<()> {
+    use crate::graph::SkillGraph;
+
+    let (skills, crossrefs) =
+        skill::analyze(&config.sources.skills, &config.detection.detectors())?;
+
+    let graph = SkillGraph::from_skills(&crossrefs, &skills);
+    let orphans = graph.unconnected();
+
+    println!("{}", "--- Orphaned skills ---".cyan().bold());
+
+    if orphans.is_empty() {
+        println!(
+            "\n{}",
+            "Every skill references or is referenced by another.".green()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{} skills with no references in or out:\n",
+        orphans.len().to_string().yellow().bold()
+    );
+    for name in &orphans {
+        if config.global.skills.contains(name) {
+            println!(
+                "  {} {} {}",
+                "•".yellow(),
+                name.yellow(),
+                "(enabled)".dimmed()
+            );
+        } else {
+            println!("  • {}", name);
         }
     }
+    println!(
+        "\n{}",
+        "Run `loadout graph` to see these skills in context.".dimmed()
+    );
 
-    // Find dangling references
-    let mut missing: Vec<String> = all_referenced
-        .iter()
-        .filter(|name| !skill_map.contains_key(*name))
-        .cloned()
-        .collect();
-    missing.sort();
+    Ok(())
+}
+
+#[cfg(not(feature = "graph"))]
+fn list_orphans(_config: &Config) -> Result<()> {
+    anyhow::bail!("Orphan detection requires the graph feature (install with --features graph)")
+}
+
+fn list_missing(config: &Config) -> Result<()> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    let missing = find_dangling_references(&skills, &config.detection.detectors())?;
 
     println!(
         "{}",
@@ -525,22 +881,80 @@ fn list_missing(config: &Config) -> Result<()> {
             "{} missing skills referenced:\n",
             missing.len().to_string().red().bold()
         );
-        for name in &missing {
-            println!("  {} {}", "✗".red(), name.red());
+        for (name, referrers) in &missing {
+            let sites: Vec<String> = referrers
+                .iter()
+                .map(|(source, line)| format!("{}:{}", source, line))
+                .collect();
+            println!(
+                "  {} {} {}",
+                "✗".red(),
+                name.red(),
+                format!("(referenced by {})", sites.join(", ")).dimmed()
+            );
         }
     }
 
     Ok(())
 }
 
+/// Map each dangling reference target to the `(skill, line)` sites that mention it
+///
+/// Targets are ordered by name; sites by skill name, then line.
+fn find_dangling_references(
+    skills: &[Skill],
+    detectors: &[Box<dyn skill::ReferenceDetector>],
+) -> Result<BTreeMap<String, Vec<(String, usize)>>> {
+    let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+    let mut missing: BTreeMap<String, Vec<(String, usize)>> = BTreeMap::new();
+    for (name, refs) in skill::extract_all_references(skills, detectors)? {
+        for r in refs {
+            if !known_skills.contains(&r.target) {
+                missing
+                    .entry(r.target)
+                    .or_default()
+                    .push((name.clone(), r.line));
+            }
+        }
+    }
+
+    for referrers in missing.values_mut() {
+        referrers.sort();
+        referrers.dedup();
+    }
+
+    Ok(missing)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Global, Sources};
+    use crate::config::{DetectionConfig, Global, Sources};
     use std::collections::HashMap;
     use std::fs;
     use tempfile::TempDir;
 
+    fn create_tagged_skills(temp: &TempDir) {
+        let skills_dir = temp.path().join("skills");
+
+        let audit_dir = skills_dir.join("audit");
+        fs::create_dir_all(&audit_dir).unwrap();
+        fs::write(
+            audit_dir.join("SKILL.md"),
+            "---\nname: audit\ndescription: Audit skill\ntags: [security]\n---\n",
+        )
+        .unwrap();
+
+        let draft_dir = skills_dir.join("draft");
+        fs::create_dir_all(&draft_dir).unwrap();
+        fs::write(
+            draft_dir.join("SKILL.md"),
+            "---\nname: draft\ndescription: Draft skill\n---\n",
+        )
+        .unwrap();
+    }
+
     fn create_test_skills(temp: &TempDir) {
         let skills_dir = temp.path().join("skills");
 
@@ -569,7 +983,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -577,15 +992,231 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
-        let result = list(&config, ListMode::Default);
+        let result = list(
+            &config,
+            ListMode::Default {
+                sort: SortKey::Name,
+                show_pipelines: true,
+            },
+        );
 
         // Then
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn should_count_found_and_missing_skills_per_scope() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let project_path = temp.path().join("project");
+        let config = Config {
+            sources: Sources {
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec!["test-skill".to_string(), "missing-skill".to_string()],
+            },
+            projects: HashMap::from([(
+                project_path.clone(),
+                Project {
+                    skills: vec!["another-skill".to_string()],
+                    inherit: false,
+                },
+            )]),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+        let skill_map =
+            skill::build_skill_map(skill::discover_all(&config.sources.skills).unwrap());
+
+        // When
+        let lines = count_lines(&config, &skill_map);
+
+        // Then
+        assert_eq!(
+            lines,
+            vec![
+                "global: 1 found, 1 missing".to_string(),
+                format!("{}: 1 found, 0 missing", project_path.display()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_sort_key_case_insensitive() {
+        // Given/When/Then
+        assert_eq!(SortKey::parse_key("name"), Some(SortKey::Name));
+        assert_eq!(SortKey::parse_key("PATH"), Some(SortKey::Path));
+        assert_eq!(SortKey::parse_key("source"), Some(SortKey::Source));
+        assert!(SortKey::parse_key("size").is_none());
+    }
+
+    #[test]
+    fn should_sort_missing_entries_by_name_alongside_found_ones() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let sources = vec![temp.path().join("skills").into()];
+        let skill_map = skill::build_skill_map(skill::discover_all(&sources).unwrap());
+        let names = vec![
+            "test-skill".to_string(),
+            "missing-skill".to_string(),
+            "another-skill".to_string(),
+        ];
+
+        // When
+        let entries = sorted_entries(&names, &skill_map, &sources, SortKey::Name);
+
+        // Then
+        let ordered: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            ordered,
+            vec!["another-skill", "missing-skill", "test-skill"]
+        );
+        assert!(entries[1].1.is_none());
+    }
+
+    #[test]
+    fn should_break_source_ties_on_name() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let sources = vec![temp.path().join("skills").into()];
+        let skill_map = skill::build_skill_map(skill::discover_all(&sources).unwrap());
+        let names = vec!["test-skill".to_string(), "another-skill".to_string()];
+
+        // When
+        let entries = sorted_entries(&names, &skill_map, &sources, SortKey::Source);
+
+        // Then
+        let ordered: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(ordered, vec!["another-skill", "test-skill"]);
+    }
+
+    #[test]
+    fn should_keep_only_enabled_skills_with_tag() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_tagged_skills(&temp);
+        let sources = vec![temp.path().join("skills").into()];
+        let skill_map = skill::build_skill_map(skill::discover_all(&sources).unwrap());
+        let enabled = vec!["draft".to_string(), "audit".to_string()];
+
+        // When
+        let matching = tagged_skills(&enabled, &skill_map, "security");
+
+        // Then
+        let names: Vec<&str> = matching.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["audit"]);
+    }
+
+    #[test]
+    fn should_list_tagged_skills_per_scope() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_tagged_skills(&temp);
+
+        let mut projects = HashMap::new();
+        projects.insert(
+            temp.path().join("project"),
+            Project {
+                skills: vec!["draft".to_string()],
+                inherit: true,
+            },
+        );
+        let config = Config {
+            sources: Sources {
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec!["audit".to_string()],
+            },
+            projects,
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        let skill_map =
+            skill::build_skill_map(skill::discover_all(&config.sources.skills).unwrap());
+
+        // When
+        let result = list(&config, ListMode::Tag("security".to_string()));
+        let sections = tagged_by_scope(&config, &skill_map, "security");
+
+        // Then
+        assert!(result.is_ok());
+        let names: Vec<(&str, Vec<&str>)> = sections
+            .iter()
+            .map(|(scope, skills)| {
+                (
+                    scope.as_str(),
+                    skills.iter().map(|s| s.name.as_str()).collect(),
+                )
+            })
+            .collect();
+        let project = format!("--- Project: {}", temp.path().join("project").display());
+        assert_eq!(
+            names,
+            vec![
+                ("--- Global scope ---", vec!["audit"]),
+                (project.as_str(), vec!["audit"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_referencing_skills_and_lines_for_missing_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let broken_dir = temp.path().join("skills").join("broken-skill");
+        fs::create_dir_all(&broken_dir).unwrap();
+        fs::write(
+            broken_dir.join("SKILL.md"),
+            "---\nname: broken-skill\ndescription: Broken\n---\n\n<crossrefs>\n  <see ref=\"ghost-skill\">Gone</see>\n</crossrefs>",
+        )
+        .unwrap();
+        let skills = skill::discover_all(&[temp.path().join("skills").into()]).unwrap();
+
+        // When
+        let missing =
+            find_dangling_references(&skills, &DetectionConfig::default().detectors()).unwrap();
+
+        // Then
+        assert_eq!(missing.len(), 1);
+        assert_eq!(
+            missing["ghost-skill"],
+            vec![("broken-skill".to_string(), 7)]
+        );
+    }
+
+    #[test]
+    fn should_detect_pipeline_order_collisions() {
+        // Given
+        let stage = |skill: &str, order| (skill.to_string(), "stage".to_string(), order);
+        let colliding = vec![stage("build", 1), stage("deploy", 2), stage("notify", 2)];
+        let distinct = vec![stage("build", 1), stage("deploy", 2)];
+
+        // When/Then
+        assert!(has_order_collisions(&colliding));
+        assert!(!has_order_collisions(&distinct));
+    }
+
     #[test]
     fn should_list_refs_for_skill() {
         // Given
@@ -594,7 +1225,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -602,6 +1234,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -619,7 +1254,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -627,6 +1263,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -644,7 +1283,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -652,6 +1292,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -669,7 +1312,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -677,6 +1321,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -694,7 +1341,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -702,6 +1350,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -719,7 +1370,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -727,6 +1379,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -744,7 +1399,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -752,6 +1408,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -762,6 +1421,248 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("not found"));
     }
 
+    #[test]
+    fn should_group_skills_by_agent() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        for (name, agent) in [
+            ("review", Some("reviewer")),
+            ("audit", Some("reviewer")),
+            ("draft", Some("writer")),
+            ("notes", None),
+        ] {
+            let agent_line = agent.map(|a| format!("agent: {a}\n")).unwrap_or_default();
+            fs::create_dir_all(skills_dir.join(name)).unwrap();
+            fs::write(
+                skills_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n{agent_line}---\n"),
+            )
+            .unwrap();
+        }
+        let skills = skill::discover_all(&[skills_dir.into()]).unwrap();
+
+        // When
+        let groups = group_by_agent(&skills);
+
+        // Then
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups[&Some("reviewer".to_string())],
+            vec!["audit", "review"]
+        );
+        assert_eq!(groups[&Some("writer".to_string())], vec!["draft"]);
+        assert_eq!(groups[&None], vec!["notes"]);
+    }
+
+    #[test]
+    fn should_annotate_deprecated_skills_with_replacement() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        for (name, deprecated) in [
+            (
+                "old-edit",
+                "deprecated:\n  since: 0.4.0\n  replacement: edit\n",
+            ),
+            ("old-draft", "deprecated: {}\n"),
+            ("edit", ""),
+        ] {
+            fs::create_dir_all(skills_dir.join(name)).unwrap();
+            fs::write(
+                skills_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n{deprecated}---\n"),
+            )
+            .unwrap();
+        }
+        let skill_map = skill::build_skill_map(skill::discover_all(&[skills_dir.into()]).unwrap());
+
+        // When
+        let old_edit = deprecation_annotation(&skill_map["old-edit"]);
+        let old_draft = deprecation_annotation(&skill_map["old-draft"]);
+        let edit = deprecation_annotation(&skill_map["edit"]);
+
+        // Then
+        assert_eq!(old_edit.as_deref(), Some("[deprecated → edit]"));
+        assert_eq!(old_draft.as_deref(), Some("[deprecated]"));
+        assert!(edit.is_none());
+    }
+
+    #[test]
+    fn should_annotate_only_skills_in_pipelines() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        for (name, pipeline) in [
+            (
+                "publish",
+                "pipeline:\n  release:\n    stage: ship\n    order: 2\n  nightly:\n    stage: ship\n    order: 1\n",
+            ),
+            ("notes", ""),
+        ] {
+            fs::create_dir_all(skills_dir.join(name)).unwrap();
+            fs::write(
+                skills_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n{pipeline}---\n"),
+            )
+            .unwrap();
+        }
+        let skill_map = skill::build_skill_map(skill::discover_all(&[skills_dir.into()]).unwrap());
+
+        // When
+        let publish = pipeline_annotation(&skill_map["publish"]);
+        let notes = pipeline_annotation(&skill_map["notes"]);
+
+        // Then
+        assert_eq!(publish.as_deref(), Some("[pipelines: nightly, release]"));
+        assert!(notes.is_none());
+    }
+
+    #[cfg(feature = "graph")]
+    #[test]
+    fn should_render_groups_as_json() {
+        // Given: draft <-> edit form a cluster, audit stands alone
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        for (name, body) in [
+            ("edit", "<see ref=\"draft\">x</see>"),
+            ("draft", "<see ref=\"edit\">x</see>"),
+            ("audit", ""),
+        ] {
+            fs::create_dir_all(skills_dir.join(name)).unwrap();
+            fs::write(
+                skills_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n---\n{body}\n"),
+            )
+            .unwrap();
+        }
+        let skills = skill::discover_all(&[skills_dir.into()]).unwrap();
+        let crossrefs =
+            skill::extract_all_references(&skills, &skill::builtin_detectors(None)).unwrap();
+        let graph = crate::graph::SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let output = groups_json(&skills, &graph.clusters);
+
+        // Then
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["clusters"], serde_json::json!([["draft", "edit"]]));
+        assert_eq!(parsed["unclustered"], serde_json::json!(["audit"]));
+    }
+
+    #[cfg(not(feature = "graph"))]
+    #[test]
+    fn should_render_all_skills_unclustered_as_json_without_graph() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_tagged_skills(&temp);
+        let skills = skill::discover_all(&[temp.path().join("skills").into()]).unwrap();
+
+        // When
+        let output = groups_json(&skills);
+
+        // Then
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["clusters"], serde_json::json!([]));
+        assert_eq!(parsed["unclustered"], serde_json::json!(["audit", "draft"]));
+        assert_eq!(parsed["note"], "graph feature disabled");
+    }
+
+    #[cfg(feature = "graph")]
+    #[test]
+    fn should_list_orphans_mode() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        create_tagged_skills(&temp);
+
+        let config = Config {
+            sources: Sources {
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec!["audit".to_string()],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let result = list(&config, ListMode::Orphans);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "graph")]
+    #[test]
+    fn should_list_impact_of_removing_skill() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+
+        let config = Config {
+            sources: Sources {
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let result = list(&config, ListMode::Impact("test-skill".to_string()));
+        let affected = impact_of_removing(&config, "test-skill").unwrap();
+
+        // Then
+        assert!(result.is_ok());
+        assert_eq!(affected, vec!["another-skill"]);
+        assert!(impact_of_removing(&config, "another-skill")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_error_when_skill_not_found_for_impact() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+
+        let config = Config {
+            sources: Sources {
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let result = list(&config, ListMode::Impact("nonexistent".to_string()));
+
+        // Then
+        assert!(result.is_err());
+    }
+
     #[test]
     fn should_list_missing_skills() {
         // Given
@@ -777,7 +1678,8 @@ mod tests {
 
         let config = Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -785,6 +1687,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
@@ -1,9 +1,12 @@
 //! New command implementation
 
+use std::collections::BTreeSet;
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use colored::Colorize;
+use walkdir::WalkDir;
 
 use crate::config::Config;
 
@@ -48,8 +51,69 @@ Write your instructions for the AI agent. You can use:
 ```
 "#;
 
+const MINIMAL_TEMPLATE: &str = r#"---
+name: {{name}}
+description: >-
+  {{description}}
+---
+
+# {{name}}
+
+TODO: Add your skill content here.
+"#;
+
+const PIPELINE_STAGE_TEMPLATE: &str = r#"---
+name: {{name}}
+description: >-
+  {{description}}
+# tags: []
+pipeline:
+  my-pipeline:
+    stage: {{name}}
+    order: 1
+    # after: []
+    # before: []
+---
+
+# {{name}}
+
+TODO: Describe what this stage consumes, what it produces, and which
+stage picks up its output.
+
+## Input
+
+What the previous stage hands over.
+
+## Output
+
+What this stage hands to the next one.
+"#;
+
+/// Templates available without any configured template source (name, SKILL.md content)
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    ("minimal", MINIMAL_TEMPLATE),
+    ("pipeline-stage", PIPELINE_STAGE_TEMPLATE),
+];
+
+/// A resolved template for `loadout new --template`
+enum Template {
+    /// Built-in template consisting of a single SKILL.md
+    Builtin(&'static str),
+    /// Template directory from a configured templates source
+    Directory(PathBuf),
+}
+
 /// Create a new skill from template
-pub fn new(config: &Config, name: String, description: Option<String>) -> Result<()> {
+///
+/// Without `template`, the skill is written from the default SKILL.md
+/// template. With `template`, configured template directories are searched
+/// first (in order), then the built-in templates.
+pub fn new(
+    config: &Config,
+    name: String,
+    description: Option<String>,
+    template: Option<String>,
+) -> Result<()> {
     // Validate skill name format
     validate_skill_name(&name)?;
 
@@ -58,6 +122,7 @@ pub fn new(config: &Config, name: String, description: Option<String>) -> Result
         .sources
         .skills
         .first()
+        .map(|source| &source.path)
         .context("No source directories configured")?;
 
     let skill_dir = source_dir.join(&name);
@@ -70,24 +135,41 @@ pub fn new(config: &Config, name: String, description: Option<String>) -> Result
         ));
     }
 
+    // Resolve the template before touching the filesystem
+    let template = template.map(|t| resolve_template(config, &t)).transpose()?;
+
     // Create skill directory
     fs::create_dir_all(&skill_dir).context(format!(
         "Failed to create skill directory: {}",
         skill_dir.display()
     ))?;
 
-    // Generate SKILL.md content
     let desc = description.unwrap_or_else(|| format!("Description for {}", name));
-    let content = TEMPLATE_CONTENT
-        .replace("{name}", &name)
-        .replace("{description}", &desc);
-
-    // Write SKILL.md file
     let skill_file = skill_dir.join("SKILL.md");
-    fs::write(&skill_file, content).context(format!(
-        "Failed to write SKILL.md: {}",
-        skill_file.display()
-    ))?;
+
+    match template {
+        None => {
+            // Generate SKILL.md content
+            let content = TEMPLATE_CONTENT
+                .replace("{name}", &name)
+                .replace("{description}", &desc);
+
+            // Write SKILL.md file
+            fs::write(&skill_file, content).context(format!(
+                "Failed to write SKILL.md: {}",
+                skill_file.display()
+            ))?;
+        }
+        Some(Template::Builtin(content)) => {
+            fs::write(&skill_file, substitute(content, &name, &desc)).context(format!(
+                "Failed to write SKILL.md: {}",
+                skill_file.display()
+            ))?;
+        }
+        Some(Template::Directory(template_dir)) => {
+            copy_template(&template_dir, &skill_dir, &name, &desc)?;
+        }
+    }
 
     println!("{} {}", "Created skill:".green().bold(), name);
     println!("  Path: {}", skill_dir.display());
@@ -101,6 +183,95 @@ pub fn new(config: &Config, name: String, description: Option<String>) -> Result
     Ok(())
 }
 
+/// Find a template by name in the configured template sources, then the built-ins
+fn resolve_template(config: &Config, template: &str) -> Result<Template> {
+    for source in &config.sources.templates {
+        let candidate = source.join(template);
+        if candidate.is_dir() {
+            return Ok(Template::Directory(candidate));
+        }
+    }
+
+    if let Some((_, content)) = BUILTIN_TEMPLATES.iter().find(|(n, _)| *n == template) {
+        return Ok(Template::Builtin(content));
+    }
+
+    let available: Vec<String> = available_templates(config).into_iter().collect();
+    Err(anyhow::anyhow!(
+        "Template '{}' not found. Available: {}",
+        template,
+        available.join(", ")
+    ))
+}
+
+/// Names of all templates: built-ins plus every subdirectory of a template source
+fn available_templates(config: &Config) -> BTreeSet<String> {
+    let mut names: BTreeSet<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(n, _)| n.to_string())
+        .collect();
+
+    for source in &config.sources.templates {
+        let Ok(entries) = fs::read_dir(source) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if entry.path().is_dir() && !file_name.starts_with('.') {
+                names.insert(file_name);
+            }
+        }
+    }
+
+    names
+}
+
+/// Replace `{{name}}` and `{{description}}` placeholders
+fn substitute(content: &str, name: &str, description: &str) -> String {
+    content
+        .replace("{{name}}", name)
+        .replace("{{description}}", description)
+}
+
+/// Copy a template directory into a new skill directory, substituting
+/// placeholders in every text file (binary files are copied verbatim)
+fn copy_template(
+    template_dir: &Path,
+    skill_dir: &Path,
+    name: &str,
+    description: &str,
+) -> Result<()> {
+    for entry in WalkDir::new(template_dir).min_depth(1) {
+        let entry = entry.context(format!(
+            "Failed to read template directory: {}",
+            template_dir.display()
+        ))?;
+        let relative = entry
+            .path()
+            .strip_prefix(template_dir)
+            .expect("walkdir entries are under the template root");
+        let dest = skill_dir.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&dest)
+                .context(format!("Failed to create directory: {}", dest.display()))?;
+            continue;
+        }
+
+        let bytes = fs::read(entry.path()).context(format!(
+            "Failed to read template file: {}",
+            entry.path().display()
+        ))?;
+        let output = match String::from_utf8(bytes) {
+            Ok(text) => substitute(&text, name, description).into_bytes(),
+            Err(e) => e.into_bytes(),
+        };
+        fs::write(&dest, output).context(format!("Failed to write file: {}", dest.display()))?;
+    }
+
+    Ok(())
+}
+
 /// Validate skill name follows the pattern: ^[a-z0-9]+(-[a-z0-9]+)*$
 fn validate_skill_name(name: &str) -> Result<()> {
     let re = regex::Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap();
@@ -132,7 +303,8 @@ mod tests {
     fn create_test_config(temp: &TempDir) -> Config {
         Config {
             sources: Sources {
-                skills: vec![temp.path().join("skills")],
+                skills: vec![temp.path().join("skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -140,6 +312,9 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         }
     }
 
@@ -154,6 +329,7 @@ mod tests {
             &config,
             "my-skill".to_string(),
             Some("Test skill".to_string()),
+            None,
         )
         .unwrap();
 
@@ -174,7 +350,7 @@ mod tests {
         let config = create_test_config(&temp);
 
         // When
-        new(&config, "my-skill".to_string(), None).unwrap();
+        new(&config, "my-skill".to_string(), None, None).unwrap();
 
         // Then
         let skill_file = temp.path().join("skills/my-skill/SKILL.md");
@@ -191,13 +367,98 @@ mod tests {
         fs::create_dir_all(temp.path().join("skills/my-skill")).unwrap();
 
         // When
-        let result = new(&config, "my-skill".to_string(), None);
+        let result = new(&config, "my-skill".to_string(), None, None);
 
         // Then
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("already exists"));
     }
 
+    #[test]
+    fn should_create_skill_from_builtin_template() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+
+        // When
+        new(
+            &config,
+            "my-stage".to_string(),
+            Some("A stage".to_string()),
+            Some("pipeline-stage".to_string()),
+        )
+        .unwrap();
+
+        // Then
+        let content = fs::read_to_string(temp.path().join("skills/my-stage/SKILL.md")).unwrap();
+        assert!(content.contains("name: my-stage"));
+        assert!(content.contains("A stage"));
+        assert!(content.contains("pipeline:"));
+        assert!(!content.contains("{{"));
+    }
+
+    #[test]
+    fn should_copy_configured_template_with_substitution() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        let template_dir = temp.path().join("templates/with-refs");
+        fs::create_dir_all(template_dir.join("references")).unwrap();
+        fs::write(
+            template_dir.join("SKILL.md"),
+            "---\nname: {{name}}\ndescription: {{description}}\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            template_dir.join("references/notes.md"),
+            "Notes for {{name}}\n",
+        )
+        .unwrap();
+        config.sources.templates = vec![temp.path().join("templates")];
+
+        // When
+        new(
+            &config,
+            "my-skill".to_string(),
+            Some("Test skill".to_string()),
+            Some("with-refs".to_string()),
+        )
+        .unwrap();
+
+        // Then
+        let skill_dir = temp.path().join("skills/my-skill");
+        let content = fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+        assert_eq!(
+            content,
+            "---\nname: my-skill\ndescription: Test skill\n---\n"
+        );
+        let notes = fs::read_to_string(skill_dir.join("references/notes.md")).unwrap();
+        assert_eq!(notes, "Notes for my-skill\n");
+    }
+
+    #[test]
+    fn should_list_available_templates_when_template_missing() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        fs::create_dir_all(temp.path().join("templates/team-review")).unwrap();
+        config.sources.templates = vec![temp.path().join("templates")];
+
+        // When
+        let result = new(
+            &config,
+            "my-skill".to_string(),
+            None,
+            Some("nope".to_string()),
+        );
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Template 'nope' not found"));
+        assert!(err.contains("minimal, pipeline-stage, team-review"));
+        assert!(!temp.path().join("skills/my-skill").exists());
+    }
+
     #[test]
     fn should_validate_skill_name_pattern() {
         // Given - valid names
@@ -1,12 +1,15 @@
 //! Validate command implementation
 
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::commands::check::Finding;
 use crate::config::Config;
-use crate::skill;
+use crate::skill::{self, DescriptionIssue, Frontmatter, ToolIssue};
 
 /// Validate SKILL.md files in source directories
 ///
@@ -14,7 +17,10 @@ use crate::skill;
 /// - All skills from config sources (no arguments)
 /// - A specific skill by name
 /// - All skills in a specific directory
-pub fn validate(config: &Config, target: Option<String>) -> Result<()> {
+///
+/// With `strict`, frontmatter keys that no field accepts are reported as
+/// warnings.
+pub fn validate(config: &Config, target: Option<String>, strict: bool) -> Result<()> {
     let mut errors = 0;
     let mut validated = 0;
 
@@ -30,20 +36,12 @@ pub fn validate(config: &Config, target: Option<String>) -> Result<()> {
             println!();
 
             for source in &config.sources.skills {
-                println!("Source: {}", source.display());
-                let skills = skill::discover_in_directory(source)?;
+                println!("Source: {}", source.path.display());
+                let skills = skill::discover_in_source(source)?;
 
                 for skill_result in skills {
                     validated += 1;
-                    match validate_skill(&skill_result) {
-                        Ok(_) => {
-                            println!("  {} {}", "✓".green(), skill_result.name);
-                        }
-                        Err(e) => {
-                            println!("  {} {} - {}", "✗".red(), skill_result.name, e);
-                            errors += 1;
-                        }
-                    }
+                    errors += report_skill(&skill_result, config, strict);
                 }
             }
         }
@@ -63,15 +61,7 @@ pub fn validate(config: &Config, target: Option<String>) -> Result<()> {
 
                 for skill_result in skills {
                     validated += 1;
-                    match validate_skill(&skill_result) {
-                        Ok(_) => {
-                            println!("  {} {}", "✓".green(), skill_result.name);
-                        }
-                        Err(e) => {
-                            println!("  {} {} - {}", "✗".red(), skill_result.name, e);
-                            errors += 1;
-                        }
-                    }
+                    errors += report_skill(&skill_result, config, strict);
                 }
             } else {
                 // Validate a specific skill by name
@@ -81,19 +71,11 @@ pub fn validate(config: &Config, target: Option<String>) -> Result<()> {
                 let skill_result = skill::resolve(&config.sources.skills, &target_str)?;
                 validated += 1;
 
-                match validate_skill(&skill_result) {
-                    Ok(_) => {
-                        println!("  {} {}", "✓".green(), skill_result.name);
-                        println!(
-                            "  Path: {}",
-                            skill_result.path.display().to_string().dimmed()
-                        );
-                    }
-                    Err(e) => {
-                        println!("  {} {} - {}", "✗".red(), skill_result.name, e);
-                        errors += 1;
-                    }
-                }
+                errors += report_skill(&skill_result, config, strict);
+                println!(
+                    "  Path: {}",
+                    skill_result.path.display().to_string().dimmed()
+                );
             }
         }
     }
@@ -113,6 +95,108 @@ pub fn validate(config: &Config, target: Option<String>) -> Result<()> {
     }
 }
 
+/// Validate a skill and print its result, followed by description,
+/// allowed-tools, argument-hint, and (when `strict`) unknown-field findings
+///
+/// Returns the number of errors: a failed validation, empty description, or
+/// malformed tool entry each count once; overlong descriptions, unknown
+/// tools, malformed argument hints, and unknown fields are warnings only.
+fn report_skill(skill: &skill::Skill, config: &Config, strict: bool) -> usize {
+    let mut errors = 0;
+
+    match validate_skill(skill) {
+        Ok(_) => {
+            println!("  {} {}", "✓".green(), skill.name);
+        }
+        Err(e) => {
+            println!("  {} {} - {}", "✗".red(), skill.name, e);
+            errors += 1;
+        }
+    }
+
+    match skill
+        .frontmatter
+        .description_issue(config.validation.max_description_len)
+    {
+        Some(DescriptionIssue::Empty) => {
+            println!("    {} {}: empty description", "✗".red(), skill.name);
+            errors += 1;
+        }
+        Some(DescriptionIssue::TooLong { len, max }) => {
+            println!(
+                "    {} {}: description is {} chars (max {})",
+                "⚠".yellow(),
+                skill.name,
+                len,
+                max
+            );
+        }
+        None => {}
+    }
+
+    for issue in skill.frontmatter.allowed_tools_issues() {
+        match issue {
+            ToolIssue::Malformed { entry, reason } => {
+                println!(
+                    "    {} {}: malformed allowed-tools entry '{}' ({})",
+                    "✗".red(),
+                    skill.name,
+                    entry,
+                    reason
+                );
+                errors += 1;
+            }
+            ToolIssue::Unknown { entry, suggestion } => {
+                let hint = suggestion
+                    .map(|s| format!(" (did you mean '{}'?)", s))
+                    .unwrap_or_default();
+                println!(
+                    "    {} {}: unknown tool in allowed-tools entry '{}'{}",
+                    "⚠".yellow(),
+                    skill.name,
+                    entry,
+                    hint
+                );
+            }
+        }
+    }
+
+    if let Some(hint) = &skill.frontmatter.argument_hint {
+        if let Err(e) = skill::parse_argument_hint(hint) {
+            println!(
+                "    {} {}: malformed argument-hint token {}",
+                "⚠".yellow(),
+                skill.name,
+                e
+            );
+        }
+    }
+
+    if strict {
+        match skill
+            .read_content()
+            .and_then(|content| Frontmatter::unknown_fields(&content))
+        {
+            Ok(fields) => {
+                for field in fields {
+                    println!(
+                        "    {} {}: unknown frontmatter field '{}'",
+                        "⚠".yellow(),
+                        skill.name,
+                        field
+                    );
+                }
+            }
+            Err(e) => {
+                println!("    {} {}: {}", "✗".red(), skill.name, e);
+                errors += 1;
+            }
+        }
+    }
+
+    errors
+}
+
 /// Validate a single skill
 fn validate_skill(skill: &skill::Skill) -> Result<()> {
     // Frontmatter is already validated during discovery
@@ -135,18 +219,282 @@ fn validate_skill(skill: &skill::Skill) -> Result<()> {
     Ok(())
 }
 
+/// Check the config itself rather than the skills it points at
+///
+/// Reports enabled skills that no source provides (error), source
+/// directories that don't exist (warning), project directories that don't
+/// exist (warning), and global targets that are missing (info when
+/// `install` can create them, error otherwise).
+pub fn validate_config(config: &Config) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    for source in &config.sources.skills {
+        if !source.path.is_dir() {
+            findings.push(Finding::warning_with_path(
+                format!("Source directory does not exist: {}", source.path.display()),
+                "Fix the path under [sources] skills, or create the directory",
+                format!("config-source:{}", source.path.display()),
+                source.path.clone(),
+            ));
+        }
+    }
+
+    let known: HashSet<String> = skill::discover_all(&config.sources.skills)?
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+
+    for name in &config.global.skills {
+        if !known.contains(name) {
+            findings.push(Finding::error(
+                format!("Global skill '{}' not found in any source", name),
+                "Fix the name under [global] skills, or add a source that provides it",
+                format!("config-skill:global:{}", name),
+            ));
+        }
+    }
+
+    for target in &config.global.targets {
+        if let Some(finding) = check_target(target) {
+            findings.push(finding);
+        }
+    }
+
+    let mut projects: Vec<_> = config.projects.iter().collect();
+    projects.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (project_path, project) in projects {
+        if !project_path.is_dir() {
+            findings.push(Finding::warning_with_path(
+                format!(
+                    "Project directory does not exist: {}",
+                    project_path.display()
+                ),
+                "Fix the [projects] key, or remove the project entry",
+                format!("config-project:{}", project_path.display()),
+                project_path.clone(),
+            ));
+        }
+
+        for name in &project.skills {
+            if !known.contains(name) {
+                findings.push(Finding::error_with_path(
+                    format!(
+                        "Project skill '{}' not found in any source (project {})",
+                        name,
+                        project_path.display()
+                    ),
+                    "Fix the name in the project's skills, or add a source that provides it",
+                    format!("config-skill:{}:{}", project_path.display(), name),
+                    project_path.clone(),
+                ));
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Report a target directory that is missing, or can't serve as a target
+fn check_target(target: &Path) -> Option<Finding> {
+    if target.is_dir() {
+        return None;
+    }
+
+    let key = format!("config-target:{}", target.display());
+
+    if target.exists() {
+        return Some(Finding::error_with_path(
+            format!("Target is not a directory: {}", target.display()),
+            "Point the target at a directory",
+            key,
+            target.to_path_buf(),
+        ));
+    }
+
+    if is_creatable(target) {
+        Some(Finding::info_with_path(
+            format!("Target directory does not exist yet: {}", target.display()),
+            "`loadout install` will create it",
+            key,
+            target.to_path_buf(),
+        ))
+    } else {
+        Some(Finding::error_with_path(
+            format!("Target directory cannot be created: {}", target.display()),
+            "Fix the target path, or make its parent directory writable",
+            key,
+            target.to_path_buf(),
+        ))
+    }
+}
+
+/// Whether a missing directory could be created: its nearest existing
+/// ancestor must be a writable directory
+fn is_creatable(path: &Path) -> bool {
+    for ancestor in path.ancestors().skip(1) {
+        let dir = if ancestor.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            ancestor
+        };
+        if let Ok(metadata) = fs::metadata(dir) {
+            return metadata.is_dir() && !metadata.permissions().readonly();
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Global, Sources};
+    use crate::commands::check::Severity;
+    use crate::config::{Global, Project, Sources};
     use std::collections::HashMap;
 
+    // Helper to create a config over the fixture skills
+    fn fixture_config(targets: Vec<PathBuf>, skills: &[&str]) -> Config {
+        Config {
+            sources: Sources {
+                skills: vec![PathBuf::from("tests/fixtures/skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets,
+                skills: skills.iter().map(|s| s.to_string()).collect(),
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        }
+    }
+
+    #[test]
+    fn should_report_no_findings_for_valid_config() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = fixture_config(vec![temp.path().to_path_buf()], &["test-skill"]);
+
+        // When
+        let findings = validate_config(&config).unwrap();
+
+        // Then
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn should_report_unknown_global_and_project_skills_as_errors() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut config = fixture_config(vec![], &["test-skil"]);
+        config.projects.insert(
+            temp.path().to_path_buf(),
+            Project {
+                skills: vec!["another-skill".to_string(), "ghost".to_string()],
+                inherit: true,
+            },
+        );
+
+        // When
+        let findings = validate_config(&config).unwrap();
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "config-skill:global:test-skil".to_string(),
+                format!("config-skill:{}:ghost", temp.path().display()),
+            ]
+        );
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+    }
+
+    #[test]
+    fn should_report_missing_source_directory_as_warning() {
+        // Given
+        let mut config = fixture_config(vec![], &[]);
+        config
+            .sources
+            .skills
+            .push(PathBuf::from("/nonexistent/skills").into());
+
+        // When
+        let findings = validate_config(&config).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(
+            findings[0].suppress_key,
+            "config-source:/nonexistent/skills"
+        );
+    }
+
+    #[test]
+    fn should_report_missing_project_directory_as_warning() {
+        // Given
+        let mut config = fixture_config(vec![], &[]);
+        config.projects.insert(
+            PathBuf::from("/nonexistent/project"),
+            Project {
+                skills: vec![],
+                inherit: true,
+            },
+        );
+
+        // When
+        let findings = validate_config(&config).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(findings[0].rule_id(), "config-project");
+    }
+
+    #[test]
+    fn should_report_creatable_missing_target_as_info() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let target = temp.path().join("agent/skills");
+        let config = fixture_config(vec![target.clone()], &[]);
+
+        // When
+        let findings = validate_config(&config).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert_eq!(findings[0].path, Some(target));
+    }
+
+    #[test]
+    fn should_report_uncreatable_target_as_error() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("not-a-dir");
+        fs::write(&file, "").unwrap();
+        let config = fixture_config(vec![file.clone(), file.join("skills")], &[]);
+
+        // When
+        let findings = validate_config(&config).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+        assert!(findings[0].message.contains("not a directory"));
+        assert!(findings[1].message.contains("cannot be created"));
+    }
+
     #[test]
     fn should_validate_all_skills_from_config() {
         // Given
         let config = Config {
             sources: Sources {
-                skills: vec![PathBuf::from("tests/fixtures/skills")],
+                skills: vec![PathBuf::from("tests/fixtures/skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -154,10 +502,13 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
-        let result = validate(&config, None);
+        let result = validate(&config, None, false);
 
         // Then
         assert!(result.is_ok());
@@ -168,7 +519,8 @@ mod tests {
         // Given
         let config = Config {
             sources: Sources {
-                skills: vec![PathBuf::from("tests/fixtures/skills")],
+                skills: vec![PathBuf::from("tests/fixtures/skills").into()],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -176,10 +528,13 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
-        let result = validate(&config, Some("test-skill".to_string()));
+        let result = validate(&config, Some("test-skill".to_string()), false);
 
         // Then
         assert!(result.is_ok());
@@ -189,17 +544,23 @@ mod tests {
     fn should_validate_skills_in_directory() {
         // Given
         let config = Config {
-            sources: Sources { skills: vec![] },
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
             global: Global {
                 targets: vec![],
                 skills: vec![],
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
-        let result = validate(&config, Some("tests/fixtures/skills".to_string()));
+        let result = validate(&config, Some("tests/fixtures/skills".to_string()), false);
 
         // Then
         assert!(result.is_ok());
@@ -210,7 +571,42 @@ mod tests {
         // Given
         let config = Config {
             sources: Sources {
-                skills: vec![PathBuf::from("tests/fixtures/skills")],
+                skills: vec![PathBuf::from("tests/fixtures/skills").into()],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let result = validate(&config, Some("nonexistent-skill".to_string()), false);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_validation_for_malformed_allowed_tools() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills").join("tool-skill");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: tool-skill\ndescription: Uses tools\nallowed-tools: Read, Bash(ls\n---\n",
+        )
+        .unwrap();
+        let config = Config {
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
@@ -218,12 +614,86 @@ mod tests {
             },
             projects: HashMap::new(),
             check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
         };
 
         // When
-        let result = validate(&config, Some("nonexistent-skill".to_string()));
+        let source = temp.path().join("skills");
+        let result = validate(&config, Some(source.display().to_string()), false);
 
         // Then
         assert!(result.is_err());
     }
+
+    #[test]
+    fn should_pass_validation_with_unknown_tool_warning() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills").join("tool-skill");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: tool-skill\ndescription: Uses tools\nallowed-tools: bash(ls)\n---\n",
+        )
+        .unwrap();
+        let config = Config {
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let source = temp.path().join("skills");
+        let result = validate(&config, Some(source.display().to_string()), false);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_pass_strict_validation_with_unknown_field_warning() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills").join("typo-skill");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: typo-skill\ndescription: Has a typo\nfoo: bar\n---\n",
+        )
+        .unwrap();
+        let config = Config {
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+            },
+            projects: HashMap::new(),
+            check: Default::default(),
+            validation: Default::default(),
+            detection: Default::default(),
+            profiles: Default::default(),
+        };
+
+        // When
+        let source = temp.path().join("skills");
+        let result = validate(&config, Some(source.display().to_string()), true);
+
+        // Then
+        assert!(result.is_ok());
+    }
 }
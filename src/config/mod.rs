@@ -2,7 +2,9 @@
 
 mod types;
 
-pub use types::{Config, Global, Project, Sources};
+pub use types::{
+    Config, DetectionConfig, Global, Profile, Project, SkillSource, Sources, ValidationConfig,
+};
 
 use std::env;
 use std::fs;
@@ -29,17 +31,58 @@ pub fn load_from(path: &Path) -> Result<Config> {
     let mut config: Config = toml::from_str(&contents)
         .context(format!("Failed to parse config file: {}", path.display()))?;
 
-    // Expand ~ in all path fields
-    expand_paths(&mut config)?;
+    // Expand environment variables and ~ in all path fields
+    expand_paths(&mut config)
+        .context(format!("Invalid path in config file: {}", path.display()))?;
+
+    // Expand glob patterns in skill sources
+    config.sources.skills = expand_globs(&config.sources.skills)?;
+    for profile in config.profiles.values_mut() {
+        if let Some(sources) = &profile.sources {
+            profile.sources = Some(expand_globs(sources)?);
+        }
+    }
 
     Ok(config)
 }
 
+/// Merge a named profile over the base config
+///
+/// Only the fields the profile sets are replaced, so a profile that just
+/// lists `targets` keeps the base `skills`. An unknown name is an error
+/// listing the available profiles.
+pub fn apply_profile(config: &mut Config, name: &str) -> Result<()> {
+    let Some(profile) = config.profiles.get(name).cloned() else {
+        let mut available: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+        available.sort_unstable();
+        if available.is_empty() {
+            anyhow::bail!("Profile '{}' not found. No profiles are defined", name);
+        }
+        anyhow::bail!(
+            "Profile '{}' not found. Available: {}",
+            name,
+            available.join(", ")
+        );
+    };
+
+    if let Some(sources) = profile.sources {
+        config.sources.skills = sources;
+    }
+    if let Some(targets) = profile.targets {
+        config.global.targets = targets;
+    }
+    if let Some(skills) = profile.skills {
+        config.global.skills = skills;
+    }
+
+    Ok(())
+}
+
 /// Resolve the config file path using environment variables and XDG conventions
 fn resolve_config_path() -> Result<PathBuf> {
     // 1. Check $LOADOUT_CONFIG
     if let Ok(path) = env::var("LOADOUT_CONFIG") {
-        let expanded = expand_tilde(&path)?;
+        let expanded = expand_path(&path).context("Invalid path in $LOADOUT_CONFIG")?;
         return Ok(expanded);
     }
 
@@ -61,6 +104,74 @@ fn resolve_config_path() -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Path of the skill discovery cache
+///
+/// Resolution order:
+/// 1. $XDG_CACHE_HOME/loadout/skills.json (if XDG_CACHE_HOME set)
+/// 2. ~/.cache/loadout/skills.json (default)
+pub fn cache_path() -> Result<PathBuf> {
+    let cache_home = match env::var("XDG_CACHE_HOME") {
+        Ok(xdg_cache) => PathBuf::from(xdg_cache),
+        Err(_) => {
+            let home = env::var("HOME").context("HOME environment variable not set")?;
+            PathBuf::from(home).join(".cache")
+        }
+    };
+
+    Ok(cache_home.join("loadout").join("skills.json"))
+}
+
+/// Expand `$VAR`/`${VAR}` references, then a leading ~, in a path string
+fn expand_path(path: &str) -> Result<PathBuf> {
+    expand_tilde(&expand_env(path)?)
+}
+
+/// Replace `$VAR` and `${VAR}` with the variable's value
+///
+/// Names are ASCII letters, digits and `_`, not starting with a digit. A `$`
+/// not followed by a name is kept as-is. An unset variable is an error, so a
+/// typo surfaces here rather than as a missing directory later.
+fn expand_env(path: &str) -> Result<String> {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut expanded = String::with_capacity(path.len());
+    let mut rest = path;
+
+    while let Some(dollar) = rest.find('$') {
+        expanded.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+
+        let (name, remainder) = if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .with_context(|| format!("Unclosed '${{' in path: {}", path))?;
+            (&braced[..end], &braced[end + 1..])
+        } else {
+            let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        };
+
+        let valid = name.chars().all(is_name_char)
+            && name.chars().next().is_some_and(|c| !c.is_ascii_digit());
+        if !valid {
+            expanded.push('$');
+            rest = after;
+            continue;
+        }
+
+        let value = env::var(name).with_context(|| {
+            format!(
+                "Environment variable ${} is not set (in path: {})",
+                name, path
+            )
+        })?;
+        expanded.push_str(&value);
+        rest = remainder;
+    }
+
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
 /// Expand ~ and ~/ to $HOME in a path string
 fn expand_tilde(path: &str) -> Result<PathBuf> {
     if let Some(stripped) = path.strip_prefix("~/") {
@@ -74,19 +185,80 @@ fn expand_tilde(path: &str) -> Result<PathBuf> {
     }
 }
 
-/// Expand ~ in all path fields within the config
+/// Expand source entries containing `*` into the directories they match
+///
+/// Non-glob paths pass through unchanged (even if missing). Order is kept,
+/// matches of one pattern are sorted, and duplicates are dropped. Every
+/// match inherits the pattern's prefix.
+fn expand_globs(sources: &[SkillSource]) -> Result<Vec<SkillSource>> {
+    let mut expanded = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    for source in sources {
+        let pattern = source.path.to_string_lossy();
+        let matches = if pattern.contains('*') {
+            let mut dirs = Vec::new();
+            for entry in glob::glob(&pattern)
+                .context(format!("Invalid glob pattern in sources: {}", pattern))?
+            {
+                let path = entry.context(format!("Failed to expand glob: {}", pattern))?;
+                if path.is_dir() {
+                    dirs.push(path);
+                }
+            }
+            dirs.sort();
+            dirs
+        } else {
+            vec![source.path.clone()]
+        };
+
+        for path in matches {
+            if seen.insert(path.clone()) {
+                expanded.push(SkillSource {
+                    path,
+                    prefix: source.prefix.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(expanded)
+}
+
+/// Expand environment variables and ~ in all path fields within the config
 fn expand_paths(config: &mut Config) -> Result<()> {
     // Expand source paths
     for source in &mut config.sources.skills {
+        if let Some(path_str) = source.path.to_str() {
+            source.path = expand_path(path_str)?;
+        }
+    }
+
+    // Expand template source paths
+    for source in &mut config.sources.templates {
         if let Some(path_str) = source.to_str() {
-            *source = expand_tilde(path_str)?;
+            *source = expand_path(path_str)?;
         }
     }
 
     // Expand global target paths
     for target in &mut config.global.targets {
         if let Some(path_str) = target.to_str() {
-            *target = expand_tilde(path_str)?;
+            *target = expand_path(path_str)?;
+        }
+    }
+
+    // Expand profile source and target paths
+    for profile in config.profiles.values_mut() {
+        for source in profile.sources.iter_mut().flatten() {
+            if let Some(path_str) = source.path.to_str() {
+                source.path = expand_path(path_str)?;
+            }
+        }
+        for target in profile.targets.iter_mut().flatten() {
+            if let Some(path_str) = target.to_str() {
+                *target = expand_path(path_str)?;
+            }
         }
     }
 
@@ -94,7 +266,7 @@ fn expand_paths(config: &mut Config) -> Result<()> {
     let project_keys: Vec<PathBuf> = config.projects.keys().cloned().collect();
     for old_key in project_keys {
         if let Some(key_str) = old_key.to_str() {
-            let new_key = expand_tilde(key_str)?;
+            let new_key = expand_path(key_str)?;
             if new_key != old_key {
                 if let Some(project) = config.projects.remove(&old_key) {
                     config.projects.insert(new_key, project);
@@ -147,6 +319,74 @@ mod tests {
         assert_eq!(expanded, PathBuf::from(path));
     }
 
+    #[test]
+    fn should_expand_env_vars_in_path() {
+        // Given
+        env::set_var("LOADOUT_TEST_AGENTS_DIR", "/srv/agents");
+
+        // When
+        let bare = expand_path("$LOADOUT_TEST_AGENTS_DIR/skills").unwrap();
+        let braced = expand_path("${LOADOUT_TEST_AGENTS_DIR}-extra").unwrap();
+        let literal = expand_path("/opt/$/skills").unwrap();
+
+        // Then
+        assert_eq!(bare, PathBuf::from("/srv/agents/skills"));
+        assert_eq!(braced, PathBuf::from("/srv/agents-extra"));
+        assert_eq!(literal, PathBuf::from("/opt/$/skills"));
+    }
+
+    #[test]
+    fn should_report_undefined_env_var_in_path() {
+        // Given
+        env::remove_var("LOADOUT_TEST_UNSET_VAR");
+
+        // When
+        let result = expand_path("$LOADOUT_TEST_UNSET_VAR/skills");
+
+        // Then
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("$LOADOUT_TEST_UNSET_VAR is not set"));
+    }
+
+    #[test]
+    fn should_expand_env_vars_and_tilde_in_config() {
+        // Given
+        let home = env::var("HOME").unwrap();
+        env::set_var("LOADOUT_TEST_CONFIG_ROOT", "/srv/loadout");
+        let toml = r#"
+            [sources]
+            skills = ["$LOADOUT_TEST_CONFIG_ROOT/skills", "~/skills"]
+
+            [global]
+            targets = ["${LOADOUT_TEST_CONFIG_ROOT}/targets"]
+            skills = []
+
+            [projects."$LOADOUT_TEST_CONFIG_ROOT/project"]
+            skills = []
+        "#;
+
+        // When
+        let mut config: Config = toml::from_str(toml).unwrap();
+        expand_paths(&mut config).unwrap();
+
+        // Then
+        assert_eq!(
+            config.sources.skills[0].path,
+            PathBuf::from("/srv/loadout/skills")
+        );
+        assert_eq!(
+            config.sources.skills[1].path,
+            PathBuf::from(&home).join("skills")
+        );
+        assert_eq!(
+            config.global.targets[0],
+            PathBuf::from("/srv/loadout/targets")
+        );
+        assert!(config
+            .projects
+            .contains_key(&PathBuf::from("/srv/loadout/project")));
+    }
+
     #[test]
     fn should_expand_paths_in_config() {
         // Given
@@ -154,6 +394,7 @@ mod tests {
         let toml = r#"
             [sources]
             skills = ["~/.config/loadout/skills", "/opt/skills"]
+            templates = ["~/.config/loadout/templates"]
 
             [global]
             targets = ["~/.claude/skills"]
@@ -169,10 +410,14 @@ mod tests {
 
         // Then
         assert_eq!(
-            config.sources.skills[0],
+            config.sources.skills[0].path,
             PathBuf::from(&home).join(".config/loadout/skills")
         );
-        assert_eq!(config.sources.skills[1], PathBuf::from("/opt/skills"));
+        assert_eq!(config.sources.skills[1].path, PathBuf::from("/opt/skills"));
+        assert_eq!(
+            config.sources.templates[0],
+            PathBuf::from(&home).join(".config/loadout/templates")
+        );
         assert_eq!(
             config.global.targets[0],
             PathBuf::from(&home).join(".claude/skills")
@@ -182,6 +427,37 @@ mod tests {
             .contains_key(&PathBuf::from(&home).join("my-project")));
     }
 
+    #[test]
+    fn should_expand_glob_sources_in_order_without_duplicates() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().join("packages");
+        for pkg in ["beta", "alpha", "gamma"] {
+            fs::create_dir_all(root.join(pkg)).unwrap();
+        }
+        fs::create_dir_all(root.join("alpha/skills")).unwrap();
+        fs::create_dir_all(root.join("beta/skills")).unwrap();
+        let sources = vec![
+            root.join("beta/skills").into(),
+            root.join("*/skills").into(),
+            PathBuf::from("/not/a/glob").into(),
+        ];
+
+        // When
+        let expanded = expand_globs(&sources).unwrap();
+
+        // Then
+        let paths: Vec<PathBuf> = expanded.into_iter().map(|s| s.path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                root.join("beta/skills"),
+                root.join("alpha/skills"),
+                PathBuf::from("/not/a/glob"),
+            ]
+        );
+    }
+
     #[test]
     fn should_load_fixture_config() {
         // Given
@@ -229,4 +505,64 @@ mod tests {
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("Failed to parse config file"));
     }
+
+    const PROFILE_CONFIG: &str = r#"
+        [sources]
+        skills = ["/srv/skills"]
+
+        [global]
+        targets = ["/home/user/.claude/skills"]
+        skills = ["draft", "edit"]
+
+        [profiles.prod]
+        targets = ["/srv/prod/skills"]
+
+        [profiles.staging]
+        skills = ["draft"]
+    "#;
+
+    #[test]
+    fn should_keep_base_config_without_profile() {
+        // Given/When
+        let config: Config = toml::from_str(PROFILE_CONFIG).unwrap();
+
+        // Then
+        assert_eq!(config.profiles.len(), 2);
+        assert_eq!(
+            config.global.targets,
+            vec![PathBuf::from("/home/user/.claude/skills")]
+        );
+        assert_eq!(config.global.skills, vec!["draft", "edit"]);
+    }
+
+    #[test]
+    fn should_override_only_fields_the_profile_sets() {
+        // Given
+        let mut config: Config = toml::from_str(PROFILE_CONFIG).unwrap();
+
+        // When
+        apply_profile(&mut config, "prod").unwrap();
+
+        // Then
+        assert_eq!(
+            config.global.targets,
+            vec![PathBuf::from("/srv/prod/skills")]
+        );
+        assert_eq!(config.global.skills, vec!["draft", "edit"]);
+        assert_eq!(config.sources.skills[0].path, PathBuf::from("/srv/skills"));
+    }
+
+    #[test]
+    fn should_list_available_profiles_for_unknown_profile() {
+        // Given
+        let mut config: Config = toml::from_str(PROFILE_CONFIG).unwrap();
+
+        // When
+        let result = apply_profile(&mut config, "dev");
+
+        // Then
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("Profile 'dev' not found"));
+        assert!(err_msg.contains("Available: prod, staging"));
+    }
 }
@@ -1,6 +1,6 @@
 //! Configuration type definitions for loadout.toml
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
@@ -21,6 +21,18 @@ pub struct Config {
     /// Check command configuration
     #[serde(default)]
     pub check: CheckConfig,
+
+    /// Skill validation limits shared by `validate` and `check`
+    #[serde(default)]
+    pub validation: ValidationConfig,
+
+    /// Cross-reference detection methods to run
+    #[serde(default)]
+    pub detection: DetectionConfig,
+
+    /// Named overrides selectable with `--profile` (keyed by profile name)
+    #[serde(default)]
+    pub profiles: HashMap<String, Profile>,
 }
 
 /// Configuration for the check command
@@ -30,13 +42,174 @@ pub struct CheckConfig {
     /// e.g., "dangling:skill-format:related-skill"
     #[serde(default)]
     pub ignore: Vec<String>,
+
+    /// Section headings every SKILL.md body must contain, e.g. "Usage"
+    /// (matched case-insensitively at any heading level)
+    #[serde(default)]
+    pub required_sections: Vec<String>,
+}
+
+/// Limits applied when validating skills
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationConfig {
+    /// Longest `description` accepted without a warning, in chars
+    #[serde(default = "default_max_description_len")]
+    pub max_description_len: usize,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            max_description_len: default_max_description_len(),
+        }
+    }
+}
+
+fn default_max_description_len() -> usize {
+    crate::skill::DEFAULT_MAX_DESCRIPTION_LENGTH
+}
+
+/// Switches for each cross-reference detection method (all on by default)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectionConfig {
+    /// `<crossrefs><see ref="...">` elements
+    #[serde(default = "enabled")]
+    pub xml_crossref: bool,
+    /// Backtick-quoted names next to contextual words
+    #[serde(default = "enabled")]
+    pub backtick_context: bool,
+    /// Rows of "Related skills" / "Integration" tables
+    #[serde(default = "enabled")]
+    pub related_table: bool,
+    /// Phrases like "invoke the X skill"
+    #[serde(default = "enabled")]
+    pub natural_language: bool,
+    /// Relative links to sibling skill directories
+    #[serde(default = "enabled")]
+    pub markdown_link: bool,
+    /// `@skill-name` mentions
+    #[serde(default = "enabled")]
+    pub mention: bool,
+}
+
+impl Default for DetectionConfig {
+    fn default() -> Self {
+        Self {
+            xml_crossref: true,
+            backtick_context: true,
+            related_table: true,
+            natural_language: true,
+            markdown_link: true,
+            mention: true,
+        }
+    }
+}
+
+impl DetectionConfig {
+    /// The detection methods switched on
+    pub fn enabled_methods(&self) -> HashSet<crate::skill::DetectionMethod> {
+        use crate::skill::DetectionMethod;
+
+        [
+            (self.xml_crossref, DetectionMethod::XmlCrossref),
+            (self.backtick_context, DetectionMethod::BacktickContext),
+            (self.related_table, DetectionMethod::RelatedTable),
+            (self.natural_language, DetectionMethod::NaturalLanguage),
+            (self.markdown_link, DetectionMethod::MarkdownLink),
+            (self.mention, DetectionMethod::Mention),
+        ]
+        .into_iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, method)| method)
+        .collect()
+    }
+
+    /// Built-in reference detectors for the enabled methods
+    pub fn detectors(&self) -> Vec<Box<dyn crate::skill::ReferenceDetector>> {
+        crate::skill::builtin_detectors(Some(&self.enabled_methods()))
+    }
+}
+
+fn enabled() -> bool {
+    true
 }
 
 /// Source directories configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sources {
     /// List of directories to search for skills (in priority order)
-    pub skills: Vec<PathBuf>,
+    pub skills: Vec<SkillSource>,
+
+    /// Directories containing skill templates for `loadout new --template`
+    #[serde(default)]
+    pub templates: Vec<PathBuf>,
+}
+
+/// A skill source directory, optionally namespacing the skills it provides
+///
+/// Written in config as a bare path string or as `{ path, prefix }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SourceEntry")]
+pub struct SkillSource {
+    /// Directory to search for skills
+    pub path: PathBuf,
+
+    /// Namespace for skills from this source: `foo` becomes `prefix/foo`
+    ///
+    /// Must be a single path segment, since it names a directory in targets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+}
+
+impl From<PathBuf> for SkillSource {
+    fn from(path: PathBuf) -> Self {
+        SkillSource { path, prefix: None }
+    }
+}
+
+/// Accepted config spellings of a skill source
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SourceEntry {
+    Path(PathBuf),
+    Table {
+        path: PathBuf,
+        #[serde(default)]
+        prefix: Option<String>,
+    },
+}
+
+impl TryFrom<SourceEntry> for SkillSource {
+    type Error = String;
+
+    fn try_from(entry: SourceEntry) -> Result<Self, Self::Error> {
+        match entry {
+            SourceEntry::Path(path) => Ok(SkillSource { path, prefix: None }),
+            SourceEntry::Table { path, prefix } => {
+                if let Some(prefix) = &prefix {
+                    check_prefix(prefix)?;
+                }
+                Ok(SkillSource { path, prefix })
+            }
+        }
+    }
+}
+
+/// Reject prefixes that would place links outside a prefix directory in the target
+fn check_prefix(prefix: &str) -> Result<(), String> {
+    if prefix.is_empty() {
+        return Err("Source prefix must not be empty".to_string());
+    }
+    if prefix.contains('/') || prefix.contains('\\') {
+        return Err(format!(
+            "Source prefix '{}' must not contain a path separator",
+            prefix
+        ));
+    }
+    if prefix == "." || prefix == ".." {
+        return Err(format!("Source prefix '{}' must name a directory", prefix));
+    }
+    Ok(())
 }
 
 /// Global skill configuration
@@ -64,6 +237,25 @@ fn default_inherit() -> bool {
     true
 }
 
+/// Overrides applied over the base config when a profile is selected
+///
+/// Each field that is set replaces the matching base field; unset fields
+/// keep the base value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profile {
+    /// Replaces `sources.skills`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<SkillSource>>,
+
+    /// Replaces `global.targets`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<PathBuf>>,
+
+    /// Replaces `global.skills`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,6 +281,30 @@ mod tests {
         assert_eq!(config.global.skills.len(), 1);
         assert_eq!(config.global.skills[0], "my-skill");
         assert!(config.projects.is_empty());
+        assert!(config.sources.templates.is_empty());
+        assert_eq!(config.validation.max_description_len, 1024);
+    }
+
+    #[test]
+    fn should_deserialize_max_description_len() {
+        // Given
+        let toml = r#"
+            [sources]
+            skills = ["/home/user/.config/loadout/skills"]
+
+            [global]
+            targets = []
+            skills = []
+
+            [validation]
+            max_description_len = 200
+        "#;
+
+        // When
+        let config: Config = toml::from_str(toml).unwrap();
+
+        // Then
+        assert_eq!(config.validation.max_description_len, 200);
     }
 
     #[test]
@@ -165,19 +381,70 @@ mod tests {
         // Then
         assert_eq!(config.sources.skills.len(), 3);
         assert_eq!(
-            config.sources.skills[0],
+            config.sources.skills[0].path,
             PathBuf::from("/home/user/.config/loadout/skills")
         );
         assert_eq!(
-            config.sources.skills[1],
+            config.sources.skills[1].path,
             PathBuf::from("/opt/shared-skills")
         );
         assert_eq!(
-            config.sources.skills[2],
+            config.sources.skills[2].path,
             PathBuf::from("/home/user/projects/team-skills")
         );
     }
 
+    #[test]
+    fn should_accept_bare_and_prefixed_sources() {
+        // Given
+        let toml = r#"
+            [sources]
+            skills = [
+                "/home/user/.config/loadout/skills",
+                { path = "/opt/vendor/skills", prefix = "vendor" },
+            ]
+
+            [global]
+            targets = []
+            skills = []
+        "#;
+
+        // When
+        let config: Config = toml::from_str(toml).unwrap();
+
+        // Then
+        assert_eq!(
+            config.sources.skills,
+            vec![
+                SkillSource {
+                    path: PathBuf::from("/home/user/.config/loadout/skills"),
+                    prefix: None,
+                },
+                SkillSource {
+                    path: PathBuf::from("/opt/vendor/skills"),
+                    prefix: Some("vendor".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reject_prefixes_that_leave_the_prefix_directory() {
+        for prefix in ["", "..", "/opt", "a/b"] {
+            // Given
+            let toml = format!(
+                "[sources]\nskills = [{{ path = \"/opt/vendor\", prefix = \"{}\" }}]\n\n[global]\ntargets = []\nskills = []\n",
+                prefix
+            );
+
+            // When
+            let result = toml::from_str::<Config>(&toml);
+
+            // Then
+            assert!(result.is_err(), "prefix '{}' was accepted", prefix);
+        }
+    }
+
     #[test]
     fn should_handle_multiple_targets() {
         // Given
@@ -1,14 +1,19 @@
 //! Dependency graph construction and analysis (requires `graph` feature)
 
-use petgraph::algo::tarjan_scc;
-use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::algo::{tarjan_scc, toposort};
+use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
 use petgraph::visit::EdgeRef;
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 
-use crate::skill::{CrossRef, Skill};
+use crate::skill::{normalize_name, CrossRef, PipelineStage, Skill};
+
+/// Paths explored per apex by [`SkillGraph::diamonds`]
+pub const DEFAULT_DIAMOND_PATH_LIMIT: usize = 10_000;
 
 /// Edge type in the skill graph
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EdgeKind {
     /// Detected from content cross-references
     CrossRef,
@@ -16,15 +21,178 @@ pub enum EdgeKind {
     Pipeline,
 }
 
+impl EdgeKind {
+    /// Lowercase label used in exported output
+    pub fn label(&self) -> &'static str {
+        match self {
+            EdgeKind::CrossRef => "crossref",
+            EdgeKind::Pipeline => "pipeline",
+        }
+    }
+}
+
+/// Edge weight: how the edge was declared and, for cross-references, where
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Edge {
+    kind: EdgeKind,
+    /// Line of the first reference in the source skill's SKILL.md
+    line: Option<usize>,
+    /// Number of references the edge stands for (always 1 for pipeline edges)
+    weight: usize,
+}
+
+/// Summary numbers for a skill graph (see [`SkillGraph::stats`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStats {
+    pub nodes: usize,
+    pub crossref_edges: usize,
+    pub pipeline_edges: usize,
+    pub clusters: usize,
+    /// Member count of the biggest cluster (0 without clusters)
+    pub largest_cluster: usize,
+    pub roots: usize,
+    pub leaves: usize,
+    pub bridges: usize,
+    /// Highest incoming edge count and the skills that have it, sorted
+    pub max_in_degree: (usize, Vec<String>),
+    /// Highest outgoing edge count and the skills that have it, sorted
+    pub max_out_degree: (usize, Vec<String>),
+    /// Edges as a fraction of the `n(n - 1)` possible directed edges
+    pub density: f64,
+}
+
+impl GraphStats {
+    /// Total edge count across kinds
+    pub fn edges(&self) -> usize {
+        self.crossref_edges + self.pipeline_edges
+    }
+}
+
+/// Order in which exports list skills
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NodeOrder {
+    /// Alphabetical by name
+    #[default]
+    Alpha,
+    /// Dependencies before the skills that need them; alphabetical if cyclic
+    Topo,
+}
+
+/// Direction DOT lays out ranks in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankDir {
+    /// Left to right
+    #[default]
+    LeftRight,
+    /// Top to bottom
+    TopBottom,
+}
+
+impl RankDir {
+    /// Graphviz `rankdir` value
+    fn label(&self) -> &'static str {
+        match self {
+            RankDir::LeftRight => "LR",
+            RankDir::TopBottom => "TB",
+        }
+    }
+}
+
+/// Extra pen width given to edges touching a highlighted skill
+const HIGHLIGHT_PENWIDTH: usize = 2;
+
+/// Layout hints for [`SkillGraph::to_dot_with`]
+///
+/// The default reproduces [`SkillGraph::to_dot`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DotOptions<'a> {
+    pub rankdir: RankDir,
+    /// Pin roots to the first rank and leaves to the last
+    pub rank_roots: bool,
+    /// Skill drawn with a bold gold fill instead of its role color, with
+    /// thickened direct edges
+    pub highlight: Option<&'a str>,
+}
+
+/// Structural differences between two skill graphs
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GraphDiff {
+    /// Skills present only in the other graph
+    pub added_nodes: Vec<String>,
+
+    /// Skills present only in this graph
+    pub removed_nodes: Vec<String>,
+
+    /// Edges present only in the other graph, as (source, target, kind)
+    pub added_edges: Vec<(String, String, EdgeKind)>,
+
+    /// Edges present only in this graph, as (source, target, kind)
+    pub removed_edges: Vec<(String, String, EdgeKind)>,
+}
+
+impl GraphDiff {
+    /// Whether the two graphs are structurally identical
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+
+    /// Export the diff as `+`/`-` prefixed lines grouped into sections
+    pub fn to_text(&self) -> String {
+        let mut output = String::from("# Skill Graph Diff\n");
+
+        let node_sections = [
+            ("Added skills", "+", &self.added_nodes),
+            ("Removed skills", "-", &self.removed_nodes),
+        ];
+        for (title, prefix, nodes) in node_sections {
+            output.push_str(&format!("\n## {} ({})\n", title, nodes.len()));
+            if !nodes.is_empty() {
+                output.push('\n');
+            }
+            for name in nodes {
+                output.push_str(&format!("{} {}\n", prefix, name));
+            }
+        }
+
+        let edge_sections = [
+            ("Added edges", "+", &self.added_edges),
+            ("Removed edges", "-", &self.removed_edges),
+        ];
+        for (title, prefix, edges) in edge_sections {
+            output.push_str(&format!("\n## {} ({})\n", title, edges.len()));
+            if !edges.is_empty() {
+                output.push('\n');
+            }
+            for (source, target, kind) in edges {
+                output.push_str(&format!(
+                    "{} {} -> {} ({})\n",
+                    prefix,
+                    source,
+                    target,
+                    kind.label()
+                ));
+            }
+        }
+
+        output
+    }
+}
+
 /// A skill dependency graph with analysis results
 #[derive(Debug)]
 pub struct SkillGraph {
     /// The underlying directed graph
-    graph: DiGraph<String, EdgeKind>,
+    graph: DiGraph<String, Edge>,
 
     /// Map from skill name to node index
     name_to_node: HashMap<String, NodeIndex>,
 
+    /// Map from skill name to its SKILL.md, for skills with a definition
+    name_to_path: HashMap<String, PathBuf>,
+
     /// Detected clusters (strongly connected components)
     pub clusters: Vec<Vec<String>>,
 
@@ -44,80 +212,110 @@ impl SkillGraph {
         let mut graph = DiGraph::new();
         let mut name_to_node = HashMap::new();
         let mut edge_set: HashSet<(String, String)> = HashSet::new();
-
-        // Collect all unique skill names from crossrefs
-        let mut all_skills: HashSet<String> = HashSet::new();
-        for (source, refs) in crossrefs {
-            all_skills.insert(source.clone());
-            for r in refs {
-                all_skills.insert(r.target.clone());
-            }
-        }
-
-        // Also add all discovered skills (some may have no crossrefs)
-        for skill in skills {
-            all_skills.insert(skill.name.clone());
+        let mut crossref_edges: HashMap<(String, String), EdgeIndex> = HashMap::new();
+
+        // Sources go in name order so neither node names nor edge order
+        // depend on hashing
+        let mut sources: Vec<_> = crossrefs.iter().collect();
+        sources.sort_by(|a, b| a.0.cmp(b.0));
+
+        // Names match on their normalized form. Discovered skills (some may
+        // have no crossrefs) keep their own name; other spellings map to the
+        // first one seen.
+        let mut canonical: HashMap<String, String> = HashMap::new();
+        let names = skills
+            .iter()
+            .map(|skill| &skill.name)
+            .chain(sources.iter().flat_map(|(source, refs)| {
+                std::iter::once(*source).chain(refs.iter().map(|r| &r.target))
+            }));
+        for name in names {
+            canonical
+                .entry(normalize_name(name))
+                .or_insert_with(|| name.clone());
         }
+        let resolve = |name: &str| canonical.get(&normalize_name(name)).cloned();
 
         // Add all skills as nodes
-        let mut sorted_skills: Vec<_> = all_skills.iter().cloned().collect();
+        let mut sorted_skills: Vec<_> = canonical.values().cloned().collect();
         sorted_skills.sort();
         for skill in &sorted_skills {
             let node = graph.add_node(skill.clone());
             name_to_node.insert(skill.clone(), node);
         }
 
-        // Add deduplicated edges from cross-references
-        for (source, refs) in crossrefs {
-            let source_node = name_to_node[source];
+        // Add deduplicated edges from cross-references, counting repeats
+        for (source, refs) in sources {
+            let source = resolve(source).unwrap_or_else(|| source.clone());
+            let source_node = name_to_node[&source];
             for r in refs {
-                let edge_key = (source.clone(), r.target.clone());
-                if !edge_set.contains(&edge_key) {
-                    if let Some(&target_node) = name_to_node.get(&r.target) {
-                        graph.add_edge(source_node, target_node, EdgeKind::CrossRef);
-                        edge_set.insert(edge_key);
-                    }
+                let target = resolve(&r.target).unwrap_or_else(|| r.target.clone());
+                let edge_key = (source.clone(), target);
+                if let Some(&edge) = crossref_edges.get(&edge_key) {
+                    let existing: &mut Edge = &mut graph[edge];
+                    existing.weight += 1;
+                    existing.line = existing.line.min(Some(r.line));
+                    continue;
+                }
+                if let Some(&target_node) = name_to_node.get(&edge_key.1) {
+                    let edge = graph.add_edge(
+                        source_node,
+                        target_node,
+                        Edge {
+                            kind: EdgeKind::CrossRef,
+                            line: Some(r.line),
+                            weight: 1,
+                        },
+                    );
+                    crossref_edges.insert(edge_key.clone(), edge);
+                    edge_set.insert(edge_key);
                 }
             }
         }
 
         // Add edges from pipeline after/before declarations
-        for skill in skills {
-            if let Some(pipeline) = &skill.frontmatter.pipeline {
-                for stage in pipeline.values() {
-                    // "after" means this skill depends on those skills
-                    if let Some(after) = &stage.after {
-                        for dep in after {
-                            let edge_key = (skill.name.clone(), dep.clone());
-                            if !edge_set.contains(&edge_key) {
-                                if let (Some(&source_node), Some(&target_node)) =
-                                    (name_to_node.get(&skill.name), name_to_node.get(dep))
-                                {
-                                    graph.add_edge(source_node, target_node, EdgeKind::Pipeline);
-                                    edge_set.insert(edge_key);
-                                }
-                            }
-                        }
-                    }
-                    // "before" means those skills depend on this skill (reverse direction)
-                    if let Some(before) = &stage.before {
-                        for dep in before {
-                            let edge_key = (dep.clone(), skill.name.clone());
-                            if !edge_set.contains(&edge_key) {
-                                if let (Some(&source_node), Some(&target_node)) =
-                                    (name_to_node.get(dep), name_to_node.get(&skill.name))
-                                {
-                                    graph.add_edge(source_node, target_node, EdgeKind::Pipeline);
-                                    edge_set.insert(edge_key);
-                                }
-                            }
-                        }
-                    }
-                }
+        for (dependent, dependency) in crate::skill::pipeline_edges(skills) {
+            let (Some(dependent), Some(dependency)) = (resolve(&dependent), resolve(&dependency))
+            else {
+                continue;
+            };
+            let edge_key = (dependent, dependency);
+            if edge_set.contains(&edge_key) {
+                continue;
             }
+            if let (Some(&source_node), Some(&target_node)) =
+                (name_to_node.get(&edge_key.0), name_to_node.get(&edge_key.1))
+            {
+                graph.add_edge(
+                    source_node,
+                    target_node,
+                    Edge {
+                        kind: EdgeKind::Pipeline,
+                        line: None,
+                        weight: 1,
+                    },
+                );
+                edge_set.insert(edge_key);
+            }
+        }
+
+        // First definition wins, matching source priority
+        let mut name_to_path = HashMap::new();
+        for skill in skills {
+            name_to_path
+                .entry(skill.name.clone())
+                .or_insert_with(|| skill.skill_file.clone());
         }
 
-        // Analyze the graph
+        Self::analyze(graph, name_to_node, name_to_path)
+    }
+
+    /// Run cluster, root, leaf and bridge detection over a built graph
+    fn analyze(
+        graph: DiGraph<String, Edge>,
+        name_to_node: HashMap<String, NodeIndex>,
+        name_to_path: HashMap<String, PathBuf>,
+    ) -> Self {
         let clusters = detect_clusters(&graph, &name_to_node);
         let roots = find_roots(&graph, &name_to_node);
         let leaves = find_leaves(&graph, &name_to_node);
@@ -126,6 +324,7 @@ impl SkillGraph {
         SkillGraph {
             graph,
             name_to_node,
+            name_to_path,
             clusters,
             roots,
             leaves,
@@ -138,6 +337,14 @@ impl SkillGraph {
         Self::from_skills(crossrefs, &[])
     }
 
+    /// SKILL.md path of a skill in the graph
+    ///
+    /// `None` for dangling references and for graphs built with
+    /// [`SkillGraph::from_crossrefs`].
+    pub fn skill_path(&self, name: &str) -> Option<&Path> {
+        self.name_to_path.get(name).map(PathBuf::as_path)
+    }
+
     /// Filter to only skills in a specific pipeline
     pub fn filter_pipeline(&self, skills: &[Skill], pipeline_name: &str) -> Self {
         let pipeline_skills: HashSet<String> = skills
@@ -152,248 +359,1623 @@ impl SkillGraph {
             .map(|s| s.name.clone())
             .collect();
 
-        self.filter_to_skills(&pipeline_skills, skills)
+        self.filter_to_skills(&pipeline_skills)
     }
 
     /// Filter to only skills with a specific tag
     pub fn filter_tag(&self, skills: &[Skill], tag: &str) -> Self {
-        let tagged_skills: HashSet<String> = skills
+        self.filter_to_skills(&tagged_skills(skills, tag))
+    }
+
+    /// Drop skills with a specific tag, along with their edges
+    ///
+    /// Nodes without a skill definition (dangling references) carry no tags
+    /// and are kept.
+    pub fn exclude_tag(&self, skills: &[Skill], tag: &str) -> Self {
+        self.exclude_skills(&tagged_skills(skills, tag))
+    }
+
+    /// Drop skills whose frontmatter marks them deprecated, along with their edges
+    ///
+    /// Dangling references have no frontmatter and are kept.
+    pub fn exclude_deprecated(&self, skills: &[Skill]) -> Self {
+        let deprecated: HashSet<String> = skills
             .iter()
-            .filter(|s| {
-                s.frontmatter
-                    .tags
-                    .as_ref()
-                    .map(|t| t.contains(&tag.to_string()))
-                    .unwrap_or(false)
-            })
+            .filter(|s| s.frontmatter.deprecated.is_some())
             .map(|s| s.name.clone())
             .collect();
 
-        self.filter_to_skills(&tagged_skills, skills)
+        self.exclude_skills(&deprecated)
     }
 
-    /// Create a subgraph containing only the specified skills
-    fn filter_to_skills(&self, keep: &HashSet<String>, skills: &[Skill]) -> Self {
-        let mut crossrefs: HashMap<String, Vec<CrossRef>> = HashMap::new();
+    /// Keep every node except the named ones
+    fn exclude_skills(&self, excluded: &HashSet<String>) -> Self {
+        let keep: HashSet<String> = self
+            .name_to_node
+            .keys()
+            .filter(|name| !excluded.contains(*name))
+            .cloned()
+            .collect();
 
-        // Rebuild crossrefs for kept nodes only
-        for (name, &idx) in &self.name_to_node {
-            if !keep.contains(name) {
-                continue;
-            }
-            let edges: Vec<CrossRef> = self
-                .graph
-                .edges(idx)
-                .filter(|e| keep.contains(&self.graph[e.target()]))
-                .map(|e| CrossRef {
-                    target: self.graph[e.target()].clone(),
-                    line: 0,
-                    method: crate::skill::DetectionMethod::XmlCrossref,
-                })
-                .collect();
-            if !edges.is_empty() {
-                crossrefs.insert(name.clone(), edges);
+        self.filter_to_skills(&keep)
+    }
+
+    /// Filter to a skill and every skill that transitively depends on it
+    pub fn filter_impact(&self, skill: &str) -> Self {
+        let mut affected: HashSet<String> = self.impact_of(skill).into_iter().collect();
+        affected.insert(skill.to_string());
+
+        self.filter_to_skills(&affected)
+    }
+
+    /// Filter to the given skills and everything reachable from them
+    ///
+    /// Follows outgoing edges breadth-first from every start at once. Unknown
+    /// start names are ignored.
+    pub fn reachable_from(&self, starts: &[String]) -> Self {
+        let mut seen: HashSet<NodeIndex> = starts
+            .iter()
+            .filter_map(|name| self.name_to_node.get(name).copied())
+            .collect();
+        let mut queue: VecDeque<NodeIndex> = seen.iter().copied().collect();
+
+        while let Some(idx) = queue.pop_front() {
+            for neighbor in self.graph.neighbors(idx) {
+                if seen.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
             }
         }
 
-        // Only pass skills that are in the keep set
-        let filtered_skills: Vec<Skill> = skills
+        let keep: HashSet<String> = seen.iter().map(|&idx| self.graph[idx].clone()).collect();
+        self.filter_to_skills(&keep)
+    }
+
+    /// Filter to skills reachable from a root that has outgoing edges
+    ///
+    /// Drops unconnected skills and islands no root leads into, such as
+    /// cycles nothing else references.
+    pub fn reachable_from_roots(&self) -> Self {
+        let unconnected = self.unconnected();
+        let starts: Vec<String> = self
+            .roots
             .iter()
-            .filter(|s| keep.contains(&s.name))
+            .filter(|name| !unconnected.contains(name))
             .cloned()
             .collect();
-
-        Self::from_skills(&crossrefs, &filtered_skills)
+        self.reachable_from(&starts)
     }
 
-    /// Export graph as Graphviz DOT format
-    pub fn to_dot(&self) -> String {
-        let mut output = String::from("digraph SkillGraph {\n");
-        output.push_str("  rankdir=LR;\n");
-        output.push_str("  node [shape=box, style=rounded];\n\n");
-
-        // Add nodes
-        let mut sorted: Vec<_> = self.name_to_node.iter().collect();
-        sorted.sort_by_key(|(name, _)| (*name).clone());
-        for (name, _) in &sorted {
-            let color = if self.roots.contains(*name) {
-                "lightblue"
-            } else if self.leaves.contains(*name) {
-                "lightgreen"
-            } else if self.bridges.contains(*name) {
-                "orange"
-            } else {
-                "white"
-            };
-            output.push_str(&format!(
-                "  \"{}\" [fillcolor={}, style=\"rounded,filled\"];\n",
-                name, color
-            ));
-        }
-
-        output.push('\n');
+    /// All skill names in the graph, sorted
+    pub fn node_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.name_to_node.keys().cloned().collect();
+        names.sort();
+        names
+    }
 
-        // Add edges with style based on kind
-        for edge in self.graph.edge_references() {
-            let source = &self.graph[edge.source()];
-            let target = &self.graph[edge.target()];
-            let style = match edge.weight() {
-                EdgeKind::CrossRef => "",
-                EdgeKind::Pipeline => " [style=dashed, color=blue]",
-            };
-            output.push_str(&format!("  \"{}\" -> \"{}\"{};\n", source, target, style));
-        }
+    /// Number of skills in the graph
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
 
-        output.push_str("}\n");
-        output
+    /// Number of edges in the graph, after deduplication
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
     }
 
-    /// Export graph as human-readable adjacency list
-    pub fn to_text(&self) -> String {
-        let mut output = String::new();
+    /// Outgoing edges of a skill as `(target, kind, line)`, sorted by target
+    ///
+    /// `line` is where the first cross-reference appears in the skill's
+    /// SKILL.md; pipeline edges have none. Returns `None` for a skill that
+    /// isn't in the graph.
+    pub fn edges_from(&self, skill: &str) -> Option<Vec<(String, EdgeKind, Option<usize>)>> {
+        self.edges_directed(skill, petgraph::Direction::Outgoing)
+    }
 
-        output.push_str("# Skill Dependency Graph\n\n");
+    /// Incoming edges of a skill as `(source, kind, line)`, sorted by source
+    ///
+    /// `line` refers to the source skill's SKILL.md. Returns `None` for a
+    /// skill that isn't in the graph.
+    pub fn edges_to(&self, skill: &str) -> Option<Vec<(String, EdgeKind, Option<usize>)>> {
+        self.edges_directed(skill, petgraph::Direction::Incoming)
+    }
 
-        // Show analysis summary
-        output.push_str(&format!("Skills: {}\n", self.name_to_node.len()));
-        output.push_str(&format!("Clusters: {}\n", self.clusters.len()));
-        output.push_str(&format!("Roots: {}\n", self.roots.len()));
-        output.push_str(&format!("Leaves: {}\n", self.leaves.len()));
-        output.push_str(&format!("Bridges: {}\n\n", self.bridges.len()));
+    fn edges_directed(
+        &self,
+        skill: &str,
+        direction: petgraph::Direction,
+    ) -> Option<Vec<(String, EdgeKind, Option<usize>)>> {
+        let &idx = self.name_to_node.get(skill)?;
+
+        let mut edges: Vec<(String, EdgeKind, Option<usize>)> = self
+            .graph
+            .edges_directed(idx, direction)
+            .map(|e| {
+                let other = match direction {
+                    petgraph::Direction::Outgoing => e.target(),
+                    petgraph::Direction::Incoming => e.source(),
+                };
+                (self.graph[other].clone(), e.weight().kind, e.weight().line)
+            })
+            .collect();
+        edges.sort();
+        Some(edges)
+    }
 
-        // Show adjacency list
-        output.push_str("## Dependencies\n\n");
-        let mut sorted_skills: Vec<_> = self.name_to_node.keys().collect();
-        sorted_skills.sort();
+    /// Skills with no incoming and no outgoing edges, sorted by name
+    pub fn unconnected(&self) -> Vec<String> {
+        self.roots
+            .iter()
+            .filter(|name| self.leaves.contains(name))
+            .cloned()
+            .collect()
+    }
 
-        for skill in sorted_skills {
-            let node = self.name_to_node[skill];
-            let mut targets: Vec<String> = self
+    /// Find all skills that transitively depend on a skill
+    ///
+    /// Walks incoming edges from the given skill, so the result is everything
+    /// that would be affected by removing it. Returns names sorted, excluding
+    /// the skill itself.
+    pub fn impact_of(&self, skill: &str) -> Vec<String> {
+        let start = match self.name_to_node.get(skill) {
+            Some(&idx) => idx,
+            None => return Vec::new(),
+        };
+
+        let mut visited: HashSet<NodeIndex> = HashSet::new();
+        visited.insert(start);
+        let mut stack = vec![start];
+
+        while let Some(idx) = stack.pop() {
+            for dependent in self
                 .graph
-                .edges(node)
-                .map(|e| self.graph[e.target()].clone())
-                .collect();
-            targets.sort();
-            targets.dedup();
-
-            if targets.is_empty() {
-                output.push_str(&format!("{}: (none)\n", skill));
-            } else {
-                output.push_str(&format!("{}: {}\n", skill, targets.join(", ")));
+                .neighbors_directed(idx, petgraph::Direction::Incoming)
+            {
+                if visited.insert(dependent) {
+                    stack.push(dependent);
+                }
             }
         }
 
-        output
+        let mut affected: Vec<String> = visited
+            .into_iter()
+            .filter(|&idx| idx != start)
+            .map(|idx| self.graph[idx].clone())
+            .collect();
+        affected.sort();
+        affected
     }
 
-    /// Export graph as JSON
-    pub fn to_json(&self) -> String {
-        let mut nodes = Vec::new();
-        let mut edges = Vec::new();
+    /// Restrict the graph to skills within `depth` hops of `center`
+    ///
+    /// Hops follow edges in either direction. A depth of 0 keeps only the
+    /// center; an unknown center yields an empty graph.
+    pub fn neighborhood(&self, center: &str, depth: usize) -> SkillGraph {
+        let mut keep: HashSet<String> = HashSet::new();
+
+        if let Some(&start) = self.name_to_node.get(center) {
+            let mut distance: HashMap<NodeIndex, usize> = HashMap::new();
+            distance.insert(start, 0);
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+
+            while let Some(idx) = queue.pop_front() {
+                let hops = distance[&idx];
+                if hops == depth {
+                    continue;
+                }
+                for neighbor in self.graph.neighbors_undirected(idx) {
+                    if let std::collections::hash_map::Entry::Vacant(entry) =
+                        distance.entry(neighbor)
+                    {
+                        entry.insert(hops + 1);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
 
-        let mut sorted: Vec<_> = self.name_to_node.iter().collect();
-        sorted.sort_by_key(|(name, _)| (*name).clone());
+            keep.extend(distance.keys().map(|&idx| self.graph[idx].clone()));
+        }
 
-        for (name, &idx) in &sorted {
-            nodes.push(serde_json::json!({
-                "id": name,
-                "is_root": self.roots.contains(*name),
-                "is_leaf": self.leaves.contains(*name),
-                "is_bridge": self.bridges.contains(*name),
-            }));
+        self.filter_to_skills(&keep)
+    }
 
-            for edge in self.graph.edges(idx) {
-                let target = &self.graph[edge.target()];
-                let kind = match edge.weight() {
-                    EdgeKind::CrossRef => "crossref",
-                    EdgeKind::Pipeline => "pipeline",
-                };
-                edges.push(serde_json::json!({
-                    "source": name,
-                    "target": target,
-                    "kind": kind,
-                }));
+    /// Keep every skill but only edges of the given kinds
+    ///
+    /// Roots, leaves, bridges and clusters are recomputed from the remaining
+    /// edges.
+    pub fn filter_edges(&self, kinds: &[EdgeKind]) -> Self {
+        let mut graph = DiGraph::new();
+        let mut name_to_node = HashMap::new();
+
+        for idx in self.graph.node_indices() {
+            let name = &self.graph[idx];
+            name_to_node.insert(name.clone(), graph.add_node(name.clone()));
+        }
+
+        for edge in self.graph.edge_references() {
+            if kinds.contains(&edge.weight().kind) {
+                let source = name_to_node[&self.graph[edge.source()]];
+                let target = name_to_node[&self.graph[edge.target()]];
+                graph.add_edge(source, target, *edge.weight());
             }
         }
 
-        serde_json::json!({
-            "nodes": nodes,
-            "edges": edges,
-            "clusters": self.clusters,
-        })
-        .to_string()
+        Self::analyze(graph, name_to_node, self.name_to_path.clone())
     }
 
-    /// Export graph as Mermaid diagram
-    pub fn to_mermaid(&self) -> String {
-        let mut output = String::from("graph LR\n");
-        let mut seen_edges: HashSet<(String, String)> = HashSet::new();
+    /// Create a subgraph containing only the specified skills
+    ///
+    /// Edge kinds are preserved and the analysis is recomputed against the
+    /// kept nodes.
+    fn filter_to_skills(&self, keep: &HashSet<String>) -> Self {
+        let mut graph = DiGraph::new();
+        let mut name_to_node = HashMap::new();
+
+        // Node indices were assigned alphabetically, so this preserves ordering
+        for idx in self.graph.node_indices() {
+            let name = &self.graph[idx];
+            if keep.contains(name) {
+                name_to_node.insert(name.clone(), graph.add_node(name.clone()));
+            }
+        }
 
         for edge in self.graph.edge_references() {
             let source = &self.graph[edge.source()];
             let target = &self.graph[edge.target()];
-            let key = (source.clone(), target.clone());
-            if seen_edges.contains(&key) {
-                continue;
+            if let (Some(&source_node), Some(&target_node)) =
+                (name_to_node.get(source), name_to_node.get(target))
+            {
+                graph.add_edge(source_node, target_node, *edge.weight());
             }
-            seen_edges.insert(key);
-
-            let arrow = match edge.weight() {
-                EdgeKind::CrossRef => "-->",
-                EdgeKind::Pipeline => "-.->",
-            };
-            output.push_str(&format!(
-                "  {}[{}] {} {}[{}]\n",
-                sanitize_mermaid(source),
-                source,
-                arrow,
-                sanitize_mermaid(target),
-                target
-            ));
         }
 
-        output
-    }
-}
-
-fn sanitize_mermaid(s: &str) -> String {
-    s.replace('-', "_")
-}
+        let name_to_path = self
+            .name_to_path
+            .iter()
+            .filter(|(name, _)| keep.contains(*name))
+            .map(|(name, path)| (name.clone(), path.clone()))
+            .collect();
 
-fn detect_clusters(
-    graph: &DiGraph<String, EdgeKind>,
-    _name_to_node: &HashMap<String, NodeIndex>,
-) -> Vec<Vec<String>> {
-    // Use Tarjan's algorithm to find strongly connected components
-    let sccs = tarjan_scc(graph);
+        Self::analyze(graph, name_to_node, name_to_path)
+    }
 
-    let mut clusters = Vec::new();
-    for scc in sccs {
-        let cluster: Vec<String> = scc.iter().map(|&idx| graph[idx].clone()).collect();
+    /// Linearize the graph so every skill appears after the skills it depends on
+    ///
+    /// Returns the detected cycles (multi-skill clusters, or a single self-referencing
+    /// skill) when no valid ordering exists.
+    pub fn topological_order(&self) -> Result<Vec<String>, Vec<Vec<String>>> {
+        match toposort(&self.graph, None) {
+            Ok(order) => {
+                // Edges point from a skill to its dependencies, so reverse to put
+                // dependencies first
+                Ok(order
+                    .into_iter()
+                    .rev()
+                    .map(|idx| self.graph[idx].clone())
+                    .collect())
+            }
+            Err(cycle) => {
+                let mut cycles: Vec<Vec<String>> = self
+                    .clusters
+                    .iter()
+                    .map(|cluster| {
+                        let mut members = cluster.clone();
+                        members.sort();
+                        members
+                    })
+                    .collect();
+                if cycles.is_empty() {
+                    cycles.push(vec![self.graph[cycle.node_id()].clone()]);
+                }
+                cycles.sort();
+                Err(cycles)
+            }
+        }
+    }
 
-        // Only include clusters with more than one skill
-        if cluster.len() > 1 {
-            clusters.push(cluster);
+    /// Compare this graph against another
+    ///
+    /// Edges are compared as (source, target, kind) triples, so an edge that
+    /// changes kind shows up as one removal plus one addition.
+    pub fn diff(&self, other: &SkillGraph) -> GraphDiff {
+        let ours: HashSet<&String> = self.name_to_node.keys().collect();
+        let theirs: HashSet<&String> = other.name_to_node.keys().collect();
+
+        let mut added_nodes: Vec<String> = theirs.difference(&ours).map(|n| (*n).clone()).collect();
+        let mut removed_nodes: Vec<String> =
+            ours.difference(&theirs).map(|n| (*n).clone()).collect();
+        added_nodes.sort();
+        removed_nodes.sort();
+
+        let our_edges = self.edge_triples();
+        let their_edges = other.edge_triples();
+
+        let mut added_edges: Vec<_> = their_edges.difference(&our_edges).cloned().collect();
+        let mut removed_edges: Vec<_> = our_edges.difference(&their_edges).cloned().collect();
+        added_edges.sort();
+        removed_edges.sort();
+
+        GraphDiff {
+            added_nodes,
+            removed_nodes,
+            added_edges,
+            removed_edges,
         }
     }
 
-    clusters
-}
+    /// All edges as (source, target, kind) triples
+    fn edge_triples(&self) -> HashSet<(String, String, EdgeKind)> {
+        self.graph
+            .edge_references()
+            .map(|e| {
+                (
+                    self.graph[e.source()].clone(),
+                    self.graph[e.target()].clone(),
+                    e.weight().kind,
+                )
+            })
+            .collect()
+    }
 
-fn find_roots(
-    graph: &DiGraph<String, EdgeKind>,
-    name_to_node: &HashMap<String, NodeIndex>,
-) -> Vec<String> {
-    let mut roots = Vec::new();
+    /// Find a concrete directed cycle for every cluster
+    ///
+    /// Each multi-skill cluster yields its shortest cycle through the
+    /// alphabetically-first member, listed in traversal order without repeating
+    /// the start. Skills that reference themselves are reported as
+    /// single-element cycles.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles = Vec::new();
+
+        for cluster in &self.clusters {
+            let members: HashSet<NodeIndex> =
+                cluster.iter().map(|name| self.name_to_node[name]).collect();
+            if let Some(start) = cluster.iter().min() {
+                if let Some(cycle) = self.shortest_cycle_through(self.name_to_node[start], &members)
+                {
+                    cycles.push(cycle);
+                }
+            }
+        }
 
-    for (name, &idx) in name_to_node {
-        // Root skills have no incoming edges
-        if graph
-            .edges_directed(idx, petgraph::Direction::Incoming)
-            .count()
-            == 0
-        {
-            roots.push(name.clone());
+        for edge in self.graph.edge_references() {
+            if edge.source() == edge.target() {
+                cycles.push(vec![self.graph[edge.source()].clone()]);
+            }
+        }
+
+        cycles.sort();
+        cycles
+    }
+
+    /// Breadth-first search for the shortest path from `start` back to itself,
+    /// staying within `members`
+    fn shortest_cycle_through(
+        &self,
+        start: NodeIndex,
+        members: &HashSet<NodeIndex>,
+    ) -> Option<Vec<String>> {
+        let mut parent: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut seen: HashSet<NodeIndex> = HashSet::new();
+        seen.insert(start);
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        while let Some(v) = queue.pop_front() {
+            let mut next: Vec<NodeIndex> = self
+                .graph
+                .neighbors(v)
+                .filter(|w| *w != v && members.contains(w))
+                .collect();
+            next.sort_by(|a, b| self.graph[*a].cmp(&self.graph[*b]));
+            next.dedup();
+
+            for w in next {
+                if w == start {
+                    let mut path = vec![self.graph[v].clone()];
+                    let mut current = v;
+                    while current != start {
+                        current = parent[&current];
+                        path.push(self.graph[current].clone());
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if seen.insert(w) {
+                    parent.insert(w, v);
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// A small set of edges whose removal leaves the graph acyclic
+    ///
+    /// Greedy: each cluster (and self-referencing skill) is searched depth-first
+    /// from its alphabetically-first member, and the lowest-weight back-edge
+    /// found is removed, ties going to the alphabetically-first edge. This
+    /// repeats until no cycles remain. The result is sorted, and isn't
+    /// guaranteed to be the minimum set.
+    pub fn feedback_edges(&self) -> Vec<(String, String, EdgeKind)> {
+        let mut graph = self.graph.clone();
+        let mut removed = Vec::new();
+
+        loop {
+            let mut changed = false;
+
+            for scc in tarjan_scc(&graph) {
+                let members: HashSet<NodeIndex> = scc.iter().copied().collect();
+                let Some(&start) = scc.iter().min_by(|a, b| graph[**a].cmp(&graph[**b])) else {
+                    continue;
+                };
+
+                let mut on_stack = HashMap::new();
+                let mut back_edges = Vec::new();
+                collect_back_edges(&graph, start, &members, &mut on_stack, &mut back_edges);
+
+                let lightest = back_edges.into_iter().min_by_key(|&e| {
+                    let (source, target) = graph.edge_endpoints(e).expect("edge exists");
+                    (
+                        graph[e].weight,
+                        &graph[source],
+                        &graph[target],
+                        graph[e].kind,
+                    )
+                });
+                if let Some(edge) = lightest {
+                    let (source, target) = graph.edge_endpoints(edge).expect("edge exists");
+                    removed.push((
+                        graph[source].clone(),
+                        graph[target].clone(),
+                        graph[edge].kind,
+                    ));
+                    graph.remove_edge(edge);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        removed.sort();
+        removed
+    }
+
+    /// Unordered pairs of skills that reference each other directly
+    ///
+    /// Each pair is returned once as `(a, b)` with `a < b`, sorted. Unlike
+    /// clusters, this isolates 2-cycles even inside larger components.
+    pub fn mutual_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs: Vec<(String, String)> = self
+            .graph
+            .edge_references()
+            .filter(|e| e.source() != e.target())
+            .filter(|e| self.graph.contains_edge(e.target(), e.source()))
+            .map(|e| {
+                let a = self.graph[e.source()].clone();
+                let b = self.graph[e.target()].clone();
+                if a < b {
+                    (a, b)
+                } else {
+                    (b, a)
+                }
+            })
+            .collect();
+        pairs.sort();
+        pairs.dedup();
+        pairs
+    }
+
+    /// Weakly connected components: islands of skills linked in either direction
+    ///
+    /// Unlike [`SkillGraph::clusters`], which only group cycles, every skill
+    /// lands in exactly one component. Members are sorted by name; components
+    /// are sorted by descending size, then by first member.
+    pub fn components(&self) -> Vec<Vec<String>> {
+        let mut seen: HashSet<NodeIndex> = HashSet::new();
+        let mut components = Vec::new();
+
+        for start in self.graph.node_indices() {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut members = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            while let Some(node) = queue.pop_front() {
+                members.push(self.graph[node].clone());
+                for next in self.graph.neighbors_undirected(node) {
+                    if seen.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            members.sort();
+            components.push(members);
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        components
+    }
+
+    /// `(apex, base)` pairs joined by two or more distinct simple paths
+    ///
+    /// Explores at most [`DEFAULT_DIAMOND_PATH_LIMIT`] paths from each apex;
+    /// see [`SkillGraph::diamonds_bounded`].
+    pub fn diamonds(&self) -> Vec<(String, String)> {
+        self.diamonds_bounded(DEFAULT_DIAMOND_PATH_LIMIT)
+    }
+
+    /// Like [`SkillGraph::diamonds`], exploring at most `max_paths` paths per apex
+    ///
+    /// Enumeration stops once the limit is hit, so on dense graphs some
+    /// diamonds may go unreported. Sorted by apex, then base.
+    pub fn diamonds_bounded(&self, max_paths: usize) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+
+        for apex in self.graph.node_indices() {
+            let mut counts: HashMap<NodeIndex, usize> = HashMap::new();
+            let mut on_path: HashSet<NodeIndex> = HashSet::from([apex]);
+            let mut budget = max_paths;
+            self.count_paths(apex, &mut on_path, &mut counts, &mut budget);
+
+            for (base, count) in counts {
+                if count >= 2 {
+                    pairs.push((self.graph[apex].clone(), self.graph[base].clone()));
+                }
+            }
+        }
+
+        pairs.sort();
+        pairs
+    }
+
+    /// Depth-first walk counting the simple paths from the walk's start to each node
+    fn count_paths(
+        &self,
+        node: NodeIndex,
+        on_path: &mut HashSet<NodeIndex>,
+        counts: &mut HashMap<NodeIndex, usize>,
+        budget: &mut usize,
+    ) {
+        let mut next: Vec<NodeIndex> = self.graph.neighbors(node).collect();
+        next.sort();
+        next.dedup();
+
+        for target in next {
+            if *budget == 0 {
+                return;
+            }
+            if !on_path.insert(target) {
+                continue;
+            }
+            *budget -= 1;
+            *counts.entry(target).or_default() += 1;
+            self.count_paths(target, on_path, counts, budget);
+            on_path.remove(&target);
+        }
+    }
+
+    /// The most connected skills by total degree (incoming + outgoing edges)
+    ///
+    /// Returns at most `limit` skills, highest degree first, ties by name.
+    pub fn hubs(&self, limit: usize) -> Vec<(String, usize)> {
+        let mut ranked: Vec<(String, usize)> = self
+            .graph
+            .node_indices()
+            .map(|idx| (self.graph[idx].clone(), self.degree(idx)))
+            .collect();
+
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Clusters paired with a representative name
+    ///
+    /// Each cluster is named after its highest-degree member, with ties going
+    /// to the alphabetically first.
+    pub fn named_clusters(&self) -> Vec<(String, Vec<String>)> {
+        self.clusters
+            .iter()
+            .map(|cluster| (self.cluster_name(cluster), cluster.clone()))
+            .collect()
+    }
+
+    /// Representative member of a cluster: highest degree, then first by name
+    fn cluster_name(&self, cluster: &[String]) -> String {
+        cluster
+            .iter()
+            .map(|name| {
+                let degree = self
+                    .name_to_node
+                    .get(name)
+                    .map_or(0, |&idx| self.degree(idx));
+                (name, degree)
+            })
+            .min_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)))
+            .map(|(name, _)| name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Total degree of a node (incoming + outgoing edges)
+    fn degree(&self, idx: NodeIndex) -> usize {
+        self.graph
+            .edges_directed(idx, petgraph::Direction::Outgoing)
+            .count()
+            + self
+                .graph
+                .edges_directed(idx, petgraph::Direction::Incoming)
+                .count()
+    }
+
+    /// Count nodes, edges, and structural features of the graph
+    pub fn stats(&self) -> GraphStats {
+        let count_kind = |kind: EdgeKind| {
+            self.graph
+                .edge_references()
+                .filter(|e| e.weight().kind == kind)
+                .count()
+        };
+
+        let n = self.graph.node_count();
+        let density = if n < 2 {
+            0.0
+        } else {
+            self.graph.edge_count() as f64 / (n * (n - 1)) as f64
+        };
+
+        GraphStats {
+            nodes: n,
+            crossref_edges: count_kind(EdgeKind::CrossRef),
+            pipeline_edges: count_kind(EdgeKind::Pipeline),
+            clusters: self.clusters.len(),
+            largest_cluster: self.clusters.iter().map(Vec::len).max().unwrap_or(0),
+            roots: self.roots.len(),
+            leaves: self.leaves.len(),
+            bridges: self.bridges.len(),
+            max_in_degree: self.max_degree(petgraph::Direction::Incoming),
+            max_out_degree: self.max_degree(petgraph::Direction::Outgoing),
+            density,
+        }
+    }
+
+    /// Highest degree in one direction with its skills; no skills when it's 0
+    fn max_degree(&self, direction: petgraph::Direction) -> (usize, Vec<String>) {
+        let degrees: Vec<(usize, &String)> = self
+            .graph
+            .node_indices()
+            .map(|idx| {
+                (
+                    self.graph.edges_directed(idx, direction).count(),
+                    &self.graph[idx],
+                )
+            })
+            .collect();
+
+        let max = degrees.iter().map(|(d, _)| *d).max().unwrap_or(0);
+        if max == 0 {
+            return (0, Vec::new());
+        }
+
+        let mut owners: Vec<String> = degrees
+            .into_iter()
+            .filter(|(d, _)| *d == max)
+            .map(|(_, name)| name.clone())
+            .collect();
+        owners.sort();
+        (max, owners)
+    }
+
+    /// Rank skills by betweenness centrality, highest first
+    ///
+    /// Uses Brandes' algorithm over the directed graph. Scores are normalized
+    /// to [0, 1] by dividing by the maximum possible number of shortest paths
+    /// through a node, `(n - 1)(n - 2)`. Ties are ordered by name.
+    pub fn centrality(&self) -> Vec<(String, f64)> {
+        let n = self.graph.node_count();
+        let mut scores = vec![0.0_f64; n];
+
+        for source in self.graph.node_indices() {
+            // Single-source shortest paths by BFS
+            let mut stack = Vec::new();
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut path_counts = vec![0.0_f64; n];
+            let mut distance: Vec<Option<usize>> = vec![None; n];
+            path_counts[source.index()] = 1.0;
+            distance[source.index()] = Some(0);
+
+            let mut queue = VecDeque::new();
+            queue.push_back(source);
+            while let Some(v) = queue.pop_front() {
+                stack.push(v.index());
+                let next_distance = distance[v.index()].unwrap_or_default() + 1;
+                for w in self.graph.neighbors(v) {
+                    if distance[w.index()].is_none() {
+                        distance[w.index()] = Some(next_distance);
+                        queue.push_back(w);
+                    }
+                    if distance[w.index()] == Some(next_distance) {
+                        path_counts[w.index()] += path_counts[v.index()];
+                        predecessors[w.index()].push(v.index());
+                    }
+                }
+            }
+
+            // Accumulate dependencies in order of non-increasing distance
+            let mut dependency = vec![0.0_f64; n];
+            while let Some(w) = stack.pop() {
+                for &v in &predecessors[w] {
+                    dependency[v] += path_counts[v] / path_counts[w] * (1.0 + dependency[w]);
+                }
+                if w != source.index() {
+                    scores[w] += dependency[w];
+                }
+            }
+        }
+
+        let scale = if n > 2 {
+            ((n - 1) * (n - 2)) as f64
+        } else {
+            1.0
+        };
+
+        let mut ranked: Vec<(String, f64)> = self
+            .graph
+            .node_indices()
+            .map(|idx| (self.graph[idx].clone(), scores[idx.index()] / scale))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+
+    /// Rank skills by PageRank, highest first
+    ///
+    /// A skill's score is fed by the skills that reference it, each passing on
+    /// its own score split evenly across the skills it references. Skills that
+    /// reference nothing spread theirs across every skill. Scores start
+    /// uniform, sum to 1, and are updated `iterations` times; `damping` is the
+    /// share that follows references rather than being spread evenly. Ties are
+    /// ordered by name.
+    pub fn pagerank(&self, damping: f64, iterations: usize) -> Vec<(String, f64)> {
+        let n = self.graph.node_count();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let targets: Vec<Vec<usize>> = self
+            .graph
+            .node_indices()
+            .map(|idx| {
+                let mut next: Vec<usize> = self.graph.neighbors(idx).map(|w| w.index()).collect();
+                next.sort_unstable();
+                next.dedup();
+                next
+            })
+            .collect();
+
+        let mut scores = vec![1.0 / n as f64; n];
+        for _ in 0..iterations {
+            let dangling: f64 = (0..n)
+                .filter(|&v| targets[v].is_empty())
+                .map(|v| scores[v])
+                .sum();
+            let base = (1.0 - damping) / n as f64 + damping * dangling / n as f64;
+
+            let mut next = vec![base; n];
+            for (v, out) in targets.iter().enumerate() {
+                for &w in out {
+                    next[w] += damping * scores[v] / out.len() as f64;
+                }
+            }
+            scores = next;
+        }
+
+        let mut ranked: Vec<(String, f64)> = self
+            .graph
+            .node_indices()
+            .map(|idx| (self.graph[idx].clone(), scores[idx.index()]))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+
+    /// Skill names in the requested export order
+    ///
+    /// Topological order needs a DAG; with a cycle this warns and falls back
+    /// to alphabetical order.
+    fn ordered_names(&self, order: NodeOrder) -> Vec<String> {
+        match order {
+            NodeOrder::Alpha => self.node_names(),
+            NodeOrder::Topo => self.topological_order().unwrap_or_else(|_| {
+                eprintln!("Warning: graph has a cycle, ordering skills alphabetically");
+                self.node_names()
+            }),
+        }
+    }
+
+    /// Export graph as Graphviz DOT format
+    pub fn to_dot(&self, order: NodeOrder) -> String {
+        self.to_dot_with(order, &DotOptions::default())
+    }
+
+    /// Export graph as Graphviz DOT format with layout hints
+    ///
+    /// With `rank_roots`, roots share a `rank=source` group and leaves a
+    /// `rank=sink` group; skills that are both stay with the roots.
+    pub fn to_dot_with(&self, order: NodeOrder, options: &DotOptions) -> String {
+        let mut output = String::from("digraph SkillGraph {\n");
+        output.push_str(&format!("  rankdir={};\n", options.rankdir.label()));
+        output.push_str("  node [shape=box, style=rounded];\n\n");
+
+        // Box each detected cluster; other nodes stay at top level
+        let mut clustered: HashSet<&str> = HashSet::new();
+        for (i, cluster) in self.clusters.iter().enumerate() {
+            output.push_str(&format!("  subgraph cluster_{} {{\n", i));
+            output.push_str(&format!("    label=\"cluster {}\";\n", i));
+            let mut members: Vec<&String> = cluster.iter().collect();
+            members.sort();
+            for name in members {
+                output.push_str(&format!("  {}", self.dot_node(name, options.highlight)));
+                clustered.insert(name);
+            }
+            output.push_str("  }\n");
+        }
+
+        // Add nodes
+        let names = self.ordered_names(order);
+        for name in &names {
+            if !clustered.contains(name.as_str()) {
+                output.push_str(&self.dot_node(name, options.highlight));
+            }
+        }
+
+        if options.rank_roots {
+            let roots: Vec<&String> = names.iter().filter(|n| self.roots.contains(n)).collect();
+            let leaves: Vec<&String> = names
+                .iter()
+                .filter(|n| self.leaves.contains(n) && !self.roots.contains(n))
+                .collect();
+            for (rank, members) in [("source", roots), ("sink", leaves)] {
+                if members.is_empty() {
+                    continue;
+                }
+                let quoted: Vec<String> = members.iter().map(|n| format!("\"{}\";", n)).collect();
+                output.push_str(&format!("  {{ rank={}; {} }}\n", rank, quoted.join(" ")));
+            }
+        }
+
+        output.push('\n');
+        output.push_str(&self.dot_edges(&names, options.highlight));
+        output.push_str("}\n");
+        output
+    }
+
+    /// Export a pipeline as DOT swimlanes, one column per stage order
+    ///
+    /// `stages` comes from `skill::pipeline_stages`. Skills sharing an order
+    /// are ranked together under a header naming their stages, and headers
+    /// are chained invisibly so columns run left-to-right even where no edge
+    /// connects them. Stages for skills not in the graph are skipped.
+    pub fn to_swimlane(&self, stages: &[(String, PipelineStage)]) -> String {
+        let mut output = String::from("digraph Pipeline {\n");
+        output.push_str("  rankdir=LR;\n");
+        output.push_str("  node [shape=box, style=rounded];\n\n");
+
+        let mut lanes: Vec<(u32, Vec<&str>, Vec<&str>)> = Vec::new();
+        for (name, stage) in stages {
+            if !self.name_to_node.contains_key(name) {
+                continue;
+            }
+            match lanes.last_mut() {
+                Some((order, labels, members)) if *order == stage.order => {
+                    if !labels.contains(&stage.stage.as_str()) {
+                        labels.push(&stage.stage);
+                    }
+                    members.push(name);
+                }
+                _ => lanes.push((stage.order, vec![&stage.stage], vec![name])),
+            }
+        }
+
+        let mut names = Vec::new();
+        for (order, labels, members) in &lanes {
+            output.push_str(&format!(
+                "  {{ rank=same; \"lane_{}\" [label=\"{}. {}\", shape=plaintext];",
+                order,
+                order,
+                labels.join(" / ")
+            ));
+            for name in members {
+                output.push_str(&format!(" \"{}\";", name));
+                names.push(name.to_string());
+            }
+            output.push_str(" }\n");
+        }
+        if lanes.len() > 1 {
+            let headers: Vec<String> = lanes
+                .iter()
+                .map(|(order, _, _)| format!("\"lane_{}\"", order))
+                .collect();
+            output.push_str(&format!("  {} [style=invis];\n", headers.join(" -> ")));
+        }
+
+        output.push('\n');
+        for name in &names {
+            output.push_str(&self.dot_node(name, None));
+        }
+
+        output.push('\n');
+        output.push_str(&self.dot_edges(&names, None));
+        output.push_str("}\n");
+        output
+    }
+
+    /// DOT edge statements between the given skills, grouped by source in the
+    /// given order, styled by kind and weight
+    fn dot_edges(&self, names: &[String], highlight: Option<&str>) -> String {
+        let mut output = String::new();
+        let edges = names
+            .iter()
+            .flat_map(|name| self.graph.edges(self.name_to_node[name]));
+        for edge in edges {
+            let source = &self.graph[edge.source()];
+            let target = &self.graph[edge.target()];
+            let mut attrs = Vec::new();
+            if edge.weight().kind == EdgeKind::Pipeline {
+                attrs.push("style=dashed".to_string());
+                attrs.push("color=blue".to_string());
+            }
+            let mut penwidth = edge.weight().weight;
+            if highlight.is_some_and(|h| h == source || h == target) {
+                penwidth += HIGHLIGHT_PENWIDTH;
+            }
+            if penwidth > 1 {
+                attrs.push(format!("penwidth={}", penwidth));
+            }
+            let style = if attrs.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attrs.join(", "))
+            };
+            output.push_str(&format!("  \"{}\" -> \"{}\"{};\n", source, target, style));
+        }
+        output
+    }
+
+    /// DOT node statement with the fill color for the skill's role
+    ///
+    /// The highlighted skill gets a bold gold style instead.
+    fn dot_node(&self, name: &str, highlight: Option<&str>) -> String {
+        if highlight == Some(name) {
+            return format!(
+                "  \"{}\" [fillcolor=gold, style=\"rounded,filled,bold\", penwidth=3];\n",
+                name
+            );
+        }
+        let has_role = |role: &[String]| role.iter().any(|n| n == name);
+        let color = if has_role(&self.roots) {
+            "lightblue"
+        } else if has_role(&self.leaves) {
+            "lightgreen"
+        } else if has_role(&self.bridges) {
+            "orange"
+        } else {
+            "white"
+        };
+        format!(
+            "  \"{}\" [fillcolor={}, style=\"rounded,filled\"];\n",
+            name, color
+        )
+    }
+
+    /// Export graph as human-readable adjacency list
+    pub fn to_text(&self, order: NodeOrder) -> String {
+        let mut output = String::new();
+
+        output.push_str("# Skill Dependency Graph\n\n");
+
+        // Show analysis summary
+        output.push_str(&format!("Skills: {}\n", self.name_to_node.len()));
+        output.push_str(&format!("Clusters: {}\n", self.clusters.len()));
+        output.push_str(&format!("Roots: {}\n", self.roots.len()));
+        output.push_str(&format!("Leaves: {}\n", self.leaves.len()));
+        output.push_str(&format!("Bridges: {}\n\n", self.bridges.len()));
+
+        // Show adjacency list
+        output.push_str("## Dependencies\n\n");
+        for skill in self.ordered_names(order) {
+            let node = self.name_to_node[&skill];
+            let mut targets: Vec<String> = self
+                .graph
+                .edges(node)
+                .map(|e| self.graph[e.target()].clone())
+                .collect();
+            targets.sort();
+            targets.dedup();
+
+            if targets.is_empty() {
+                output.push_str(&format!("{}: (none)\n", skill));
+            } else {
+                output.push_str(&format!("{}: {}\n", skill, targets.join(", ")));
+            }
+        }
+
+        output
+    }
+
+    /// Logical export structure shared by the JSON, YAML, and TOML formats
+    fn export(&self, order: NodeOrder) -> GraphExport {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+
+        let cluster_of: HashMap<&str, usize> = self
+            .clusters
+            .iter()
+            .enumerate()
+            .flat_map(|(i, members)| members.iter().map(move |m| (m.as_str(), i)))
+            .collect();
+
+        for name in &self.ordered_names(order) {
+            let idx = self.name_to_node[name];
+            nodes.push(NodeExport {
+                id: name.clone(),
+                is_root: self.roots.contains(name),
+                is_leaf: self.leaves.contains(name),
+                is_bridge: self.bridges.contains(name),
+                out_degree: self
+                    .graph
+                    .edges_directed(idx, petgraph::Direction::Outgoing)
+                    .count(),
+                in_degree: self
+                    .graph
+                    .edges_directed(idx, petgraph::Direction::Incoming)
+                    .count(),
+                cluster: cluster_of.get(name.as_str()).copied(),
+                path: self.name_to_path.get(name).cloned(),
+            });
+
+            for edge in self.graph.edges(idx) {
+                edges.push(EdgeExport {
+                    source: name.clone(),
+                    target: self.graph[edge.target()].clone(),
+                    kind: edge.weight().kind.label().to_string(),
+                    weight: edge.weight().weight,
+                });
+            }
+        }
+
+        GraphExport {
+            nodes,
+            edges,
+            clusters: self.clusters.clone(),
+        }
+    }
+
+    /// Export graph as JSON
+    pub fn to_json(&self, order: NodeOrder) -> String {
+        serde_json::to_string(&self.export(order)).expect("graph export is always serializable")
+    }
+
+    /// Export graph as a standalone HTML page with an interactive layout
+    ///
+    /// Embeds the [`SkillGraph::to_json`] data and loads vis-network from a
+    /// CDN, so the file opens directly in a browser. Node colors follow the
+    /// DOT role colors; pipeline edges are dashed.
+    pub fn to_html(&self) -> String {
+        // Keep `</script>` inside names from closing the data script early
+        let data = self.to_json(NodeOrder::Alpha).replace("</", "<\\/");
+        include_str!("template.html").replace("__GRAPH_DATA__", &data)
+    }
+
+    /// Export graph as YAML, with the same structure as [`SkillGraph::to_json`]
+    pub fn to_yaml(&self, order: NodeOrder) -> String {
+        serde_yaml::to_string(&self.export(order)).expect("graph export is always serializable")
+    }
+
+    /// Export graph as TOML, with the same structure as [`SkillGraph::to_json`]
+    pub fn to_toml(&self, order: NodeOrder) -> String {
+        toml::to_string(&self.export(order)).expect("graph export is always serializable")
+    }
+
+    /// Export detected cycles, one per line, closing back to the first skill
+    pub fn to_cycles_text(&self) -> String {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return "No cycles detected\n".to_string();
+        }
+
+        let mut output = String::new();
+        for cycle in &cycles {
+            output.push_str(&format!("{} → {}\n", cycle.join(" → "), cycle[0]));
+        }
+        output
+    }
+
+    /// Export feedback edges as `remove: a → b (kind)` suggestions
+    pub fn to_break_cycles_text(&self) -> String {
+        let edges = self.feedback_edges();
+        if edges.is_empty() {
+            return "No cycles detected\n".to_string();
+        }
+
+        let mut output = String::new();
+        for (source, target, kind) in &edges {
+            output.push_str(&format!(
+                "remove: {} → {} ({})\n",
+                source,
+                target,
+                kind.label()
+            ));
+        }
+        output
+    }
+
+    /// Export mutual reference pairs as `a ⇄ b` lines
+    pub fn to_mutual_text(&self) -> String {
+        let pairs = self.mutual_pairs();
+        if pairs.is_empty() {
+            return "No mutual references detected\n".to_string();
+        }
+
+        let mut output = String::new();
+        for (a, b) in &pairs {
+            output.push_str(&format!("{} ⇄ {}\n", a, b));
+        }
+        output
+    }
+
+    /// Export diamonds as `apex ⇉ base`, one pair per line
+    pub fn to_diamonds_text(&self) -> String {
+        let pairs = self.diamonds();
+        if pairs.is_empty() {
+            return "No diamonds detected\n".to_string();
+        }
+
+        let mut output = String::new();
+        for (apex, base) in &pairs {
+            output.push_str(&format!("{} ⇉ {}\n", apex, base));
+        }
+        output
+    }
+
+    /// Export weakly connected components as numbered `N. a, b` lines
+    pub fn to_components_text(&self) -> String {
+        let components = self.components();
+        if components.is_empty() {
+            return "No skills in graph\n".to_string();
+        }
+
+        let mut output = String::new();
+        for (i, members) in components.iter().enumerate() {
+            output.push_str(&format!("{}. {}\n", i + 1, members.join(", ")));
+        }
+        output
+    }
+
+    /// Export betweenness centrality as aligned `skill  score` columns
+    pub fn to_centrality_text(&self) -> String {
+        let ranked = self.centrality();
+        let width = ranked.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for (name, score) in &ranked {
+            output.push_str(&format!("{:<width$}  {:.4}\n", name, score, width = width));
+        }
+        output
+    }
+
+    /// Export PageRank scores as aligned `skill  score` columns
+    pub fn to_pagerank_text(&self, damping: f64, iterations: usize) -> String {
+        let ranked = self.pagerank(damping, iterations);
+        let width = ranked.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for (name, score) in &ranked {
+            output.push_str(&format!("{:<width$}  {:.4}\n", name, score, width = width));
+        }
+        output
+    }
+
+    /// Export the top hubs as aligned `skill  degree` columns
+    pub fn to_hubs_text(&self, limit: usize) -> String {
+        let ranked = self.hubs(limit);
+        let width = ranked.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for (name, degree) in &ranked {
+            output.push_str(&format!("{:<width$}  {}\n", name, degree, width = width));
+        }
+        output
+    }
+
+    /// Export [`SkillGraph::stats`] as an aligned `label  value` report
+    pub fn to_stats_text(&self) -> String {
+        let stats = self.stats();
+        let with_owners = |(degree, owners): &(usize, Vec<String>)| {
+            if owners.is_empty() {
+                degree.to_string()
+            } else {
+                format!("{} ({})", degree, owners.join(", "))
+            }
+        };
+
+        let rows = [
+            ("Skills", stats.nodes.to_string()),
+            (
+                "Edges",
+                format!(
+                    "{} (crossref {}, pipeline {})",
+                    stats.edges(),
+                    stats.crossref_edges,
+                    stats.pipeline_edges
+                ),
+            ),
+            (
+                "Clusters",
+                format!("{} (largest {})", stats.clusters, stats.largest_cluster),
+            ),
+            ("Roots", stats.roots.to_string()),
+            ("Leaves", stats.leaves.to_string()),
+            ("Bridges", stats.bridges.to_string()),
+            ("Max in-degree", with_owners(&stats.max_in_degree)),
+            ("Max out-degree", with_owners(&stats.max_out_degree)),
+            ("Density", format!("{:.4}", stats.density)),
+        ];
+        let width = rows
+            .iter()
+            .map(|(label, _)| label.len() + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut output = String::new();
+        for (label, value) in &rows {
+            let label = format!("{}:", label);
+            output.push_str(&format!("{:<width$}  {}\n", label, value, width = width));
+        }
+        output
+    }
+
+    /// Export a Markdown dependency report, e.g. for a `DEPENDENCIES.md`
+    ///
+    /// A summary table of counts, then one section per skill listing its
+    /// outgoing and incoming references as links to the other sections, then
+    /// a cycles section when the graph has clusters.
+    pub fn to_markdown_report(&self) -> String {
+        let stats = self.stats();
+        let link = |name: &str| format!("[{}](#{})", name, markdown_anchor(name));
+        let bullets = |edges: Vec<(String, EdgeKind, Option<usize>)>| {
+            if edges.is_empty() {
+                return "_None_\n".to_string();
+            }
+            edges
+                .iter()
+                .map(|(name, kind, line)| match (kind, line) {
+                    (EdgeKind::Pipeline, _) => format!("- {} (pipeline)\n", link(name)),
+                    (_, Some(line)) => format!("- {} (line {})\n", link(name), line),
+                    (_, None) => format!("- {}\n", link(name)),
+                })
+                .collect()
+        };
+
+        let mut output = String::from("# Skill Dependencies\n\n## Summary\n\n");
+        output.push_str("| Metric | Count |\n|--------|-------|\n");
+        for (label, count) in [
+            ("Skills", stats.nodes),
+            ("Cross-reference edges", stats.crossref_edges),
+            ("Pipeline edges", stats.pipeline_edges),
+            ("Clusters", stats.clusters),
+            ("Roots", stats.roots),
+            ("Leaves", stats.leaves),
+            ("Bridges", stats.bridges),
+        ] {
+            output.push_str(&format!("| {} | {} |\n", label, count));
+        }
+
+        output.push_str("\n## Skills\n");
+        for name in self.node_names() {
+            output.push_str(&format!("\n### {}\n\n", name));
+            output.push_str("References:\n\n");
+            output.push_str(&bullets(self.edges_from(&name).unwrap_or_default()));
+            output.push_str("\nReferenced by:\n\n");
+            output.push_str(&bullets(self.edges_to(&name).unwrap_or_default()));
+        }
+
+        if !self.clusters.is_empty() {
+            output.push_str("\n## Cycles\n\n");
+            for (name, mut members) in self.named_clusters() {
+                members.sort();
+                let members: Vec<String> = members.iter().map(|m| link(m)).collect();
+                output.push_str(&format!("- **{}**: {}\n", name, members.join(", ")));
+            }
+        }
+
+        output
+    }
+
+    /// Export graph as a D2 diagram
+    ///
+    /// Node fills follow the DOT role colors; pipeline edges are dashed.
+    pub fn to_d2(&self) -> String {
+        let mut output = String::from("direction: right\n\n");
+
+        let mut sorted: Vec<_> = self.name_to_node.keys().collect();
+        sorted.sort();
+        for name in &sorted {
+            let fill = if self.roots.contains(*name) {
+                Some("lightblue")
+            } else if self.leaves.contains(*name) {
+                Some("lightgreen")
+            } else if self.bridges.contains(*name) {
+                Some("orange")
+            } else {
+                None
+            };
+            let style = fill
+                .map(|color| format!(" {{style.fill: \"{}\"}}", color))
+                .unwrap_or_default();
+            output.push_str(&format!(
+                "{}: \"{}\"{}\n",
+                sanitize_d2(name),
+                escape_d2_label(name),
+                style
+            ));
+        }
+
+        output.push('\n');
+
+        for edge in self.graph.edge_references() {
+            let source = &self.graph[edge.source()];
+            let target = &self.graph[edge.target()];
+            let style = match edge.weight().kind {
+                EdgeKind::CrossRef => "",
+                EdgeKind::Pipeline => ": {style.stroke-dash: 3}",
+            };
+            output.push_str(&format!(
+                "{} -> {}{}\n",
+                sanitize_d2(source),
+                sanitize_d2(target),
+                style
+            ));
+        }
+
+        output
+    }
+
+    /// Export graph as Mermaid diagram
+    ///
+    /// Every skill is declared first, so unconnected skills still appear, and
+    /// role classes reuse the DOT palette.
+    pub fn to_mermaid(&self) -> String {
+        self.to_mermaid_with(None)
+    }
+
+    /// Like [`SkillGraph::to_mermaid`], emphasizing one skill
+    ///
+    /// The highlighted skill gets a `highlight` class in place of its role
+    /// class, and its direct edges are drawn thicker via `linkStyle`.
+    pub fn to_mermaid_with(&self, highlight: Option<&str>) -> String {
+        let mut output = String::from("graph LR\n");
+
+        let mut sorted: Vec<_> = self.name_to_node.keys().collect();
+        sorted.sort();
+        for name in &sorted {
+            output.push_str(&format!("  {}[{}]\n", sanitize_mermaid(name), name));
+        }
+
+        let mut seen_edges: HashSet<(String, String)> = HashSet::new();
+        let mut highlighted_links = Vec::new();
+        for edge in self.graph.edge_references() {
+            let source = &self.graph[edge.source()];
+            let target = &self.graph[edge.target()];
+            let key = (source.clone(), target.clone());
+            if seen_edges.contains(&key) {
+                continue;
+            }
+            seen_edges.insert(key);
+            if highlight.is_some_and(|h| h == source || h == target) {
+                highlighted_links.push((seen_edges.len() - 1).to_string());
+            }
+
+            let arrow = match edge.weight().kind {
+                EdgeKind::CrossRef => "-->",
+                EdgeKind::Pipeline => "-.->",
+            };
+            output.push_str(&format!(
+                "  {} {} {}\n",
+                sanitize_mermaid(source),
+                arrow,
+                sanitize_mermaid(target)
+            ));
+        }
+
+        output.push_str("  classDef root fill:lightblue\n");
+        output.push_str("  classDef leaf fill:lightgreen\n");
+        output.push_str("  classDef bridge fill:orange\n");
+        if highlight.is_some() {
+            output.push_str("  classDef highlight fill:gold,stroke:#000,stroke-width:3px\n");
+        }
+
+        // Same precedence as DOT: a skill gets only its first matching role
+        let mut by_role: [(&str, Vec<String>); 3] =
+            [("root", vec![]), ("leaf", vec![]), ("bridge", vec![])];
+        for name in &sorted {
+            let role = if highlight == Some(name.as_str()) {
+                continue;
+            } else if self.roots.contains(*name) {
+                0
+            } else if self.leaves.contains(*name) {
+                1
+            } else if self.bridges.contains(*name) {
+                2
+            } else {
+                continue;
+            };
+            by_role[role].1.push(sanitize_mermaid(name));
+        }
+        for (class, members) in &by_role {
+            if !members.is_empty() {
+                output.push_str(&format!("  class {} {}\n", members.join(","), class));
+            }
+        }
+        if let Some(name) = highlight.filter(|h| self.name_to_node.contains_key(*h)) {
+            output.push_str(&format!("  class {} highlight\n", sanitize_mermaid(name)));
+        }
+        if !highlighted_links.is_empty() {
+            output.push_str(&format!(
+                "  linkStyle {} stroke-width:3px\n",
+                highlighted_links.join(",")
+            ));
+        }
+
+        output
+    }
+}
+
+/// Serialized graph shape: nodes with roles, typed edges, and clusters
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GraphExport {
+    nodes: Vec<NodeExport>,
+    edges: Vec<EdgeExport>,
+    clusters: Vec<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NodeExport {
+    id: String,
+    is_root: bool,
+    is_leaf: bool,
+    is_bridge: bool,
+    out_degree: usize,
+    in_degree: usize,
+    /// Index into `clusters`, or `None` for skills outside any cluster
+    cluster: Option<usize>,
+    /// SKILL.md path, when the graph was built from skills
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EdgeExport {
+    source: String,
+    target: String,
+    kind: String,
+    /// Number of references behind the edge
+    weight: usize,
+}
+
+fn sanitize_mermaid(s: &str) -> String {
+    s.replace('-', "_")
+}
+
+/// Reduce a skill name to a D2 identifier: anything but ASCII alphanumerics
+/// and `_` becomes `_`, so hyphens can never form `--`/`->` connections
+fn sanitize_d2(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Escape a skill name for use inside a double-quoted D2 label
+fn escape_d2_label(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// GitHub-style heading anchor: lowercase, punctuation other than `-`/`_` dropped
+fn markdown_anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Names of skills carrying a tag
+fn tagged_skills(skills: &[Skill], tag: &str) -> HashSet<String> {
+    skills
+        .iter()
+        .filter(|s| {
+            s.frontmatter
+                .tags
+                .as_ref()
+                .map(|t| t.contains(&tag.to_string()))
+                .unwrap_or(false)
+        })
+        .map(|s| s.name.clone())
+        .collect()
+}
+
+fn detect_clusters(
+    graph: &DiGraph<String, Edge>,
+    _name_to_node: &HashMap<String, NodeIndex>,
+) -> Vec<Vec<String>> {
+    // Use Tarjan's algorithm to find strongly connected components
+    let sccs = tarjan_scc(graph);
+
+    let mut clusters = Vec::new();
+    for scc in sccs {
+        let cluster: Vec<String> = scc.iter().map(|&idx| graph[idx].clone()).collect();
+
+        // Only include clusters with more than one skill
+        if cluster.len() > 1 {
+            clusters.push(cluster);
+        }
+    }
+
+    clusters
+}
+
+/// Depth-first search from `v` within `members`, collecting edges that lead
+/// back to a skill still on the search stack
+///
+/// Neighbors are visited alphabetically so the result is deterministic.
+fn collect_back_edges(
+    graph: &DiGraph<String, Edge>,
+    v: NodeIndex,
+    members: &HashSet<NodeIndex>,
+    on_stack: &mut HashMap<NodeIndex, bool>,
+    back_edges: &mut Vec<EdgeIndex>,
+) {
+    on_stack.insert(v, true);
+
+    let mut edges: Vec<_> = graph
+        .edges(v)
+        .filter(|e| members.contains(&e.target()))
+        .collect();
+    edges.sort_by(|a, b| graph[a.target()].cmp(&graph[b.target()]));
+
+    for edge in edges {
+        match on_stack.get(&edge.target()) {
+            Some(true) => back_edges.push(edge.id()),
+            Some(false) => {}
+            None => collect_back_edges(graph, edge.target(), members, on_stack, back_edges),
+        }
+    }
+
+    on_stack.insert(v, false);
+}
+
+fn find_roots(
+    graph: &DiGraph<String, Edge>,
+    name_to_node: &HashMap<String, NodeIndex>,
+) -> Vec<String> {
+    let mut roots = Vec::new();
+
+    for (name, &idx) in name_to_node {
+        // Root skills have no incoming edges
+        if graph
+            .edges_directed(idx, petgraph::Direction::Incoming)
+            .count()
+            == 0
+        {
+            roots.push(name.clone());
         }
     }
 
@@ -401,125 +1983,1250 @@ fn find_roots(
     roots
 }
 
-fn find_leaves(
-    graph: &DiGraph<String, EdgeKind>,
-    name_to_node: &HashMap<String, NodeIndex>,
-) -> Vec<String> {
-    let mut leaves = Vec::new();
+fn find_leaves(
+    graph: &DiGraph<String, Edge>,
+    name_to_node: &HashMap<String, NodeIndex>,
+) -> Vec<String> {
+    let mut leaves = Vec::new();
+
+    for (name, &idx) in name_to_node {
+        // Leaf skills have no outgoing edges
+        if graph
+            .edges_directed(idx, petgraph::Direction::Outgoing)
+            .count()
+            == 0
+        {
+            leaves.push(name.clone());
+        }
+    }
+
+    leaves.sort();
+    leaves
+}
+
+fn find_bridges(
+    graph: &DiGraph<String, Edge>,
+    name_to_node: &HashMap<String, NodeIndex>,
+) -> Vec<String> {
+    // Articulation points of the undirected projection: a node is a bridge if
+    // removing it increases the number of weakly connected components
+    let neighbors: Vec<Vec<usize>> = graph
+        .node_indices()
+        .map(|idx| {
+            let mut adjacent: Vec<usize> = graph
+                .neighbors_undirected(idx)
+                .map(|n| n.index())
+                .filter(|&n| n != idx.index())
+                .collect();
+            adjacent.sort_unstable();
+            adjacent.dedup();
+            adjacent
+        })
+        .collect();
+
+    let mut search = ArticulationSearch {
+        neighbors: &neighbors,
+        discovered: vec![None; neighbors.len()],
+        low: vec![0; neighbors.len()],
+        is_articulation: vec![false; neighbors.len()],
+        time: 0,
+    };
+
+    for start in 0..neighbors.len() {
+        if search.discovered[start].is_none() {
+            search.visit(start, None);
+        }
+    }
+
+    let mut bridges: Vec<String> = name_to_node
+        .iter()
+        .filter(|(_, idx)| search.is_articulation[idx.index()])
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    bridges.sort();
+    bridges
+}
+
+/// Depth-first search state for Tarjan's articulation point algorithm
+struct ArticulationSearch<'a> {
+    neighbors: &'a [Vec<usize>],
+    discovered: Vec<Option<usize>>,
+    low: Vec<usize>,
+    is_articulation: Vec<bool>,
+    time: usize,
+}
+
+impl ArticulationSearch<'_> {
+    fn visit(&mut self, node: usize, parent: Option<usize>) {
+        self.discovered[node] = Some(self.time);
+        self.low[node] = self.time;
+        self.time += 1;
+
+        let mut children = 0;
+        for &next in &self.neighbors[node] {
+            if Some(next) == parent {
+                continue;
+            }
+            match self.discovered[next] {
+                Some(next_time) => {
+                    // Back edge to an ancestor
+                    self.low[node] = self.low[node].min(next_time);
+                }
+                None => {
+                    children += 1;
+                    self.visit(next, Some(node));
+                    self.low[node] = self.low[node].min(self.low[next]);
+
+                    // A non-root node is an articulation point when some child
+                    // subtree has no back edge reaching above this node
+                    let node_time = self.discovered[node].unwrap_or_default();
+                    if parent.is_some() && self.low[next] >= node_time {
+                        self.is_articulation[node] = true;
+                    }
+                }
+            }
+        }
+
+        // The DFS root is an articulation point when it has multiple subtrees
+        if parent.is_none() && children > 1 {
+            self.is_articulation[node] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::{CrossRef, DetectionMethod};
+
+    fn test_crossref(target: &str) -> CrossRef {
+        CrossRef {
+            target: target.to_string(),
+            line: 1,
+            method: DetectionMethod::XmlCrossref,
+            self_reference: false,
+        }
+    }
+
+    // Helper to create a skill with minimal frontmatter
+    fn test_skill(name: &str) -> Skill {
+        let content = format!("---\nname: {}\ndescription: Test\n---\n", name);
+        Skill {
+            name: name.to_string(),
+            path: std::path::PathBuf::from(format!("/test/{}", name)),
+            skill_file: std::path::PathBuf::from(format!("/test/{}/SKILL.md", name)),
+            frontmatter: crate::skill::Frontmatter::parse(&content).unwrap(),
+            content: None,
+        }
+    }
+
+    #[test]
+    fn should_build_graph_from_crossrefs() {
+        // Given: skill-a → skill-b → skill-c
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.name_to_node.len(), 3);
+    }
+
+    #[test]
+    fn should_merge_name_variants_into_one_node() {
+        // Given: draft spells build-tool two ways, review a third
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "draft".to_string(),
+            vec![test_crossref("Build-Tool"), test_crossref("build_tool")],
+        );
+        crossrefs.insert("review".to_string(), vec![test_crossref("BUILD tool")]);
+        let skills = vec![test_skill("build-tool")];
+
+        // When
+        let graph = SkillGraph::from_skills(&crossrefs, &skills);
+
+        // Then
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(
+            graph.edges_to("build-tool"),
+            Some(vec![
+                ("draft".to_string(), EdgeKind::CrossRef, Some(1)),
+                ("review".to_string(), EdgeKind::CrossRef, Some(1)),
+            ])
+        );
+    }
+
+    #[test]
+    fn should_identify_root_skills() {
+        // Given: skill-a → skill-b (skill-a is root)
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.roots.len(), 1);
+        assert!(graph.roots.contains(&"skill-a".to_string()));
+    }
+
+    #[test]
+    fn should_identify_leaf_skills() {
+        // Given: skill-a → skill-b (skill-b is leaf)
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.leaves.len(), 1);
+        assert!(graph.leaves.contains(&"skill-b".to_string()));
+    }
+
+    #[test]
+    fn should_detect_clusters() {
+        // Given: skill-a ↔ skill-b (circular reference, forms a cluster)
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-a")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.clusters.len(), 1);
+        assert_eq!(graph.clusters[0].len(), 2);
+    }
+
+    #[test]
+    fn should_identify_middle_of_chain_as_bridge() {
+        // Given: skill-a → skill-b → skill-c
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.bridges, vec!["skill-b".to_string()]);
+    }
+
+    #[test]
+    fn should_identify_only_center_of_star_as_bridge() {
+        // Given: hub → spoke-a, hub → spoke-b, spoke-c → hub
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "hub".to_string(),
+            vec![test_crossref("spoke-a"), test_crossref("spoke-b")],
+        );
+        crossrefs.insert("spoke-c".to_string(), vec![test_crossref("hub")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.bridges, vec!["hub".to_string()]);
+    }
+
+    #[test]
+    fn should_identify_hub_that_disconnects_two_clusters() {
+        // Given: two triangles joined only through hub, each attached by two edges
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "left-a".to_string(),
+            vec![test_crossref("left-b"), test_crossref("hub")],
+        );
+        crossrefs.insert(
+            "left-b".to_string(),
+            vec![test_crossref("left-c"), test_crossref("hub")],
+        );
+        crossrefs.insert("left-c".to_string(), vec![test_crossref("left-a")]);
+        crossrefs.insert(
+            "hub".to_string(),
+            vec![test_crossref("right-a"), test_crossref("right-b")],
+        );
+        crossrefs.insert("right-a".to_string(), vec![test_crossref("right-b")]);
+        crossrefs.insert("right-b".to_string(), vec![test_crossref("right-c")]);
+        crossrefs.insert("right-c".to_string(), vec![test_crossref("right-a")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.bridges, vec!["hub".to_string()]);
+    }
+
+    #[test]
+    fn should_order_dependencies_before_dependents() {
+        // Given: skill-a → skill-b → skill-c
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+
+        // When
+        let order = SkillGraph::from_crossrefs(&crossrefs)
+            .topological_order()
+            .unwrap();
+
+        // Then
+        assert_eq!(order, vec!["skill-c", "skill-b", "skill-a"]);
+    }
+
+    #[test]
+    fn should_return_cycle_members_when_ordering_is_impossible() {
+        // Given: skill-a ↔ skill-b, plus an unrelated skill-c
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert(
+            "skill-b".to_string(),
+            vec![test_crossref("skill-a"), test_crossref("skill-c")],
+        );
+
+        // When
+        let result = SkillGraph::from_crossrefs(&crossrefs).topological_order();
+
+        // Then
+        assert_eq!(
+            result.unwrap_err(),
+            vec![vec!["skill-a".to_string(), "skill-b".to_string()]]
+        );
+    }
+
+    #[test]
+    fn should_find_transitive_dependents_as_impact() {
+        // Given: skill-a → skill-b → skill-c, skill-d → skill-c, skill-c → skill-e
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-d".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-e")]);
+
+        // When
+        let impact = SkillGraph::from_crossrefs(&crossrefs).impact_of("skill-c");
+
+        // Then
+        assert_eq!(impact, vec!["skill-a", "skill-b", "skill-d"]);
+    }
+
+    #[test]
+    fn should_turn_predecessor_into_leaf_when_excluding_tag() {
+        // Given: skill-a → skill-b → skill-c, with skill-b deprecated
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let mut deprecated = test_skill("skill-b");
+        deprecated.frontmatter.tags = Some(vec!["deprecated".to_string()]);
+        let skills = vec![test_skill("skill-a"), deprecated, test_skill("skill-c")];
+
+        // When
+        let filtered = graph.exclude_tag(&skills, "deprecated");
+
+        // Then
+        assert_eq!(filtered.node_names(), vec!["skill-a", "skill-c"]);
+        assert_eq!(filtered.graph.edge_count(), 0);
+        assert!(filtered.leaves.contains(&"skill-a".to_string()));
+        assert!(!graph.leaves.contains(&"skill-a".to_string()));
+    }
+
+    #[test]
+    fn should_drop_deprecated_skills_and_their_edges() {
+        // Given: skill-a → skill-b → skill-c, with skill-b deprecated in frontmatter
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let mut deprecated = test_skill("skill-b");
+        deprecated.frontmatter.deprecated = Some(Default::default());
+        let skills = vec![test_skill("skill-a"), deprecated, test_skill("skill-c")];
+
+        // When
+        let filtered = graph.exclude_deprecated(&skills);
+
+        // Then
+        assert_eq!(filtered.node_names(), vec!["skill-a", "skill-c"]);
+        assert_eq!(filtered.graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn should_terminate_impact_analysis_on_cycles() {
+        // Given: skill-a → skill-b → skill-c → skill-a
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-a")]);
+
+        // When
+        let impact = SkillGraph::from_crossrefs(&crossrefs).impact_of("skill-a");
+
+        // Then
+        assert_eq!(impact, vec!["skill-b", "skill-c"]);
+    }
+
+    #[test]
+    fn should_return_no_impact_for_unknown_skill() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+
+        // When
+        let impact = SkillGraph::from_crossrefs(&crossrefs).impact_of("nonexistent");
+
+        // Then
+        assert!(impact.is_empty());
+    }
+
+    #[test]
+    fn should_report_added_and_removed_nodes_in_diff() {
+        // Given
+        let mut before = HashMap::new();
+        before.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        let mut after = HashMap::new();
+        after.insert("skill-a".to_string(), vec![test_crossref("skill-c")]);
+
+        // When
+        let diff = SkillGraph::from_crossrefs(&before).diff(&SkillGraph::from_crossrefs(&after));
+
+        // Then
+        assert_eq!(diff.added_nodes, vec!["skill-c"]);
+        assert_eq!(diff.removed_nodes, vec!["skill-b"]);
+        assert_eq!(
+            diff.added_edges,
+            vec![(
+                "skill-a".to_string(),
+                "skill-c".to_string(),
+                EdgeKind::CrossRef
+            )]
+        );
+        assert_eq!(
+            diff.removed_edges,
+            vec![(
+                "skill-a".to_string(),
+                "skill-b".to_string(),
+                EdgeKind::CrossRef
+            )]
+        );
+    }
+
+    #[test]
+    fn should_report_no_differences_for_identical_graphs() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+
+        // When
+        let diff =
+            SkillGraph::from_crossrefs(&crossrefs).diff(&SkillGraph::from_crossrefs(&crossrefs));
+
+        // Then
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn should_prefix_diff_lines_by_change_direction() {
+        // Given
+        let diff = GraphDiff {
+            added_nodes: vec!["skill-new".to_string()],
+            removed_nodes: vec!["skill-old".to_string()],
+            added_edges: vec![(
+                "skill-a".to_string(),
+                "skill-b".to_string(),
+                EdgeKind::Pipeline,
+            )],
+            removed_edges: vec![],
+        };
+
+        // When
+        let text = diff.to_text();
+
+        // Then
+        assert!(text.contains("+ skill-new"));
+        assert!(text.contains("- skill-old"));
+        assert!(text.contains("+ skill-a -> skill-b (pipeline)"));
+    }
+
+    #[test]
+    fn should_rank_star_center_highest_by_centrality() {
+        // Given: three spokes route through hub to three more spokes
+        let mut crossrefs = HashMap::new();
+        for spoke in ["in-a", "in-b", "in-c"] {
+            crossrefs.insert(spoke.to_string(), vec![test_crossref("hub")]);
+        }
+        crossrefs.insert(
+            "hub".to_string(),
+            vec![
+                test_crossref("out-a"),
+                test_crossref("out-b"),
+                test_crossref("out-c"),
+            ],
+        );
+
+        // When
+        let ranked = SkillGraph::from_crossrefs(&crossrefs).centrality();
+
+        // Then
+        assert_eq!(ranked[0].0, "hub");
+        assert!(ranked[0].1 > 0.0 && ranked[0].1 <= 1.0);
+        assert!(ranked[1..].iter().all(|(_, score)| *score == 0.0));
+    }
+
+    #[test]
+    fn should_rank_skills_referenced_by_important_skills_higher_by_pagerank() {
+        // Given: three leaves → skill-core → skill-base, plus skill-x → skill-y
+        let mut crossrefs = HashMap::new();
+        for leaf in ["leaf-a", "leaf-b", "leaf-c"] {
+            crossrefs.insert(leaf.to_string(), vec![test_crossref("skill-core")]);
+        }
+        crossrefs.insert("skill-core".to_string(), vec![test_crossref("skill-base")]);
+        crossrefs.insert("skill-x".to_string(), vec![test_crossref("skill-y")]);
+
+        // When
+        let ranked = SkillGraph::from_crossrefs(&crossrefs).pagerank(0.85, 50);
+
+        // Then
+        let names: Vec<&str> = ranked.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(&names[..3], &["skill-base", "skill-core", "skill-y"]);
+        assert_eq!(&names[3..], &["leaf-a", "leaf-b", "leaf-c", "skill-x"]);
+        let total: f64 = ranked.iter().map(|(_, score)| score).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_rank_chain_middle_above_endpoints_by_centrality() {
+        // Given: skill-a → skill-b → skill-c → skill-d
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-d")]);
+
+        // When
+        let scores: HashMap<String, f64> = SkillGraph::from_crossrefs(&crossrefs)
+            .centrality()
+            .into_iter()
+            .collect();
+
+        // Then
+        assert!(scores["skill-b"] > scores["skill-a"]);
+        assert!(scores["skill-c"] > scores["skill-d"]);
+        assert_eq!(scores["skill-a"], 0.0);
+        assert_eq!(scores["skill-d"], 0.0);
+    }
+
+    #[test]
+    fn should_report_concrete_cycle_path() {
+        // Given: skill-a → skill-b → skill-c → skill-a, plus skill-c → skill-d
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert(
+            "skill-c".to_string(),
+            vec![test_crossref("skill-a"), test_crossref("skill-d")],
+        );
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.cycles(), vec![vec!["skill-a", "skill-b", "skill-c"]]);
+        assert_eq!(
+            graph.to_cycles_text(),
+            "skill-a → skill-b → skill-c → skill-a\n"
+        );
+    }
+
+    #[test]
+    fn should_suggest_one_edge_to_break_three_node_cycle() {
+        // Given: skill-a → skill-b → skill-c → skill-a
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-a")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(
+            graph.feedback_edges(),
+            vec![(
+                "skill-c".to_string(),
+                "skill-a".to_string(),
+                EdgeKind::CrossRef
+            )]
+        );
+        assert_eq!(
+            graph.to_break_cycles_text(),
+            "remove: skill-c → skill-a (crossref)\n"
+        );
+    }
+
+    #[test]
+    fn should_break_every_cycle_including_self_references() {
+        // Given: skill-a ⇄ skill-b, skill-b ⇄ skill-c, and skill-d → skill-d
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert(
+            "skill-b".to_string(),
+            vec![test_crossref("skill-a"), test_crossref("skill-c")],
+        );
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-d".to_string(), vec![test_crossref("skill-d")]);
+
+        // When
+        let edges = SkillGraph::from_crossrefs(&crossrefs).feedback_edges();
+
+        // Then
+        let remaining: HashMap<String, Vec<CrossRef>> = crossrefs
+            .into_iter()
+            .map(|(source, refs)| {
+                let kept = refs
+                    .into_iter()
+                    .filter(|r| !edges.iter().any(|(s, t, _)| *s == source && *t == r.target))
+                    .collect();
+                (source, kept)
+            })
+            .collect();
+        assert_eq!(edges.len(), 3);
+        assert!(SkillGraph::from_crossrefs(&remaining).cycles().is_empty());
+    }
+
+    #[test]
+    fn should_report_no_cycles_to_break_in_acyclic_graph() {
+        assert_eq!(chain_graph().to_break_cycles_text(), "No cycles detected\n");
+    }
+
+    #[test]
+    fn should_report_self_reference_as_single_element_cycle() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-a")]);
+
+        // When
+        let cycles = SkillGraph::from_crossrefs(&crossrefs).cycles();
+
+        // Then
+        assert_eq!(cycles, vec![vec!["skill-a"]]);
+    }
+
+    #[test]
+    fn should_limit_neighborhood_to_depth_in_both_directions() {
+        // Given: skill-a → skill-b → skill-c → skill-d, plus skill-e → skill-b
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-d")]);
+        crossrefs.insert("skill-e".to_string(), vec![test_crossref("skill-b")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let around = graph.neighborhood("skill-b", 1);
+
+        // Then: skill-d is two hops away; roots/leaves reflect the subgraph
+        assert_eq!(around.graph.node_count(), 4);
+        assert!(!around.name_to_node.contains_key("skill-d"));
+        assert_eq!(around.roots, vec!["skill-a", "skill-e"]);
+        assert_eq!(around.leaves, vec!["skill-c"]);
+        assert_eq!(around.bridges, vec!["skill-b"]);
+    }
+
+    #[test]
+    fn should_return_only_center_when_depth_is_zero() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let around = graph.neighborhood("skill-a", 0);
+
+        // Then
+        assert_eq!(around.graph.node_count(), 1);
+        assert!(around.name_to_node.contains_key("skill-a"));
+        assert_eq!(around.graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn should_find_unconnected_skills() {
+        // Given: skill-a → skill-b, skill-c stands alone
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-c".to_string(), vec![]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(graph.unconnected(), vec!["skill-c"]);
+    }
+
+    #[test]
+    fn should_report_edges_with_reference_lines() {
+        // Given: skill-a references skill-b on line 12
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-a".to_string(),
+            vec![CrossRef {
+                target: "skill-b".to_string(),
+                line: 12,
+                method: DetectionMethod::XmlCrossref,
+                self_reference: false,
+            }],
+        );
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert_eq!(
+            graph.edges_from("skill-a"),
+            Some(vec![("skill-b".to_string(), EdgeKind::CrossRef, Some(12))])
+        );
+        assert_eq!(
+            graph.edges_to("skill-b"),
+            Some(vec![("skill-a".to_string(), EdgeKind::CrossRef, Some(12))])
+        );
+        assert_eq!(graph.edges_from("skill-b"), Some(vec![]));
+        assert_eq!(graph.edges_to("skill-a"), Some(vec![]));
+    }
+
+    #[test]
+    fn should_compute_stats_for_small_graph() {
+        // Given: a -> b, a -> c, b -> c, c -> b, d isolated, plus pipeline d -> a
+        use crate::skill::frontmatter::PipelineStage;
+
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "a".to_string(),
+            vec![test_crossref("b"), test_crossref("c")],
+        );
+        crossrefs.insert("b".to_string(), vec![test_crossref("c")]);
+        crossrefs.insert("c".to_string(), vec![test_crossref("b")]);
+        let content = "---\nname: d\ndescription: Test\n---\n";
+        let mut frontmatter = crate::skill::Frontmatter::parse(content).unwrap();
+        let mut stages = HashMap::new();
+        stages.insert(
+            "release".to_string(),
+            PipelineStage {
+                stage: "last".to_string(),
+                order: 2,
+                after: Some(vec!["a".to_string()]),
+                before: None,
+            },
+        );
+        frontmatter.pipeline = Some(stages);
+        let skills = vec![Skill {
+            name: "d".to_string(),
+            path: std::path::PathBuf::from("/test/d"),
+            skill_file: std::path::PathBuf::from("/test/d/SKILL.md"),
+            frontmatter,
+            content: None,
+        }];
+        let graph = SkillGraph::from_skills(&crossrefs, &skills);
+
+        // When
+        let stats = graph.stats();
+
+        // Then
+        assert_eq!(stats.nodes, 4);
+        assert_eq!(stats.crossref_edges, 4);
+        assert_eq!(stats.pipeline_edges, 1);
+        assert_eq!(stats.edges(), 5);
+        assert_eq!(stats.clusters, 1);
+        assert_eq!(stats.largest_cluster, 2);
+        assert_eq!(stats.roots, 1);
+        assert_eq!(stats.leaves, 0);
+        assert_eq!(
+            stats.max_in_degree,
+            (2, vec!["b".to_string(), "c".to_string()])
+        );
+        assert_eq!(stats.max_out_degree, (2, vec!["a".to_string()]));
+        assert!((stats.density - 5.0 / 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_render_aligned_stats_report() {
+        // Given
+        let graph = SkillGraph::from_crossrefs(&HashMap::new());
+
+        // When
+        let text = graph.to_stats_text();
+
+        // Then
+        assert!(text.starts_with("Skills:          0\n"));
+        assert!(text.contains("Max in-degree:   0\n"));
+        assert!(text.ends_with("Density:         0.0000\n"));
+    }
+
+    #[test]
+    fn should_name_cluster_after_highest_degree_member() {
+        // Given: auth-core <-> session, auth-core <-> token, plus an outside caller
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "auth-core".to_string(),
+            vec![test_crossref("session"), test_crossref("token")],
+        );
+        crossrefs.insert("session".to_string(), vec![test_crossref("auth-core")]);
+        crossrefs.insert("token".to_string(), vec![test_crossref("auth-core")]);
+        crossrefs.insert("login".to_string(), vec![test_crossref("session")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let named = graph.named_clusters();
+
+        // Then: auth-core has degree 4, session 3, token 2
+        assert_eq!(named.len(), 1);
+        assert_eq!(named[0].0, "auth-core");
+        assert_eq!(named[0].1.len(), 3);
+    }
+
+    #[test]
+    fn should_name_cluster_alphabetically_on_degree_tie() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("zeta".to_string(), vec![test_crossref("beta")]);
+        crossrefs.insert("beta".to_string(), vec![test_crossref("zeta")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let named = graph.named_clusters();
+
+        // Then
+        assert_eq!(named[0].0, "beta");
+    }
+
+    #[test]
+    fn should_keep_only_skills_reachable_from_roots() {
+        // Given: chain a -> b -> c, island cycle x <-> y, unconnected z
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("a".to_string(), vec![test_crossref("b")]);
+        crossrefs.insert("b".to_string(), vec![test_crossref("c")]);
+        crossrefs.insert("x".to_string(), vec![test_crossref("y")]);
+        crossrefs.insert("y".to_string(), vec![test_crossref("x")]);
+        let skills: Vec<Skill> = ["a", "b", "c", "x", "y", "z"]
+            .into_iter()
+            .map(test_skill)
+            .collect();
+        let graph = SkillGraph::from_skills(&crossrefs, &skills);
+
+        // When
+        let pruned = graph.reachable_from_roots();
+
+        // Then
+        assert_eq!(pruned.node_names(), vec!["a", "b", "c"]);
+        assert_eq!(pruned.edge_count(), 2);
+        assert_eq!(
+            graph.reachable_from(&["b".to_string()]).node_names(),
+            vec!["b", "c"]
+        );
+    }
+
+    #[test]
+    fn should_pin_roots_and_leaves_to_rank_groups_when_requested() {
+        // Given
+        let graph = chain_graph();
+        let options = DotOptions {
+            rankdir: RankDir::TopBottom,
+            rank_roots: true,
+            ..Default::default()
+        };
+
+        // When
+        let dot = graph.to_dot_with(NodeOrder::Alpha, &options);
+
+        // Then
+        assert!(dot.contains("  rankdir=TB;\n"));
+        assert!(dot.contains("  { rank=source; \"a-first\"; }\n"));
+        assert!(dot.contains("  { rank=sink; \"c-last\"; }\n"));
+        assert_eq!(
+            graph.to_dot_with(NodeOrder::Alpha, &DotOptions::default()),
+            graph.to_dot(NodeOrder::Alpha)
+        );
+        assert!(!graph.to_dot(NodeOrder::Alpha).contains("rank="));
+    }
+
+    #[test]
+    fn should_highlight_one_skill_and_its_edges_in_dot() {
+        // Given
+        let graph = chain_graph();
+        let options = DotOptions {
+            highlight: Some("c-last"),
+            ..Default::default()
+        };
+
+        // When
+        let dot = graph.to_dot_with(NodeOrder::Alpha, &options);
+
+        // Then
+        assert!(dot.contains(
+            "  \"c-last\" [fillcolor=gold, style=\"rounded,filled,bold\", penwidth=3];\n"
+        ));
+        assert!(dot.contains("  \"a-first\" [fillcolor=lightblue, style=\"rounded,filled\"];\n"));
+        assert!(dot.contains("  \"b-middle\" -> \"c-last\" [penwidth=3];\n"));
+        assert!(dot.contains("  \"a-first\" -> \"b-middle\";\n"));
+    }
+
+    #[test]
+    fn should_highlight_one_skill_in_mermaid() {
+        // Given
+        let graph = chain_graph();
+
+        // When
+        let mermaid = graph.to_mermaid_with(Some("a-first"));
+
+        // Then
+        assert!(mermaid.contains("  class a_first highlight\n"));
+        assert!(mermaid.contains("  class c_last leaf\n"));
+        assert!(!mermaid.contains("class a_first root"));
+        assert!(mermaid.contains("  linkStyle 0 stroke-width:3px\n"));
+        assert!(!graph.to_mermaid().contains("highlight"));
+    }
+
+    #[test]
+    fn should_include_skill_paths_in_json_when_built_from_skills() {
+        // Given: a defined skill referencing a dangling one
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("missing")]);
+        let graph = SkillGraph::from_skills(&crossrefs, &[test_skill("skill-a")]);
+
+        // When
+        let json = graph
+            .filter_edges(&[EdgeKind::CrossRef])
+            .to_json(NodeOrder::Alpha);
+
+        // Then
+        let export: GraphExport = serde_json::from_str(&json).unwrap();
+        let path_of = |id: &str| {
+            let node = export.nodes.iter().find(|n| n.id == id).unwrap();
+            node.path.clone()
+        };
+        assert_eq!(
+            path_of("skill-a"),
+            Some(std::path::PathBuf::from("/test/skill-a/SKILL.md"))
+        );
+        assert_eq!(path_of("missing"), None);
+        assert!(json.contains("\"path\":\"/test/skill-a/SKILL.md\""));
+        assert_eq!(
+            graph.skill_path("skill-a"),
+            Some(Path::new("/test/skill-a/SKILL.md"))
+        );
+        assert!(SkillGraph::from_crossrefs(&crossrefs)
+            .skill_path("skill-a")
+            .is_none());
+    }
+
+    #[test]
+    fn should_export_standalone_html_with_graph_data() {
+        // Given
+        let graph = chain_graph();
+
+        // When
+        let html = graph.to_html();
+
+        // Then
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<script>"));
+        assert!(html.contains(&graph.to_json(NodeOrder::Alpha)));
+        for name in graph.node_names() {
+            assert!(html.contains(&format!("\"id\":\"{}\"", name)));
+        }
+        assert!(!html.contains("__GRAPH_DATA__"));
+    }
+
+    // Helper to build the diamond a -> b, a -> c, b -> d, c -> d
+    fn diamond_graph() -> SkillGraph {
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "a".to_string(),
+            vec![test_crossref("b"), test_crossref("c")],
+        );
+        crossrefs.insert("b".to_string(), vec![test_crossref("d")]);
+        crossrefs.insert("c".to_string(), vec![test_crossref("d")]);
+        SkillGraph::from_crossrefs(&crossrefs)
+    }
+
+    #[test]
+    fn should_find_canonical_diamond() {
+        // Given
+        let graph = diamond_graph();
+
+        // When
+        let diamonds = graph.diamonds();
+
+        // Then
+        assert_eq!(diamonds, vec![("a".to_string(), "d".to_string())]);
+        assert_eq!(graph.to_diamonds_text(), "a ⇉ d\n");
+    }
+
+    #[test]
+    fn should_not_report_diamonds_in_chain_or_cycle() {
+        // Given: chain plus a two-skill cycle
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("a".to_string(), vec![test_crossref("b")]);
+        crossrefs.insert("b".to_string(), vec![test_crossref("c")]);
+        crossrefs.insert("x".to_string(), vec![test_crossref("y")]);
+        crossrefs.insert("y".to_string(), vec![test_crossref("x")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
 
-    for (name, &idx) in name_to_node {
-        // Leaf skills have no outgoing edges
-        if graph
-            .edges_directed(idx, petgraph::Direction::Outgoing)
-            .count()
-            == 0
-        {
-            leaves.push(name.clone());
-        }
+        // When/Then
+        assert!(graph.diamonds().is_empty());
+        assert_eq!(graph.to_diamonds_text(), "No diamonds detected\n");
     }
 
-    leaves.sort();
-    leaves
-}
+    #[test]
+    fn should_stop_enumerating_paths_at_limit() {
+        // Given
+        let graph = diamond_graph();
 
-fn find_bridges(
-    graph: &DiGraph<String, EdgeKind>,
-    name_to_node: &HashMap<String, NodeIndex>,
-) -> Vec<String> {
-    // Articulation points - nodes whose removal would increase connected components
-    // For directed graphs, this is approximate - we look for nodes that are the only path
-    // between different parts of the graph
+        // When: a -> b -> d uses the whole budget of 2 paths from a
+        let diamonds = graph.diamonds_bounded(2);
 
-    let mut bridges = Vec::new();
+        // Then
+        assert!(diamonds.is_empty());
+    }
 
-    // Simple heuristic: a node is a bridge if it has both incoming and outgoing edges
-    // and removing it would disconnect some nodes
-    for (name, &idx) in name_to_node {
-        let incoming = graph
-            .edges_directed(idx, petgraph::Direction::Incoming)
-            .count();
-        let outgoing = graph
-            .edges_directed(idx, petgraph::Direction::Outgoing)
-            .count();
+    // Helper to build the chain a-first -> b-middle -> c-last
+    fn chain_graph() -> SkillGraph {
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("a-first".to_string(), vec![test_crossref("b-middle")]);
+        crossrefs.insert("b-middle".to_string(), vec![test_crossref("c-last")]);
+        SkillGraph::from_crossrefs(&crossrefs)
+    }
 
-        // Bridge candidates have both incoming and outgoing edges
-        if incoming > 0 && outgoing > 0 {
-            bridges.push(name.clone());
-        }
+    #[test]
+    fn should_list_nodes_alphabetically_by_default() {
+        // Given
+        let graph = chain_graph();
+
+        // When
+        let text = graph.to_text(NodeOrder::default());
+        let export: GraphExport = serde_json::from_str(&graph.to_json(NodeOrder::Alpha)).unwrap();
+
+        // Then
+        assert!(text.contains("a-first: b-middle\nb-middle: c-last\nc-last: (none)\n"));
+        let ids: Vec<&str> = export.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a-first", "b-middle", "c-last"]);
     }
 
-    bridges.sort();
-    bridges
-}
+    #[test]
+    fn should_list_nodes_in_topological_order() {
+        // Given
+        let graph = chain_graph();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::skill::{CrossRef, DetectionMethod};
+        // When
+        let text = graph.to_text(NodeOrder::Topo);
+        let export: GraphExport = serde_json::from_str(&graph.to_json(NodeOrder::Topo)).unwrap();
+        let dot = graph.to_dot(NodeOrder::Topo);
 
-    fn test_crossref(target: &str) -> CrossRef {
-        CrossRef {
-            target: target.to_string(),
-            line: 1,
-            method: DetectionMethod::XmlCrossref,
-        }
+        // Then
+        assert!(text.contains("c-last: (none)\nb-middle: c-last\na-first: b-middle\n"));
+        let ids: Vec<&str> = export.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["c-last", "b-middle", "a-first"]);
+        let first = dot.find("\"b-middle\" -> \"c-last\"").unwrap();
+        let second = dot.find("\"a-first\" -> \"b-middle\"").unwrap();
+        assert!(first < second);
     }
 
     #[test]
-    fn should_build_graph_from_crossrefs() {
-        // Given: skill-a → skill-b → skill-c
+    fn should_fall_back_to_alpha_order_when_cyclic() {
+        // Given: b-skill ⇄ a-skill
         let mut crossrefs = HashMap::new();
-        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
-        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("b-skill".to_string(), vec![test_crossref("a-skill")]);
+        crossrefs.insert("a-skill".to_string(), vec![test_crossref("b-skill")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
 
         // When
-        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let export: GraphExport = serde_json::from_str(&graph.to_json(NodeOrder::Topo)).unwrap();
 
         // Then
-        assert_eq!(graph.name_to_node.len(), 3);
+        let ids: Vec<&str> = export.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a-skill", "b-skill"]);
     }
 
     #[test]
-    fn should_identify_root_skills() {
-        // Given: skill-a → skill-b (skill-a is root)
+    fn should_return_none_for_edges_of_unknown_skill() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When/Then
+        assert!(graph.edges_from("unknown").is_none());
+        assert!(graph.edges_to("unknown").is_none());
+    }
+
+    #[test]
+    fn should_report_sorted_node_names_and_counts() {
+        // Given: c -> a, c -> b, a -> b, plus isolated d
         let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-c".to_string(),
+            vec![test_crossref("skill-a"), test_crossref("skill-b")],
+        );
         crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-d".to_string(), vec![]);
 
         // When
         let graph = SkillGraph::from_crossrefs(&crossrefs);
 
         // Then
-        assert_eq!(graph.roots.len(), 1);
-        assert!(graph.roots.contains(&"skill-a".to_string()));
+        assert_eq!(
+            graph.node_names(),
+            vec!["skill-a", "skill-b", "skill-c", "skill-d"]
+        );
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.edges_from("skill-d"), Some(vec![]));
+        assert_eq!(graph.edges_to("skill-d"), Some(vec![]));
     }
 
     #[test]
-    fn should_identify_leaf_skills() {
-        // Given: skill-a → skill-b (skill-b is leaf)
+    fn should_report_pipeline_edges_without_line() {
+        // Given: skill-b runs after skill-a
+        use crate::skill::frontmatter::PipelineStage;
+
+        let skills: Vec<Skill> = ["skill-a", "skill-b"]
+            .iter()
+            .map(|name| {
+                let content = format!("---\nname: {}\ndescription: Test\n---\n", name);
+                let mut frontmatter = crate::skill::Frontmatter::parse(&content).unwrap();
+                if *name == "skill-b" {
+                    let mut stages = HashMap::new();
+                    stages.insert(
+                        "release".to_string(),
+                        PipelineStage {
+                            stage: "second".to_string(),
+                            order: 2,
+                            after: Some(vec!["skill-a".to_string()]),
+                            before: None,
+                        },
+                    );
+                    frontmatter.pipeline = Some(stages);
+                }
+                Skill {
+                    name: name.to_string(),
+                    path: std::path::PathBuf::from(format!("/test/{}", name)),
+                    skill_file: std::path::PathBuf::from(format!("/test/{}/SKILL.md", name)),
+                    frontmatter,
+                    content: None,
+                }
+            })
+            .collect();
+
+        // When
+        let graph = SkillGraph::from_skills(&HashMap::new(), &skills);
+
+        // Then
+        assert_eq!(
+            graph.edges_from("skill-b"),
+            Some(vec![("skill-a".to_string(), EdgeKind::Pipeline, None)])
+        );
+    }
+
+    #[test]
+    fn should_find_mutual_pairs_inside_larger_cycle() {
+        // Given: a ⇄ b, plus the cycle b -> c -> a
         let mut crossrefs = HashMap::new();
         crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert(
+            "skill-b".to_string(),
+            vec![test_crossref("skill-a"), test_crossref("skill-c")],
+        );
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-a")]);
 
         // When
         let graph = SkillGraph::from_crossrefs(&crossrefs);
 
         // Then
-        assert_eq!(graph.leaves.len(), 1);
-        assert!(graph.leaves.contains(&"skill-b".to_string()));
+        assert_eq!(graph.clusters.len(), 1);
+        assert_eq!(
+            graph.mutual_pairs(),
+            vec![("skill-a".to_string(), "skill-b".to_string())]
+        );
+        assert_eq!(graph.to_mutual_text(), "skill-a ⇄ skill-b\n");
     }
 
     #[test]
-    fn should_detect_clusters() {
-        // Given: skill-a ↔ skill-b (circular reference, forms a cluster)
+    fn should_render_markdown_report_with_summary_and_skill_sections() {
+        // Given
+        let graph = chain_graph();
+
+        // When
+        let report = graph.to_markdown_report();
+
+        // Then
+        assert!(report.starts_with("# Skill Dependencies\n"));
+        assert!(report.contains("| Metric | Count |\n|--------|-------|\n| Skills | 3 |\n"));
+        assert!(report.contains(
+            "### b-middle\n\nReferences:\n\n- [c-last](#c-last) (line 1)\n\n\
+             Referenced by:\n\n- [a-first](#a-first) (line 1)\n"
+        ));
+        assert!(report.contains("### a-first\n\nReferences:\n\n- [b-middle](#b-middle) (line 1)\n\nReferenced by:\n\n_None_\n"));
+        assert!(!report.contains("## Cycles"));
+    }
+
+    #[test]
+    fn should_list_clusters_in_markdown_cycles_section() {
+        // Given: skill-a ⇄ skill-b
         let mut crossrefs = HashMap::new();
         crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
         crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-a")]);
 
+        // When
+        let report = SkillGraph::from_crossrefs(&crossrefs).to_markdown_report();
+
+        // Then
+        assert!(report
+            .ends_with("## Cycles\n\n- **skill-a**: [skill-a](#skill-a), [skill-b](#skill-b)\n"));
+    }
+
+    #[test]
+    fn should_split_disjoint_chains_into_components() {
+        // Given: a -> b -> c and x -> y, with no link between them
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-c")]);
+        crossrefs.insert("skill-x".to_string(), vec![test_crossref("skill-y")]);
+
         // When
         let graph = SkillGraph::from_crossrefs(&crossrefs);
 
         // Then
-        assert_eq!(graph.clusters.len(), 1);
-        assert_eq!(graph.clusters[0].len(), 2);
+        assert!(graph.clusters.is_empty());
+        assert_eq!(
+            graph.components(),
+            vec![
+                vec!["skill-a", "skill-b", "skill-c"],
+                vec!["skill-x", "skill-y"],
+            ]
+        );
+        assert_eq!(
+            graph.to_components_text(),
+            "1. skill-a, skill-b, skill-c\n2. skill-x, skill-y\n"
+        );
+    }
+
+    #[test]
+    fn should_rank_hubs_by_total_degree() {
+        // Given: skill-b is referenced by skill-a and skill-c, and references skill-d
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-d")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-b")]);
+
+        // When
+        let hubs = SkillGraph::from_crossrefs(&crossrefs).hubs(2);
+
+        // Then: ties at degree 1 break by name
+        assert_eq!(
+            hubs,
+            vec![("skill-b".to_string(), 3), ("skill-a".to_string(), 1)]
+        );
     }
 
     #[test]
@@ -530,13 +3237,57 @@ mod tests {
 
         // When
         let graph = SkillGraph::from_crossrefs(&crossrefs);
-        let dot = graph.to_dot();
+        let dot = graph.to_dot(NodeOrder::Alpha);
 
         // Then
         assert!(dot.contains("digraph SkillGraph"));
         assert!(dot.contains("\"skill-a\" -> \"skill-b\""));
     }
 
+    #[test]
+    fn should_box_clusters_as_dot_subgraphs() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-a")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-a")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let dot = graph.to_dot(NodeOrder::Alpha);
+
+        // Then
+        let start = dot.find("subgraph cluster_0 {").unwrap();
+        let end = start + dot[start..].find("  }\n").unwrap();
+        let block = &dot[start..end];
+        assert!(block.contains("label=\"cluster 0\";"));
+        assert!(block.contains("\"skill-a\""));
+        assert!(block.contains("\"skill-b\""));
+        assert!(!block.contains("\"skill-c\""));
+        assert!(dot.contains("  \"skill-c\" [fillcolor=lightblue"));
+    }
+
+    #[test]
+    fn should_keep_self_loop_in_dot_and_json() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-a".to_string(),
+            crate::skill::extract_references("<see ref=\"skill-a\">Me</see>", "skill-a"),
+        );
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then
+        assert!(graph
+            .to_dot(NodeOrder::Alpha)
+            .contains("\"skill-a\" -> \"skill-a\""));
+        assert!(graph
+            .to_json(NodeOrder::Alpha)
+            .contains("\"source\":\"skill-a\",\"target\":\"skill-a\""));
+    }
+
     #[test]
     fn should_generate_json_output() {
         // Given
@@ -545,7 +3296,7 @@ mod tests {
 
         // When
         let graph = SkillGraph::from_crossrefs(&crossrefs);
-        let json = graph.to_json();
+        let json = graph.to_json(NodeOrder::Alpha);
 
         // Then
         assert!(json.contains("\"nodes\""));
@@ -553,6 +3304,243 @@ mod tests {
         assert!(json.contains("skill-a"));
     }
 
+    #[test]
+    fn should_include_degrees_and_cluster_index_in_json_nodes() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-a")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-a")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let json: serde_json::Value =
+            serde_json::from_str(&graph.to_json(NodeOrder::Alpha)).unwrap();
+
+        // Then
+        let node = |id: &str| {
+            json["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|n| n["id"] == id)
+                .unwrap()
+                .clone()
+        };
+        let a = node("skill-a");
+        assert_eq!(a["out_degree"], 1);
+        assert_eq!(a["in_degree"], 2);
+        assert_eq!(a["cluster"], 0);
+        let b = node("skill-b");
+        assert_eq!(b["out_degree"], 1);
+        assert_eq!(b["in_degree"], 1);
+        assert_eq!(b["cluster"], 0);
+        let c = node("skill-c");
+        assert_eq!(c["out_degree"], 1);
+        assert_eq!(c["in_degree"], 0);
+        assert!(c["cluster"].is_null());
+    }
+
+    #[test]
+    fn should_round_trip_identically_through_json_yaml_and_toml() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("skill-b".to_string(), vec![test_crossref("skill-a")]);
+        crossrefs.insert("skill-c".to_string(), vec![test_crossref("skill-a")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let from_json: GraphExport =
+            serde_json::from_str(&graph.to_json(NodeOrder::Alpha)).unwrap();
+        let from_yaml: GraphExport =
+            serde_yaml::from_str(&graph.to_yaml(NodeOrder::Alpha)).unwrap();
+        let from_toml: GraphExport = toml::from_str(&graph.to_toml(NodeOrder::Alpha)).unwrap();
+
+        // Then
+        assert_eq!(from_json, graph.export(NodeOrder::Alpha));
+        assert_eq!(from_yaml, from_json);
+        assert_eq!(from_toml, from_json);
+        assert_eq!(from_json.nodes.len(), 3);
+        assert_eq!(from_json.edges.len(), 3);
+        assert_eq!(from_json.clusters.len(), 1);
+    }
+
+    #[test]
+    fn should_rank_pipeline_orders_in_separate_swimlanes() {
+        // Given: draft (order 1) → edit (order 2)
+        let stage = |label: &str, order| PipelineStage {
+            stage: label.to_string(),
+            order,
+            after: None,
+            before: None,
+        };
+        let stages = vec![
+            ("draft".to_string(), stage("draft", 1)),
+            ("edit".to_string(), stage("edit", 2)),
+        ];
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("draft".to_string(), vec![test_crossref("edit")]);
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // When
+        let dot = graph.to_swimlane(&stages);
+
+        // Then
+        let ranks: Vec<&str> = dot.lines().filter(|l| l.contains("rank=same")).collect();
+        assert_eq!(ranks.len(), 2);
+        assert!(
+            ranks[0].contains("\"lane_1\" [label=\"1. draft\"") && ranks[0].contains(" \"draft\";")
+        );
+        assert!(
+            ranks[1].contains("\"lane_2\" [label=\"2. edit\"") && ranks[1].contains(" \"edit\";")
+        );
+        assert!(!ranks[0].contains(" \"edit\";"));
+        assert!(dot.contains("\"lane_1\" -> \"lane_2\" [style=invis];"));
+        assert!(dot.contains("\"draft\" -> \"edit\";"));
+    }
+
+    #[test]
+    fn should_drop_crossref_edges_when_filtering_to_pipeline() {
+        // Given: draft -> edit is declared, edit -> notes is only mentioned
+        use crate::skill::frontmatter::PipelineStage;
+        let skills: Vec<Skill> = ["draft", "edit", "notes"]
+            .iter()
+            .map(|name| {
+                let content = format!("---\nname: {}\ndescription: Test\n---\n", name);
+                let mut frontmatter = crate::skill::Frontmatter::parse(&content).unwrap();
+                if *name == "draft" {
+                    let mut stages = HashMap::new();
+                    stages.insert(
+                        "blog".to_string(),
+                        PipelineStage {
+                            stage: "draft".to_string(),
+                            order: 1,
+                            after: None,
+                            before: Some(vec!["edit".to_string()]),
+                        },
+                    );
+                    frontmatter.pipeline = Some(stages);
+                }
+                Skill {
+                    name: name.to_string(),
+                    path: std::path::PathBuf::from(format!("/test/{}", name)),
+                    skill_file: std::path::PathBuf::from(format!("/test/{}/SKILL.md", name)),
+                    frontmatter,
+                    content: None,
+                }
+            })
+            .collect();
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("edit".to_string(), vec![test_crossref("notes")]);
+        let graph = SkillGraph::from_skills(&crossrefs, &skills);
+
+        // When
+        let filtered = graph.filter_edges(&[EdgeKind::Pipeline]);
+
+        // Then
+        assert_eq!(
+            filtered.edges_from("edit"),
+            Some(vec![("draft".to_string(), EdgeKind::Pipeline, None)])
+        );
+        assert_eq!(filtered.graph.node_count(), 3);
+        assert!(!graph.roots.contains(&"notes".to_string()));
+        assert!(filtered.roots.contains(&"notes".to_string()));
+    }
+
+    #[test]
+    fn should_generate_d2_output_with_sanitized_ids() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("my skill".to_string(), vec![test_crossref("skill-b")]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let d2 = graph.to_d2();
+
+        // Then
+        assert!(d2.contains("my_skill: \"my skill\" {style.fill: \"lightblue\"}"));
+        assert!(d2.contains("skill_b: \"skill-b\" {style.fill: \"lightgreen\"}"));
+        assert!(d2.contains("my_skill -> skill_b\n"));
+    }
+
+    #[test]
+    fn should_dash_pipeline_edges_in_d2_output() {
+        // Given
+        use crate::skill::frontmatter::PipelineStage;
+        let skills: Vec<Skill> = ["draft", "edit"]
+            .iter()
+            .map(|name| {
+                let content = format!("---\nname: {}\ndescription: Test\n---\n", name);
+                let mut frontmatter = crate::skill::Frontmatter::parse(&content).unwrap();
+                if *name == "edit" {
+                    let mut stages = HashMap::new();
+                    stages.insert(
+                        "blog".to_string(),
+                        PipelineStage {
+                            stage: "edit".to_string(),
+                            order: 2,
+                            after: Some(vec!["draft".to_string()]),
+                            before: None,
+                        },
+                    );
+                    frontmatter.pipeline = Some(stages);
+                }
+                Skill {
+                    name: name.to_string(),
+                    path: std::path::PathBuf::from(format!("/test/{}", name)),
+                    skill_file: std::path::PathBuf::from(format!("/test/{}/SKILL.md", name)),
+                    frontmatter,
+                    content: None,
+                }
+            })
+            .collect();
+
+        // When
+        let graph = SkillGraph::from_skills(&HashMap::new(), &skills);
+        let d2 = graph.to_d2();
+
+        // Then
+        assert!(d2.contains("edit -> draft: {style.stroke-dash: 3}"));
+    }
+
+    #[test]
+    fn should_include_unconnected_skill_in_mermaid_output() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert("skill-a".to_string(), vec![test_crossref("skill-b")]);
+        crossrefs.insert("loner".to_string(), vec![]);
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let mermaid = graph.to_mermaid();
+
+        // Then
+        assert!(mermaid.contains("  loner[loner]\n"));
+    }
+
+    #[test]
+    fn should_weight_edges_by_reference_count() {
+        // Given: skill-a mentions skill-b twice
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-a".to_string(),
+            vec![test_crossref("skill-b"), test_crossref("skill-b")],
+        );
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+        let export: GraphExport = serde_json::from_str(&graph.to_json(NodeOrder::Alpha)).unwrap();
+
+        // Then
+        assert_eq!(export.edges.len(), 1);
+        assert_eq!(export.edges[0].weight, 2);
+        assert!(graph.to_json(NodeOrder::Alpha).contains("\"weight\":2"));
+        assert!(graph
+            .to_dot(NodeOrder::Alpha)
+            .contains("\"skill-a\" -> \"skill-b\" [penwidth=2];"));
+    }
+
     #[test]
     fn should_generate_mermaid_output() {
         // Given
@@ -567,6 +3555,9 @@ mod tests {
         assert!(mermaid.contains("graph LR"));
         assert!(mermaid.contains("skill_a"));
         assert!(mermaid.contains("-->"));
+        assert!(mermaid.contains("classDef root fill:lightblue"));
+        assert!(mermaid.contains("class skill_a root"));
+        assert!(mermaid.contains("class skill_b leaf"));
     }
 
     #[test]
@@ -580,7 +3571,7 @@ mod tests {
 
         // When
         let graph = SkillGraph::from_crossrefs(&crossrefs);
-        let text = graph.to_text();
+        let text = graph.to_text(NodeOrder::Alpha);
 
         // Then: skill-b should appear only once in the adjacency list
         let line = text.lines().find(|l| l.starts_with("skill-a:")).unwrap();
@@ -612,6 +3603,8 @@ mod tests {
                     compatibility: None,
                     metadata: None,
                     tags: None,
+                    aliases: None,
+                    deprecated: None,
                     pipeline: Some({
                         let mut m = HashMap::new();
                         m.insert(
@@ -626,6 +3619,7 @@ mod tests {
                         m
                     }),
                 },
+                content: None,
             },
             Skill {
                 name: "skill-b".to_string(),
@@ -645,6 +3639,8 @@ mod tests {
                     compatibility: None,
                     metadata: None,
                     tags: None,
+                    aliases: None,
+                    deprecated: None,
                     pipeline: Some({
                         let mut m = HashMap::new();
                         m.insert(
@@ -659,6 +3655,7 @@ mod tests {
                         m
                     }),
                 },
+                content: None,
             },
         ];
 
@@ -666,7 +3663,7 @@ mod tests {
 
         // When
         let graph = SkillGraph::from_skills(&crossrefs, &skills);
-        let text = graph.to_text();
+        let text = graph.to_text(NodeOrder::Alpha);
 
         // Then: pipeline edges create the dependency
         let line_b = text.lines().find(|l| l.starts_with("skill-b:")).unwrap();
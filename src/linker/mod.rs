@@ -1,14 +1,26 @@
 //! Symlink creation, removal, and marker management
+//!
+//! The marker file doubles as a manifest: a JSON document listing the skill
+//! links loadout created in the directory, so later installs can prune links
+//! for skills that are no longer enabled without touching anything else.
 
+use std::collections::BTreeSet;
 use std::fs;
 use std::os::unix::fs as unix_fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 const MARKER_FILE_NAME: &str = ".managed-by-loadout";
 
+/// Contents of the marker file: the skill links loadout installed
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    skills: BTreeSet<String>,
+}
+
 /// Errors that can occur during linking operations
 #[derive(Error, Debug)]
 pub enum LinkerError {
@@ -17,6 +29,12 @@ pub enum LinkerError {
 
     #[error("Symlink already exists: {0}")]
     SymlinkExists(PathBuf),
+
+    #[error("Skill name '{0}' doesn't name a path inside the target directory")]
+    InvalidSkillName(String),
+
+    #[error("Refusing to link through symlinked directory {0}")]
+    SymlinkedParent(PathBuf),
 }
 
 /// Create a symlink from source skill directory to target location
@@ -26,7 +44,13 @@ pub enum LinkerError {
 /// - Creates a marker file to indicate the directory is managed
 /// - Creates the symlink if it doesn't already exist
 /// - Skips if the symlink already exists and points to the correct source
+/// - Records the skill in the directory's manifest
+///
+/// Fails without touching anything if the link would land outside
+/// `target_dir` or pass through a symlinked directory on the way.
 pub fn link_skill(skill_name: &str, skill_path: &Path, target_dir: &Path) -> Result<()> {
+    check_link_parents(skill_name, target_dir)?;
+
     // Create target directory if it doesn't exist
     fs::create_dir_all(target_dir).context(format!(
         "Failed to create target directory: {}",
@@ -46,8 +70,8 @@ pub fn link_skill(skill_name: &str, skill_path: &Path, target_dir: &Path) -> Res
             let current_target = fs::read_link(&link_path)
                 .context(format!("Failed to read symlink: {}", link_path.display()))?;
             if current_target == skill_path {
-                // Symlink already correct, nothing to do
-                return Ok(());
+                // Symlink already correct, only make sure it is recorded
+                return record_in_manifest(target_dir, skill_name);
             }
         }
 
@@ -60,13 +84,128 @@ pub fn link_skill(skill_name: &str, skill_path: &Path, target_dir: &Path) -> Res
         remove_symlink(&link_path)?;
     }
 
+    // Prefixed skills (`prefix/name`) link inside a prefix directory
+    if let Some(parent) = link_path.parent() {
+        fs::create_dir_all(parent)
+            .context(format!("Failed to create directory: {}", parent.display()))?;
+    }
+
     // Create the symlink
     unix_fs::symlink(skill_path, &link_path)
         .context(format!("Failed to create symlink: {}", link_path.display()))?;
 
+    record_in_manifest(target_dir, skill_name)
+}
+
+/// Remove a skill's symlink from a managed target directory
+///
+/// Only the symlink is removed; a regular file or directory at the link path
+/// is left alone. The skill is dropped from the manifest either way.
+pub fn unlink_skill(skill_name: &str, target_dir: &Path) -> Result<()> {
+    check_link_parents(skill_name, target_dir)?;
+    let link_path = target_dir.join(skill_name);
+
+    if link_path.is_symlink() {
+        remove_symlink(&link_path)?;
+        remove_empty_parents(&link_path, target_dir)?;
+    }
+
+    let mut manifest = read_manifest(target_dir)?;
+    if manifest.remove(skill_name) {
+        write_manifest(target_dir, &manifest)?;
+    }
+
+    Ok(())
+}
+
+/// Check that a skill's link path stays inside `target_dir` on real directories
+///
+/// Prefixed skills link inside a prefix directory. If that directory is a
+/// symlink (say, the link of a skill named like the prefix), creating the
+/// link would write into whatever it points at.
+fn check_link_parents(skill_name: &str, target_dir: &Path) -> Result<()> {
+    let relative = Path::new(skill_name);
+    if skill_name.is_empty()
+        || !relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return Err(LinkerError::InvalidSkillName(skill_name.to_string()).into());
+    }
+
+    let mut dir = target_dir.to_path_buf();
+    for component in relative.parent().into_iter().flat_map(Path::components) {
+        dir.push(component);
+        if dir.is_symlink() {
+            return Err(LinkerError::SymlinkedParent(dir).into());
+        }
+    }
+
     Ok(())
 }
 
+/// Skill links recorded in a target directory's manifest
+///
+/// Returns an empty set for unmanaged directories and for legacy markers
+/// (see [`has_legacy_marker`]), which don't say which links loadout made.
+pub fn read_manifest(target_dir: &Path) -> Result<BTreeSet<String>> {
+    let marker_path = target_dir.join(MARKER_FILE_NAME);
+
+    if !marker_path.exists() {
+        return Ok(BTreeSet::new());
+    }
+
+    let content = fs::read_to_string(&marker_path).context(format!(
+        "Failed to read marker file: {}",
+        marker_path.display()
+    ))?;
+
+    if content.trim().is_empty() {
+        return Ok(BTreeSet::new());
+    }
+
+    let manifest: Manifest = serde_json::from_str(&content).context(format!(
+        "Failed to parse marker file: {}",
+        marker_path.display()
+    ))?;
+
+    Ok(manifest.skills)
+}
+
+/// Add a skill to a target directory's manifest
+fn record_in_manifest(target_dir: &Path, skill_name: &str) -> Result<()> {
+    let mut manifest = read_manifest(target_dir)?;
+    if manifest.insert(skill_name.to_string()) {
+        write_manifest(target_dir, &manifest)?;
+    }
+    Ok(())
+}
+
+/// Write a manifest into a target directory's marker file
+fn write_manifest(target_dir: &Path, skills: &BTreeSet<String>) -> Result<()> {
+    let marker_path = target_dir.join(MARKER_FILE_NAME);
+    let manifest = Manifest {
+        skills: skills.clone(),
+    };
+    let content = serde_json::to_string_pretty(&manifest)?;
+
+    fs::write(&marker_path, content + "\n").context(format!(
+        "Failed to write marker file: {}",
+        marker_path.display()
+    ))?;
+
+    Ok(())
+}
+
+/// Check if a target directory's marker predates manifests
+///
+/// Such markers are empty, so nothing in the directory can be pruned safely
+/// until an install records its links in a real manifest.
+pub fn has_legacy_marker(target_dir: &Path) -> bool {
+    fs::read_to_string(target_dir.join(MARKER_FILE_NAME))
+        .is_ok_and(|content| content.trim().is_empty())
+}
+
 /// Remove all managed symlinks from a target directory
 pub fn clean_target(target_dir: &Path) -> Result<Vec<PathBuf>> {
     if !is_managed(target_dir) {
@@ -76,6 +215,13 @@ pub fn clean_target(target_dir: &Path) -> Result<Vec<PathBuf>> {
 
     let mut removed = Vec::new();
 
+    // Prefixed links live below the top level; only the manifest knows them
+    for path in nested_links(target_dir)? {
+        remove_symlink(&path)?;
+        remove_empty_parents(&path, target_dir)?;
+        removed.push(path);
+    }
+
     // Read all entries in the target directory
     if target_dir.exists() && target_dir.is_dir() {
         for entry in fs::read_dir(target_dir).context(format!(
@@ -112,15 +258,64 @@ pub fn clean_target(target_dir: &Path) -> Result<Vec<PathBuf>> {
     Ok(removed)
 }
 
+/// Manifest-recorded links of prefixed skills that still exist as symlinks
+fn nested_links(target_dir: &Path) -> Result<Vec<PathBuf>> {
+    Ok(read_manifest(target_dir)?
+        .iter()
+        .filter(|name| name.contains('/'))
+        .map(|name| target_dir.join(name))
+        .filter(|path| path.is_symlink())
+        .collect())
+}
+
+/// Remove directories left empty between a removed link and the target
+fn remove_empty_parents(link_path: &Path, target_dir: &Path) -> Result<()> {
+    let mut dir = link_path.parent();
+    while let Some(current) = dir {
+        if current == target_dir || !is_directory_empty(current)? {
+            break;
+        }
+        fs::remove_dir(current).context(format!(
+            "Failed to remove empty directory: {}",
+            current.display()
+        ))?;
+        dir = current.parent();
+    }
+    Ok(())
+}
+
+/// Paths `clean_target` would remove: every symlink (including prefixed
+/// skills' nested links) plus the marker file
+///
+/// Returns an empty list for unmanaged directories.
+pub fn clean_candidates(target_dir: &Path) -> Result<Vec<PathBuf>> {
+    if !is_managed(target_dir) {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(target_dir).context(format!(
+        "Failed to read directory: {}",
+        target_dir.display()
+    ))? {
+        let path = entry?.path();
+        if path.is_symlink() {
+            paths.push(path);
+        }
+    }
+    paths.extend(nested_links(target_dir)?);
+    paths.sort();
+    paths.push(target_dir.join(MARKER_FILE_NAME));
+
+    Ok(paths)
+}
+
 /// Create a marker file in the target directory
 fn create_marker(target_dir: &Path) -> Result<()> {
     let marker_path = target_dir.join(MARKER_FILE_NAME);
 
     if !marker_path.exists() {
-        fs::write(&marker_path, "").context(format!(
-            "Failed to create marker file: {}",
-            marker_path.display()
-        ))?;
+        write_manifest(target_dir, &BTreeSet::new())?;
     }
 
     Ok(())
@@ -182,6 +377,45 @@ mod tests {
         assert_eq!(link_target, skill_dir);
     }
 
+    #[test]
+    fn should_refuse_to_link_through_symlinked_prefix_directory() {
+        // Given: the vendor skill's link occupies the vendor/ prefix directory
+        let temp = TempDir::new().unwrap();
+        let vendor_source = temp.path().join("vendor-source");
+        let foo_source = temp.path().join("foo-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&vendor_source).unwrap();
+        fs::create_dir(&foo_source).unwrap();
+        link_skill("vendor", &vendor_source, &target_dir).unwrap();
+
+        // When
+        let result = link_skill("vendor/foo", &foo_source, &target_dir);
+
+        // Then
+        assert!(result.is_err());
+        assert!(!vendor_source.join("foo").exists());
+        assert!(!vendor_source.join("foo").is_symlink());
+    }
+
+    #[test]
+    fn should_reject_skill_names_leaving_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skill-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&skill_dir).unwrap();
+
+        // When/Then
+        for name in ["", "../escape", "/abs", "a/../b"] {
+            assert!(
+                link_skill(name, &skill_dir, &target_dir).is_err(),
+                "'{}' was linked",
+                name
+            );
+        }
+        assert!(!temp.path().join("escape").exists());
+    }
+
     #[test]
     fn should_create_marker_file() {
         // Given
@@ -320,6 +554,105 @@ mod tests {
         assert!(target_dir.exists());
     }
 
+    #[test]
+    fn should_record_linked_skills_in_manifest() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skill-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&skill_dir).unwrap();
+
+        // When
+        link_skill("my-skill", &skill_dir, &target_dir).unwrap();
+        link_skill("other-skill", &skill_dir, &target_dir).unwrap();
+
+        // Then
+        let manifest = read_manifest(&target_dir).unwrap();
+        assert_eq!(
+            manifest.into_iter().collect::<Vec<_>>(),
+            vec!["my-skill", "other-skill"]
+        );
+    }
+
+    #[test]
+    fn should_unlink_skill_and_drop_it_from_manifest() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skill-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&skill_dir).unwrap();
+        link_skill("my-skill", &skill_dir, &target_dir).unwrap();
+
+        // When
+        unlink_skill("my-skill", &target_dir).unwrap();
+
+        // Then
+        assert!(!target_dir.join("my-skill").is_symlink());
+        assert!(read_manifest(&target_dir).unwrap().is_empty());
+        assert!(is_managed(&target_dir));
+    }
+
+    #[test]
+    fn should_link_and_clean_prefixed_skill_in_prefix_directory() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skill-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&skill_dir).unwrap();
+
+        // When
+        link_skill("vendor/my-skill", &skill_dir, &target_dir).unwrap();
+        let candidates = clean_candidates(&target_dir).unwrap();
+        let removed = clean_target(&target_dir).unwrap();
+
+        // Then
+        let link = target_dir.join("vendor/my-skill");
+        assert_eq!(
+            candidates,
+            vec![link.clone(), target_dir.join(MARKER_FILE_NAME)]
+        );
+        assert_eq!(removed, vec![link]);
+        assert!(!target_dir.exists());
+    }
+
+    #[test]
+    fn should_read_legacy_marker_as_empty_manifest() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skill-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::create_dir(&target_dir).unwrap();
+        fs::write(target_dir.join(MARKER_FILE_NAME), "").unwrap();
+        unix_fs::symlink(&skill_dir, target_dir.join("my-own-link")).unwrap();
+
+        // When
+        let manifest = read_manifest(&target_dir).unwrap();
+
+        // Then
+        assert!(manifest.is_empty());
+        assert!(has_legacy_marker(&target_dir));
+    }
+
+    #[test]
+    fn should_replace_legacy_marker_with_manifest_when_linking() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skill-source");
+        let target_dir = temp.path().join("target");
+        fs::create_dir(&skill_dir).unwrap();
+        fs::create_dir(&target_dir).unwrap();
+        fs::write(target_dir.join(MARKER_FILE_NAME), "").unwrap();
+
+        // When
+        link_skill("my-skill", &skill_dir, &target_dir).unwrap();
+
+        // Then
+        assert!(!has_legacy_marker(&target_dir));
+        let manifest = read_manifest(&target_dir).unwrap();
+        assert_eq!(manifest.into_iter().collect::<Vec<_>>(), vec!["my-skill"]);
+    }
+
     #[test]
     fn should_detect_managed_directory() {
         // Given
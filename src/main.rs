@@ -7,6 +7,10 @@ use loadout::{commands, config};
 #[command(name = "loadout")]
 #[command(version, about, long_about = None)]
 struct Cli {
+    /// Merge a named [profiles.<name>] section over the base config
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -18,12 +22,24 @@ enum Commands {
         /// Show what would happen without making changes
         #[arg(long)]
         dry_run: bool,
+        /// Resolve conflicting links last-writer-wins instead of aborting
+        #[arg(long)]
+        force: bool,
+        /// Keep previously installed links for skills that are no longer enabled
+        #[arg(long)]
+        no_prune: bool,
+        /// Only install skills whose compatibility lists this target
+        #[arg(long, value_name = "TARGET")]
+        compatible_with: Option<String>,
     },
     /// Remove all managed symlinks from target directories
     Clean {
         /// Show what would happen without making changes
         #[arg(long)]
         dry_run: bool,
+        /// Only clean this global target directory
+        #[arg(long)]
+        target: Option<std::path::PathBuf>,
     },
     /// Check skill system health and report diagnostics
     Check {
@@ -33,11 +49,25 @@ enum Commands {
         /// Show suppressed findings alongside active ones
         #[arg(long)]
         verbose: bool,
+        /// Rewrite SKILL.md files to resolve fixable findings
+        #[arg(long)]
+        fix: bool,
+        /// With --fix, also apply fixes for error-level findings
+        #[arg(long, requires = "fix")]
+        force: bool,
+        /// Output format: human, sarif
+        #[arg(long, default_value = "human")]
+        format: String,
+        /// Lowest severity that causes a non-zero exit (error, warning, info)
+        #[arg(long, default_value = "error")]
+        fail_on: String,
     },
     /// Visualize skill dependency graph
     #[cfg(feature = "graph")]
     Graph {
-        /// Output format: dot, text, json, mermaid
+        #[command(subcommand)]
+        action: Option<GraphAction>,
+        /// Output format: dot, text, json, yaml, toml, mermaid, d2, topo, centrality, pagerank, cycles, break-cycles, hubs, mutual, stats, diamonds, html, swimlane, components, markdown (comma-separate several with --output-dir)
         #[arg(long, default_value = "text")]
         format: String,
         /// Filter to skills in a specific pipeline
@@ -46,12 +76,60 @@ enum Commands {
         /// Filter to skills with a specific tag
         #[arg(long)]
         tag: Option<String>,
+        /// Drop skills with a specific tag, and their edges
+        #[arg(long)]
+        exclude_tag: Option<String>,
+        /// Drop skills marked deprecated in frontmatter, and their edges
+        #[arg(long)]
+        exclude_deprecated: bool,
+        /// Filter to a skill and everything that transitively depends on it
+        #[arg(long)]
+        impact: Option<String>,
+        /// Filter to skills within --depth hops of a skill
+        #[arg(long)]
+        around: Option<String>,
+        /// Hop limit for --around
+        #[arg(long, default_value_t = 1)]
+        depth: usize,
+        /// Edge kinds to keep: crossref, pipeline, all
+        #[arg(long, default_value = "all")]
+        edges: String,
+        /// Node order for dot, text, json, yaml, and toml: alpha, topo
+        #[arg(long, default_value = "alpha")]
+        order: String,
+        /// DOT layout direction: LR, TB
+        #[arg(long, default_value = "LR")]
+        rankdir: String,
+        /// In DOT output, pin roots to the first rank and leaves to the last
+        #[arg(long)]
+        rank_roots: bool,
+        /// In dot and mermaid output, emphasize a skill and its direct edges
+        #[arg(long, value_name = "SKILL")]
+        highlight: Option<String>,
+        /// Drop skills not reachable from a root (unconnected skills and islands)
+        #[arg(long)]
+        from_roots: bool,
+        /// Write the graph to a file instead of stdout
+        #[arg(long)]
+        output: Option<std::path::PathBuf>,
+        /// Build the graph once and write each --format to DIR/graph.<ext>
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["output", "watch", "check_acyclic"])]
+        output_dir: Option<std::path::PathBuf>,
+        /// Re-render whenever a SKILL.md changes (requires the watch feature)
+        #[arg(long)]
+        watch: bool,
+        /// Print cycles and exit non-zero if the graph has any
+        #[arg(long, conflicts_with_all = ["watch", "output"])]
+        check_acyclic: bool,
     },
     /// List enabled skills per scope
     List {
         /// Show skills organized by detected clusters
         #[arg(long)]
         groups: bool,
+        /// Print --groups output as JSON
+        #[arg(long, requires = "groups")]
+        json: bool,
         /// Show references for a specific skill
         #[arg(long)]
         refs: Option<String>,
@@ -61,7 +139,10 @@ enum Commands {
         /// Show all tags with skill counts
         #[arg(long)]
         tags: bool,
-        /// Show skills with a specific tag
+        /// Group skills by their frontmatter agent
+        #[arg(long)]
+        by_agent: bool,
+        /// Show enabled skills with a specific tag, grouped by scope
         #[arg(long)]
         tag: Option<String>,
         /// Show all pipelines with skill counts
@@ -70,11 +151,35 @@ enum Commands {
         /// Show a specific pipeline in stage order
         #[arg(long)]
         pipeline: Option<String>,
+        /// Show skills that would be affected by removing a skill
+        #[arg(long)]
+        impact: Option<String>,
+        /// Show skills that neither reference nor are referenced by any other
+        #[arg(long)]
+        orphans: bool,
+        /// Sort skills within each scope: name, path, source
+        #[arg(long, default_value = "name")]
+        sort: String,
+        /// Append the pipelines each skill belongs to
+        #[arg(long)]
+        show_pipelines: bool,
+        /// Only print found and missing counts per scope
+        #[arg(long)]
+        count: bool,
+        /// Only show enabled skills whose compatibility lists this target
+        #[arg(long, value_name = "TARGET")]
+        compatible_with: Option<String>,
     },
     /// Validate SKILL.md files
     Validate {
         /// Skill name or directory path (validates all if not specified)
         target: Option<String>,
+        /// Check the config instead: enabled skills, sources, and targets
+        #[arg(long = "config", conflicts_with = "target")]
+        check_config: bool,
+        /// Warn about frontmatter fields loadout doesn't recognize
+        #[arg(long, conflicts_with = "check_config")]
+        strict: bool,
     },
     /// Create a new skill from template
     New {
@@ -83,100 +188,298 @@ enum Commands {
         /// Skill description
         #[arg(short, long)]
         description: Option<String>,
+        /// Scaffold from a named template (built-in: minimal, pipeline-stage)
+        #[arg(short, long)]
+        template: Option<String>,
+    },
+}
+
+#[cfg(feature = "graph")]
+#[derive(Subcommand, Debug)]
+enum GraphAction {
+    /// Compare the configured skill graph against other source directories
+    Diff {
+        /// Source directories to compare against
+        #[arg(required = true)]
+        sources: Vec<std::path::PathBuf>,
     },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let config = config::load()?;
+    let mut config = config::load()?;
+    if let Some(profile) = &cli.profile {
+        config::apply_profile(&mut config, profile)?;
+    }
 
     match cli.command {
-        Commands::Install { dry_run } => {
-            commands::install(&config, dry_run)?;
+        Commands::Install {
+            dry_run,
+            force,
+            no_prune,
+            compatible_with,
+        } => {
+            let mut config = config;
+            if let Some(target) = compatible_with {
+                commands::install::retain_compatible(&mut config, &target)?;
+            }
+            commands::install(&config, dry_run, force, no_prune)?;
         }
-        Commands::Clean { dry_run } => {
-            commands::clean(&config, dry_run)?;
+        Commands::Clean { dry_run, target } => {
+            commands::clean(&config, dry_run, target.as_deref())?;
         }
-        Commands::Check { severity, verbose } => {
-            let filter = severity
-                .as_deref()
-                .map(|s| match s.to_lowercase().as_str() {
-                    "error" => commands::check::Severity::Error,
-                    "warning" => commands::check::Severity::Warning,
-                    "info" => commands::check::Severity::Info,
-                    _ => {
-                        eprintln!(
-                            "Invalid severity: {}. Valid values: error, warning, info",
-                            s
-                        );
-                        std::process::exit(1);
-                    }
-                });
+        Commands::Check {
+            severity,
+            verbose,
+            fix,
+            force,
+            format,
+            fail_on,
+        } => {
+            let parse_severity = |s: &str| {
+                commands::check::Severity::parse_severity(s).unwrap_or_else(|| {
+                    eprintln!(
+                        "Invalid severity: {}. Valid values: error, warning, info",
+                        s
+                    );
+                    std::process::exit(1);
+                })
+            };
+            let filter = severity.as_deref().map(parse_severity);
+            let threshold = parse_severity(&fail_on);
+
+            let sarif = match format.to_lowercase().as_str() {
+                "human" => false,
+                "sarif" => true,
+                _ => {
+                    eprintln!("Invalid format: {}. Valid values: human, sarif", format);
+                    std::process::exit(1);
+                }
+            };
+
+            let mut findings = commands::check(&config, filter, verbose)?;
+            if fix {
+                let fixed = commands::check::apply_fixes(&findings, force)?;
+                // Keep stdout parseable when emitting SARIF
+                if sarif {
+                    eprintln!("Fixed {} finding(s)", fixed);
+                } else {
+                    println!("Fixed {} finding(s)", fixed);
+                }
+                findings = commands::check(&config, filter, verbose)?;
+            }
 
-            let findings = commands::check(&config, filter, verbose)?;
-            commands::print_check_findings(&findings);
-            std::process::exit(commands::check_exit_code(&findings));
+            if sarif {
+                let root = std::env::current_dir()?;
+                println!("{}", commands::check::to_sarif(&findings, &root));
+            } else {
+                commands::print_check_findings(&findings);
+            }
+            std::process::exit(commands::check_exit_code(&findings, threshold));
+        }
+        #[cfg(feature = "graph")]
+        Commands::Graph {
+            action: Some(GraphAction::Diff { sources }),
+            ..
+        } => {
+            commands::graph::diff(&config, &sources)?;
         }
         #[cfg(feature = "graph")]
         Commands::Graph {
+            action: None,
             format,
             pipeline,
             tag,
+            exclude_tag,
+            exclude_deprecated,
+            impact,
+            around,
+            depth,
+            edges,
+            order,
+            rankdir,
+            rank_roots,
+            highlight,
+            from_roots,
+            output,
+            output_dir,
+            watch,
+            check_acyclic,
         } => {
-            let output_format = commands::graph::OutputFormat::parse_format(&format)
+            let output_formats = commands::graph::OutputFormat::parse_formats(&format)
                 .unwrap_or_else(|| {
                     eprintln!(
-                        "Invalid format: {}. Valid values: dot, text, json, mermaid",
+                        "Invalid format: {}. Valid values: dot, text, json, yaml, toml, mermaid, d2, topo, centrality, pagerank, cycles, break-cycles, hubs, mutual, stats, diamonds, html, swimlane, components, markdown",
                         format
                     );
                     std::process::exit(1);
                 });
 
-            let filter = if let Some(name) = pipeline {
-                commands::graph::GraphFilter::Pipeline(name)
-            } else if let Some(tag_name) = tag {
-                commands::graph::GraphFilter::Tag(tag_name)
-            } else {
-                commands::graph::GraphFilter::None
+            if output_formats.len() > 1 && output_dir.is_none() {
+                eprintln!("Multiple formats require --output-dir");
+                std::process::exit(1);
+            }
+            let output_format = output_formats[0];
+
+            let edge_kinds = commands::graph::parse_edge_selection(&edges).unwrap_or_else(|| {
+                eprintln!(
+                    "Invalid edges: {}. Valid values: crossref, pipeline, all",
+                    edges
+                );
+                std::process::exit(1);
+            });
+
+            let node_order = commands::graph::parse_node_order(&order).unwrap_or_else(|| {
+                eprintln!("Invalid order: {}. Valid values: alpha, topo", order);
+                std::process::exit(1);
+            });
+
+            let dot_options = loadout::graph::DotOptions {
+                rankdir: commands::graph::parse_rank_dir(&rankdir).unwrap_or_else(|| {
+                    eprintln!("Invalid rankdir: {}. Valid values: LR, TB", rankdir);
+                    std::process::exit(1);
+                }),
+                rank_roots,
+                highlight: highlight.as_deref(),
             };
 
-            commands::graph(&config, output_format, filter)?;
+            // Drop unwanted edge kinds first so the other filters see the same edges
+            let mut filters = vec![commands::graph::FilterClause::Edges(edge_kinds)];
+            if let Some(name) = pipeline {
+                filters.push(commands::graph::FilterClause::Pipeline(name));
+            }
+            if let Some(tag_name) = tag {
+                filters.push(commands::graph::FilterClause::Tag(tag_name));
+            }
+            if let Some(tag_name) = exclude_tag {
+                filters.push(commands::graph::FilterClause::ExcludeTag(tag_name));
+            }
+            if exclude_deprecated {
+                filters.push(commands::graph::FilterClause::ExcludeDeprecated);
+            }
+            if let Some(skill_name) = impact {
+                filters.push(commands::graph::FilterClause::Impact(skill_name));
+            }
+            if let Some(skill_name) = around {
+                filters.push(commands::graph::FilterClause::Around(skill_name, depth));
+            }
+            if from_roots {
+                filters.push(commands::graph::FilterClause::FromRoots);
+            }
+
+            if let Some(dir) = output_dir {
+                commands::graph::graph_to_dir(
+                    &config,
+                    &output_formats,
+                    node_order,
+                    dot_options,
+                    &filters,
+                    &dir,
+                )?;
+            } else if check_acyclic {
+                let acyclic = commands::graph::check_acyclic(&config, &filters)?;
+                std::process::exit(if acyclic { 0 } else { 1 });
+            } else if watch {
+                commands::graph::watch(
+                    &config,
+                    output_format,
+                    node_order,
+                    dot_options,
+                    &filters,
+                    output.as_deref(),
+                )?;
+            } else {
+                commands::graph(
+                    &config,
+                    output_format,
+                    node_order,
+                    dot_options,
+                    &filters,
+                    output.as_deref(),
+                )?;
+            }
         }
         Commands::List {
             groups,
+            json,
             refs,
             missing,
             tags,
+            by_agent,
             tag,
             pipelines,
             pipeline,
+            impact,
+            orphans,
+            sort,
+            show_pipelines,
+            count,
+            compatible_with,
         } => {
             let mode = if groups {
-                commands::list::ListMode::Groups
+                commands::list::ListMode::Groups { json }
             } else if let Some(skill_name) = refs {
                 commands::list::ListMode::Refs(skill_name)
             } else if missing {
                 commands::list::ListMode::Missing
             } else if tags {
                 commands::list::ListMode::Tags
+            } else if by_agent {
+                commands::list::ListMode::Agents
             } else if let Some(tag_name) = tag {
                 commands::list::ListMode::Tag(tag_name)
             } else if pipelines {
                 commands::list::ListMode::Pipelines
             } else if let Some(pipeline_name) = pipeline {
                 commands::list::ListMode::Pipeline(pipeline_name)
+            } else if let Some(skill_name) = impact {
+                commands::list::ListMode::Impact(skill_name)
+            } else if orphans {
+                commands::list::ListMode::Orphans
+            } else if count {
+                commands::list::ListMode::Count
             } else {
-                commands::list::ListMode::Default
+                let sort_key = commands::list::SortKey::parse_key(&sort).unwrap_or_else(|| {
+                    eprintln!(
+                        "Invalid sort key: {}. Valid values: name, path, source",
+                        sort
+                    );
+                    std::process::exit(1);
+                });
+                commands::list::ListMode::Default {
+                    sort: sort_key,
+                    show_pipelines,
+                }
             };
 
+            let mut config = config;
+            if let Some(target) = compatible_with {
+                commands::install::retain_compatible(&mut config, &target)?;
+            }
             commands::list(&config, mode)?;
         }
-        Commands::Validate { target } => {
-            commands::validate(&config, target)?;
+        Commands::Validate {
+            target,
+            check_config,
+            strict,
+        } => {
+            if check_config {
+                let findings = commands::validate::validate_config(&config)?;
+                commands::print_check_findings(&findings);
+                std::process::exit(commands::check_exit_code(
+                    &findings,
+                    commands::check::Severity::Error,
+                ));
+            }
+            commands::validate(&config, target, strict)?;
         }
-        Commands::New { name, description } => {
-            commands::new(&config, name, description)?;
+        Commands::New {
+            name,
+            description,
+            template,
+        } => {
+            commands::new(&config, name, description, template)?;
         }
     }
 
@@ -0,0 +1,263 @@
+//! Skill discovery inside `.tar.gz` and `.zip` archives
+//!
+//! Archived skills are read straight from the archive without extracting it.
+//! Each one gets a virtual path made by joining the archive path with the
+//! entry's path inside it, e.g. `bundle.tar.gz/draft/SKILL.md`, and carries
+//! its SKILL.md contents in memory for reference extraction.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+
+use super::{Frontmatter, Skill, SKILL_FILE_NAME};
+
+/// Check if a source path names a supported archive
+pub fn is_archive(path: &Path) -> bool {
+    let name = lowercase_name(path);
+    name.ends_with(".tar.gz") || name.ends_with(".tgz") || name.ends_with(".zip")
+}
+
+/// File name of a path in lowercase, for matching extensions
+fn lowercase_name(path: &Path) -> String {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Discover skills from the SKILL.md files inside an archive
+///
+/// Skills are returned in the order of their paths inside the archive.
+/// Entries under hidden directories are skipped, as in directory sources.
+/// A missing archive yields no skills, like a missing directory.
+pub fn discover_in_archive(archive: &Path) -> Result<Vec<Skill>> {
+    if !archive.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = if lowercase_name(archive).ends_with(".zip") {
+        read_zip(archive)?
+    } else {
+        read_tar_gz(archive)?
+    };
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut skills = Vec::new();
+    for (entry, content) in files {
+        let skill_file = archive.join(&entry);
+        match Frontmatter::parse(&content) {
+            Ok(frontmatter) => skills.push(Skill {
+                name: frontmatter.name.clone(),
+                path: skill_file.parent().unwrap_or(archive).to_path_buf(),
+                skill_file,
+                frontmatter,
+                content: Some(content),
+            }),
+            Err(e) => {
+                // Log error but continue discovering other skills
+                eprintln!(
+                    "Warning: Failed to load skill from {}: {}",
+                    skill_file.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(skills)
+}
+
+/// SKILL.md entries of a gzipped tarball as `(path inside archive, contents)`
+fn read_tar_gz(archive: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let file =
+        File::open(archive).context(format!("Failed to open archive {}", archive.display()))?;
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let mut files = Vec::new();
+
+    let entries = tar
+        .entries()
+        .context(format!("Failed to read archive {}", archive.display()))?;
+    for entry in entries {
+        let mut entry = entry.context(format!("Failed to read archive {}", archive.display()))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let Some(path) = skill_entry_path(&entry.path()?) else {
+            continue;
+        };
+
+        let mut content = String::new();
+        entry.read_to_string(&mut content).context(format!(
+            "Failed to read {} in {}",
+            path.display(),
+            archive.display()
+        ))?;
+        files.push((path, content));
+    }
+
+    Ok(files)
+}
+
+/// SKILL.md entries of a zip file as `(path inside archive, contents)`
+fn read_zip(archive: &Path) -> Result<Vec<(PathBuf, String)>> {
+    let file =
+        File::open(archive).context(format!("Failed to open archive {}", archive.display()))?;
+    let mut zip = zip::ZipArchive::new(file)
+        .context(format!("Failed to read archive {}", archive.display()))?;
+    let mut files = Vec::new();
+
+    for i in 0..zip.len() {
+        let mut entry = zip
+            .by_index(i)
+            .context(format!("Failed to read archive {}", archive.display()))?;
+        if !entry.is_file() {
+            continue;
+        }
+        let Some(path) = entry.enclosed_name().and_then(|p| skill_entry_path(&p)) else {
+            continue;
+        };
+
+        let mut content = String::new();
+        entry.read_to_string(&mut content).context(format!(
+            "Failed to read {} in {}",
+            path.display(),
+            archive.display()
+        ))?;
+        files.push((path, content));
+    }
+
+    Ok(files)
+}
+
+/// Normalized path of an archive entry if it's a SKILL.md outside hidden directories
+///
+/// `./` components are dropped, so tarballs made with `tar -C dir .` resolve
+/// the same as ones made from a directory name. Entries with `..` or an
+/// absolute path are ignored.
+fn skill_entry_path(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => {
+                if name.to_str().is_some_and(|s| s.starts_with('.')) {
+                    return None;
+                }
+                normalized.push(name);
+            }
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+
+    (normalized.file_name()? == SKILL_FILE_NAME).then_some(normalized)
+}
+
+/// Write a gzipped tarball holding `(path, contents)` files, for tests
+#[cfg(test)]
+pub(crate) fn write_tar_gz(path: &Path, files: &[(&str, &str)]) {
+    let encoder =
+        flate2::write::GzEncoder::new(File::create(path).unwrap(), flate2::Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, content.as_bytes())
+            .unwrap();
+    }
+    builder.into_inner().unwrap().finish().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    const DRAFT: &str =
+        "---\nname: draft\ndescription: Draft a post\n---\n\nUse the `edit` skill next.\n";
+    const EDIT: &str = "---\nname: edit\ndescription: Edit a post\n---\n";
+
+    #[test]
+    fn should_recognize_archive_extensions() {
+        assert!(is_archive(Path::new("skills/bundle.tar.gz")));
+        assert!(is_archive(Path::new("bundle.TGZ")));
+        assert!(is_archive(Path::new("bundle.zip")));
+        assert!(!is_archive(Path::new("skills")));
+        assert!(!is_archive(Path::new("bundle.tar")));
+    }
+
+    #[test]
+    fn should_discover_skills_from_tarball() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let archive = temp.path().join("bundle.tar.gz");
+        write_tar_gz(
+            &archive,
+            &[
+                ("./edit/SKILL.md", EDIT),
+                ("./draft/SKILL.md", DRAFT),
+                ("./draft/notes.md", "not a skill"),
+                ("./.hidden/SKILL.md", EDIT),
+            ],
+        );
+
+        // When
+        let skills = discover_in_archive(&archive).unwrap();
+
+        // Then
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["draft", "edit"]);
+        assert_eq!(skills[0].skill_file, archive.join("draft/SKILL.md"));
+        assert_eq!(skills[0].path, archive.join("draft"));
+        assert_eq!(skills[0].read_content().unwrap(), DRAFT);
+    }
+
+    #[test]
+    fn should_discover_skills_from_zip() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let archive = temp.path().join("bundle.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&archive).unwrap());
+        zip.start_file("draft/SKILL.md", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(DRAFT.as_bytes()).unwrap();
+        zip.finish().unwrap();
+
+        // When
+        let skills = discover_in_archive(&archive).unwrap();
+
+        // Then
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "draft");
+        assert_eq!(skills[0].skill_file, archive.join("draft/SKILL.md"));
+    }
+
+    #[test]
+    fn should_extract_references_from_archived_content() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let archive = temp.path().join("bundle.tgz");
+        write_tar_gz(
+            &archive,
+            &[("draft/SKILL.md", DRAFT), ("edit/SKILL.md", EDIT)],
+        );
+        let sources = vec![crate::config::SkillSource::from(archive)];
+
+        // When
+        let (_, crossrefs) =
+            crate::skill::analyze(&sources, &crate::skill::builtin_detectors(None)).unwrap();
+
+        // Then
+        let targets: Vec<&str> = crossrefs["draft"]
+            .iter()
+            .map(|r| r.target.as_str())
+            .collect();
+        assert_eq!(targets, vec!["edit"]);
+    }
+}
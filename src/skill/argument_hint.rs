@@ -0,0 +1,183 @@
+//! Parsing for the `argument-hint` frontmatter mini-syntax
+//!
+//! A hint is a whitespace-separated list of items:
+//!
+//! - `<name>` a required argument
+//! - `[name]` an optional argument (may nest, e.g. `[--out <file>]`)
+//! - `-f` or `--flag` a flag (letters, digits, `-` and `_`)
+//! - `|` separating alternatives
+//! - any other word, taken literally (e.g. a subcommand)
+
+use std::fmt;
+
+/// One item of a parsed argument hint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HintArg {
+    /// `<name>`, holding the text between the brackets
+    Required(String),
+
+    /// `[name]`, holding the text between the brackets
+    Optional(String),
+
+    /// `-f` or `--flag`, including the dashes
+    Flag(String),
+
+    /// `|` between alternative forms
+    Alternative,
+
+    /// A bare word
+    Literal(String),
+}
+
+/// A hint token that doesn't follow the grammar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HintError {
+    /// The offending token
+    pub token: String,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' ({})", self.token, self.reason)
+    }
+}
+
+/// Parse an argument hint into its items
+pub fn parse_argument_hint(hint: &str) -> Result<Vec<HintArg>, HintError> {
+    let mut args = Vec::new();
+    let mut rest = hint.trim_start();
+
+    while let Some(first) = rest.chars().next() {
+        let (arg, len) = match first {
+            '<' | '[' => parse_group(rest)?,
+            _ => parse_word(rest)?,
+        };
+        args.push(arg);
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(args)
+}
+
+/// Parse a bracketed argument at the start of `s`, returning it and its length
+fn parse_group(s: &str) -> Result<(HintArg, usize), HintError> {
+    let error = |token: &str, reason| HintError {
+        token: token.to_string(),
+        reason,
+    };
+    let mut open = Vec::new();
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '[' => open.push(c),
+            '>' | ']' => {
+                let expected = if c == '>' { '<' } else { '[' };
+                if open.pop() != Some(expected) {
+                    return Err(error(&s[..=i], "mismatched brackets"));
+                }
+                if open.is_empty() {
+                    let inner = s[1..i].trim().to_string();
+                    if inner.is_empty() {
+                        return Err(error(&s[..=i], "empty argument name"));
+                    }
+                    let arg = match c {
+                        '>' => HintArg::Required(inner),
+                        _ => HintArg::Optional(inner),
+                    };
+                    return Ok((arg, i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(error(s.trim_end(), "missing closing bracket"))
+}
+
+/// Parse a flag, `|`, or literal word at the start of `s`
+fn parse_word(s: &str) -> Result<(HintArg, usize), HintError> {
+    let len = s.find(char::is_whitespace).unwrap_or(s.len());
+    let word = &s[..len];
+    let error = |reason| HintError {
+        token: word.to_string(),
+        reason,
+    };
+
+    if word.contains(['<', '>', '[', ']']) {
+        return Err(error("unmatched bracket"));
+    }
+
+    let arg = if word == "|" {
+        HintArg::Alternative
+    } else if let Some(name) = word.strip_prefix('-') {
+        let name = name.strip_prefix('-').unwrap_or(name);
+        let valid = name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if name.is_empty() || name.starts_with('-') || !valid {
+            return Err(error("flags are -x or --name"));
+        }
+        HintArg::Flag(word.to_string())
+    } else {
+        HintArg::Literal(word.to_string())
+    };
+
+    Ok((arg, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_valid_hint() {
+        // Given
+        let hint = "add <issue-number> [priority] --force | list [--out <file>]";
+
+        // When
+        let args = parse_argument_hint(hint).unwrap();
+
+        // Then
+        assert_eq!(
+            args,
+            vec![
+                HintArg::Literal("add".to_string()),
+                HintArg::Required("issue-number".to_string()),
+                HintArg::Optional("priority".to_string()),
+                HintArg::Flag("--force".to_string()),
+                HintArg::Alternative,
+                HintArg::Literal("list".to_string()),
+                HintArg::Optional("--out <file>".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reject_mismatched_brackets() {
+        // Given
+        let hint = "<file] [mode]";
+
+        // When
+        let err = parse_argument_hint(hint).unwrap_err();
+
+        // Then
+        assert_eq!(err.token, "<file]");
+        assert_eq!(err.reason, "mismatched brackets");
+    }
+
+    #[test]
+    fn should_reject_unclosed_and_stray_brackets() {
+        // Given/When/Then
+        assert_eq!(
+            parse_argument_hint("[message").unwrap_err().token,
+            "[message"
+        );
+        assert_eq!(parse_argument_hint("file]").unwrap_err().token, "file]");
+        assert_eq!(
+            parse_argument_hint("<>").unwrap_err().reason,
+            "empty argument name"
+        );
+        assert!(parse_argument_hint("---x").is_err());
+    }
+}
@@ -0,0 +1,376 @@
+//! On-disk cache of parsed skills for repeated discovery
+//!
+//! Entries are keyed by SKILL.md path and invalidated when the file's mtime
+//! changes. Extracted references also depend on which other skills exist and
+//! which detectors run, so they are only reused while the set of known names,
+//! aliases and detectors is unchanged.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    build_alias_map, extract_references_with_filter, find_skill_dirs, qualify, CrossRef,
+    Frontmatter, ReferenceDetector, Skill, SKILL_FILE_NAME,
+};
+use crate::config::SkillSource;
+
+/// Cross-references keyed by the referring skill's name
+pub type CrossRefMap = HashMap<String, Vec<CrossRef>>;
+
+/// Contents of the cache file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cache {
+    /// Known skill names, aliases and detectors the cached references came from
+    scope: String,
+    entries: BTreeMap<PathBuf, Entry>,
+}
+
+/// Cached parse results for one SKILL.md
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    mtime: SystemTime,
+    frontmatter: Frontmatter,
+    refs: Vec<CrossRef>,
+}
+
+/// A skill being discovered, with its content if it had to be read
+struct Loaded {
+    skill: Skill,
+    mtime: SystemTime,
+    content: Option<String>,
+    cached: Option<Entry>,
+}
+
+/// Discover all skills and their cross-references, reusing a cache file
+///
+/// Behaves like `analyze` with the same `detectors`, but only reads SKILL.md
+/// files that are new or modified since the cache was written. A missing or
+/// unreadable cache is rebuilt from scratch; the cache is rewritten with
+/// exactly the skills found this time.
+pub fn discover_all_cached(
+    sources: &[SkillSource],
+    detectors: &[Box<dyn ReferenceDetector>],
+    cache_path: &Path,
+) -> Result<(Vec<Skill>, CrossRefMap)> {
+    let cache = load(cache_path);
+    let mut loaded = Vec::new();
+
+    for source in sources {
+        if !source.path.exists() {
+            continue;
+        }
+
+        // Archived skills are held in memory already, so there's nothing to reuse
+        #[cfg(feature = "archives")]
+        if super::archive::is_archive(&source.path) {
+            let mtime = fs::metadata(&source.path)
+                .and_then(|m| m.modified())
+                .context(format!("Failed to stat {}", source.path.display()))?;
+            for skill in super::archive::discover_in_archive(&source.path)? {
+                loaded.push(Loaded {
+                    content: skill.content.clone(),
+                    skill: qualify(skill, source.prefix.as_deref()),
+                    mtime,
+                    cached: None,
+                });
+            }
+            continue;
+        }
+
+        for skill_dir in find_skill_dirs(&source.path)? {
+            let skill_file = skill_dir.join(SKILL_FILE_NAME);
+            match load_skill(&skill_dir, &skill_file, &cache) {
+                Ok(mut entry) => {
+                    entry.skill = qualify(entry.skill, source.prefix.as_deref());
+                    loaded.push(entry);
+                }
+                Err(e) => {
+                    // Log error but continue discovering other skills
+                    eprintln!(
+                        "Warning: Failed to load skill from {}: {}",
+                        skill_dir.display(),
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    let skills: Vec<Skill> = loaded.iter().map(|l| l.skill.clone()).collect();
+    let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    let aliases = build_alias_map(&skills);
+    let scope = scope_key(&known_skills, &aliases, detectors);
+
+    let mut crossrefs = HashMap::new();
+    let mut fresh = Cache {
+        scope: scope.clone(),
+        entries: BTreeMap::new(),
+    };
+
+    for l in loaded {
+        let refs = match (&l.cached, &l.content) {
+            (Some(entry), None) if cache.scope == scope => entry.refs.clone(),
+            (_, content) => {
+                let content = match content {
+                    Some(content) => content.clone(),
+                    None => read(&l.skill.skill_file)?,
+                };
+                extract_references_with_filter(
+                    &content,
+                    &l.skill.name,
+                    Some(&known_skills),
+                    Some(&aliases),
+                    detectors,
+                )
+            }
+        };
+
+        fresh.entries.insert(
+            l.skill.skill_file.clone(),
+            Entry {
+                mtime: l.mtime,
+                frontmatter: l.skill.frontmatter.clone(),
+                refs: refs.clone(),
+            },
+        );
+        if !refs.is_empty() {
+            crossrefs.insert(l.skill.name.clone(), refs);
+        }
+    }
+
+    save(cache_path, &fresh)?;
+
+    Ok((skills, crossrefs))
+}
+
+/// Build a skill from its cache entry if the file is unchanged, else parse it
+fn load_skill(skill_dir: &Path, skill_file: &Path, cache: &Cache) -> Result<Loaded> {
+    let mtime = fs::metadata(skill_file)
+        .and_then(|m| m.modified())
+        .context(format!("Failed to stat {}", skill_file.display()))?;
+
+    let cached = cache
+        .entries
+        .get(skill_file)
+        .filter(|entry| entry.mtime == mtime)
+        .cloned();
+
+    let (frontmatter, content) = match &cached {
+        Some(entry) => (entry.frontmatter.clone(), None),
+        None => {
+            let content = read(skill_file)?;
+            (Frontmatter::parse(&content)?, Some(content))
+        }
+    };
+
+    Ok(Loaded {
+        skill: Skill {
+            name: frontmatter.name.clone(),
+            path: skill_dir.to_path_buf(),
+            skill_file: skill_file.to_path_buf(),
+            frontmatter,
+            content: None,
+        },
+        mtime,
+        content,
+        cached,
+    })
+}
+
+/// Fingerprint of the names references can resolve to and the detectors finding them
+fn scope_key(
+    known_skills: &HashSet<String>,
+    aliases: &HashMap<String, String>,
+    detectors: &[Box<dyn ReferenceDetector>],
+) -> String {
+    let mut names: Vec<&String> = known_skills.iter().collect();
+    names.sort();
+    let mut pairs: Vec<String> = aliases.iter().map(|(a, c)| format!("{a}={c}")).collect();
+    pairs.sort();
+    let mut ids: Vec<String> = detectors
+        .iter()
+        .map(|d| format!("detector={}", d.id()))
+        .collect();
+    ids.sort();
+
+    names
+        .into_iter()
+        .cloned()
+        .chain(pairs)
+        .chain(ids)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Read a cache file, treating a missing or corrupt one as empty
+fn load(cache_path: &Path) -> Cache {
+    fs::read_to_string(cache_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Write the cache file, creating its directory if needed
+fn save(cache_path: &Path, cache: &Cache) -> Result<()> {
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent).context(format!(
+            "Failed to create cache directory: {}",
+            parent.display()
+        ))?;
+    }
+
+    let content = serde_json::to_string(cache)?;
+    fs::write(cache_path, content).context(format!(
+        "Failed to write skill cache: {}",
+        cache_path.display()
+    ))?;
+
+    Ok(())
+}
+
+/// Read a SKILL.md file
+fn read(skill_file: &Path) -> Result<String> {
+    #[cfg(test)]
+    tests::READS.with(|reads| reads.set(reads.get() + 1));
+
+    fs::read_to_string(skill_file).context(format!("Failed to read {}", skill_file.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        /// SKILL.md reads performed by this thread
+        pub(super) static READS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn reads() -> usize {
+        READS.with(|reads| reads.get())
+    }
+
+    fn detectors() -> Vec<Box<dyn ReferenceDetector>> {
+        crate::skill::builtin_detectors(None)
+    }
+
+    // Helper to write a skill whose body references `refs` by XML crossref
+    fn write_skill(source: &Path, name: &str, refs: &[&str]) {
+        let dir = source.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        let body: String = refs
+            .iter()
+            .map(|r| format!("<see ref=\"{}\">x</see>\n", r))
+            .collect();
+        fs::write(
+            dir.join("SKILL.md"),
+            format!(
+                "---\nname: {}\ndescription: Test skill\n---\n{}",
+                name, body
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn should_skip_reading_unchanged_skills_on_second_discovery() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        write_skill(&source, "draft", &["edit"]);
+        write_skill(&source, "edit", &[]);
+        let sources = vec![source.into()];
+        let cache_path = temp.path().join("cache/skills.json");
+        let (first_skills, first_refs) =
+            discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+        let reads_before = reads();
+
+        // When
+        let (skills, crossrefs) = discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+
+        // Then
+        assert_eq!(reads(), reads_before);
+        assert_eq!(reads_before, 2);
+        assert_eq!(skills.len(), first_skills.len());
+        assert_eq!(crossrefs, first_refs);
+        assert_eq!(crossrefs["draft"][0].target, "edit");
+    }
+
+    #[test]
+    fn should_reparse_skill_whose_mtime_changed() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        write_skill(&source, "draft", &[]);
+        write_skill(&source, "edit", &[]);
+        let sources = vec![source.clone().into()];
+        let cache_path = temp.path().join("skills.json");
+        discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+
+        write_skill(&source, "draft", &["edit"]);
+        let file = fs::File::options()
+            .write(true)
+            .open(source.join("draft/SKILL.md"))
+            .unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(60))
+            .unwrap();
+        let reads_before = reads();
+
+        // When
+        let (_, crossrefs) = discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+
+        // Then
+        assert_eq!(reads() - reads_before, 1);
+        assert_eq!(crossrefs["draft"][0].target, "edit");
+    }
+
+    #[test]
+    fn should_reextract_references_when_known_skills_change() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        fs::create_dir_all(source.join("draft")).unwrap();
+        fs::write(
+            source.join("draft/SKILL.md"),
+            "---\nname: draft\ndescription: Test skill\n---\nInvoke the edit skill next.\n",
+        )
+        .unwrap();
+        let sources = vec![source.clone().into()];
+        let cache_path = temp.path().join("skills.json");
+        let (_, before) = discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+
+        // When
+        write_skill(&source, "edit", &[]);
+        let (_, after) = discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+
+        // Then
+        assert!(before.is_empty());
+        assert_eq!(after["draft"][0].target, "edit");
+    }
+
+    #[test]
+    fn should_reextract_references_when_detectors_change() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        write_skill(&source, "draft", &["edit"]);
+        write_skill(&source, "edit", &[]);
+        let sources = vec![source.into()];
+        let cache_path = temp.path().join("skills.json");
+        let (_, before) = discover_all_cached(&sources, &detectors(), &cache_path).unwrap();
+
+        // When
+        let methods: HashSet<_> = [crate::skill::DetectionMethod::BacktickContext].into();
+        let without_xml = crate::skill::builtin_detectors(Some(&methods));
+        let (_, after) = discover_all_cached(&sources, &without_xml, &cache_path).unwrap();
+
+        // Then
+        assert_eq!(before["draft"][0].target, "edit");
+        assert!(after.is_empty());
+    }
+}
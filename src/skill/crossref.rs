@@ -1,8 +1,11 @@
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+use super::normalize_name;
+
 /// A cross-reference to another skill found in SKILL.md body content
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CrossRef {
     /// The name of the referenced skill
     pub target: String,
@@ -10,10 +13,12 @@ pub struct CrossRef {
     pub line: usize,
     /// How the reference was detected
     pub method: DetectionMethod,
+    /// Whether the skill references its own name (almost always a copy-paste bug)
+    pub self_reference: bool,
 }
 
 /// Detection method for skill references
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DetectionMethod {
     /// Found in <crossrefs><see ref="..."> XML element
     XmlCrossref,
@@ -23,34 +28,216 @@ pub enum DetectionMethod {
     RelatedTable,
     /// Natural language pattern (e.g., "invoke the X skill", "load X first")
     NaturalLanguage,
+    /// Relative Markdown link to a sibling skill directory (e.g., `[build](../build/SKILL.md)`)
+    MarkdownLink,
+    /// `@skill-name` mention of a known skill
+    Mention,
+    /// Found by a [`ReferenceDetector`] outside loadout, named by that detector
+    Custom(String),
+}
+
+/// A way of finding skill references in SKILL.md content
+///
+/// Each built-in heuristic is a detector; library users can add their own by
+/// appending to [`builtin_detectors`] and passing the list to
+/// [`extract_references_with_filter`] or `skill::extract_all_references`.
+/// Detectors see the full content, code included, and report targets as
+/// written: alias resolution, lowercasing, and self-reference tagging happen
+/// afterwards.
+pub trait ReferenceDetector: Send + Sync {
+    /// Find references in `content`, the body of skill `source_name`
+    ///
+    /// `known` holds the accepted skill names and aliases when the caller
+    /// knows them, so detectors can skip names that aren't skills.
+    fn detect(
+        &self,
+        content: &str,
+        source_name: &str,
+        known: Option<&HashSet<String>>,
+    ) -> Vec<CrossRef>;
+
+    /// Identifier of the detector, for telling detector sets apart
+    ///
+    /// The discovery cache only reuses references found by the same set of
+    /// detectors. Defaults to the type name; override it if a detector's
+    /// settings change what it finds.
+    fn id(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+}
+
+/// `<crossrefs><see ref="...">` elements outside code
+pub struct XmlCrossrefDetector;
+
+impl ReferenceDetector for XmlCrossrefDetector {
+    fn detect(&self, content: &str, _: &str, _: Option<&HashSet<String>>) -> Vec<CrossRef> {
+        extract_xml_crossrefs(&mask_code(content, true))
+    }
+}
+
+/// Backtick-quoted names next to contextual words, outside fenced code
+pub struct BacktickContextDetector;
+
+impl ReferenceDetector for BacktickContextDetector {
+    fn detect(&self, content: &str, _: &str, known: Option<&HashSet<String>>) -> Vec<CrossRef> {
+        extract_backtick_context(&mask_code(content, false), known)
+    }
+}
+
+/// Rows of "Related skills" and "Integration" tables, outside fenced code
+pub struct RelatedTableDetector;
+
+impl ReferenceDetector for RelatedTableDetector {
+    fn detect(&self, content: &str, _: &str, _: Option<&HashSet<String>>) -> Vec<CrossRef> {
+        extract_related_tables(&mask_code(content, false))
+    }
+}
+
+/// Phrases like "invoke the X skill" outside code
+pub struct NaturalLanguageDetector;
+
+impl ReferenceDetector for NaturalLanguageDetector {
+    fn detect(&self, content: &str, _: &str, known: Option<&HashSet<String>>) -> Vec<CrossRef> {
+        extract_natural_language(&mask_code(content, true), known)
+    }
+}
+
+/// Relative Markdown links to sibling skill directories outside code
+pub struct MarkdownLinkDetector;
+
+impl ReferenceDetector for MarkdownLinkDetector {
+    fn detect(&self, content: &str, _: &str, known: Option<&HashSet<String>>) -> Vec<CrossRef> {
+        extract_markdown_links(&mask_code(content, true), known)
+    }
+}
+
+/// `@skill-name` mentions outside code
+pub struct MentionDetector;
+
+impl ReferenceDetector for MentionDetector {
+    fn detect(&self, content: &str, _: &str, known: Option<&HashSet<String>>) -> Vec<CrossRef> {
+        extract_mentions(&mask_code(content, true), known)
+    }
+}
+
+/// The built-in detectors for the given methods, or all of them for `None`
+pub fn builtin_detectors(
+    methods: Option<&HashSet<DetectionMethod>>,
+) -> Vec<Box<dyn ReferenceDetector>> {
+    let all: [(DetectionMethod, Box<dyn ReferenceDetector>); 6] = [
+        (DetectionMethod::XmlCrossref, Box::new(XmlCrossrefDetector)),
+        (
+            DetectionMethod::BacktickContext,
+            Box::new(BacktickContextDetector),
+        ),
+        (
+            DetectionMethod::RelatedTable,
+            Box::new(RelatedTableDetector),
+        ),
+        (
+            DetectionMethod::NaturalLanguage,
+            Box::new(NaturalLanguageDetector),
+        ),
+        (
+            DetectionMethod::MarkdownLink,
+            Box::new(MarkdownLinkDetector),
+        ),
+        (DetectionMethod::Mention, Box::new(MentionDetector)),
+    ];
+
+    all.into_iter()
+        .filter(|(method, _)| methods.is_none_or(|m| m.contains(method)))
+        .map(|(_, detector)| detector)
+        .collect()
 }
 
 /// Extract all skill references from SKILL.md body content
 ///
 /// Returns a Vec of CrossRef entries for each detected reference.
-/// Filters out self-references (when skill_name matches the reference).
+/// Self-references (when skill_name matches the reference) are kept but
+/// tagged with `self_reference`.
 /// Optionally provide known_skills to filter backtick matches to only valid skill names.
 pub fn extract_references(content: &str, skill_name: &str) -> Vec<CrossRef> {
-    extract_references_with_filter(content, skill_name, None)
+    extract_references_with_filter(content, skill_name, None, None, &builtin_detectors(None))
 }
 
 /// Extract references with optional skill name filtering for backtick context
+///
+/// `aliases` maps normalized alternative names to canonical skill names (see
+/// `build_alias_map`). When given, a reference matching an alias, or a known
+/// name spelled with different casing or separators (see [`normalize_name`]),
+/// is emitted under its canonical name.
+///
+/// A skill named `prefix/name` comes from a prefixed source; its bare
+/// references to other known `prefix/...` skills resolve to those siblings.
+///
+/// References come from running each of `detectors` in turn (see
+/// [`builtin_detectors`]).
 pub fn extract_references_with_filter(
     content: &str,
     skill_name: &str,
     known_skills: Option<&std::collections::HashSet<String>>,
+    aliases: Option<&std::collections::HashMap<String, String>>,
+    detectors: &[Box<dyn ReferenceDetector>],
 ) -> Vec<CrossRef> {
     let mut refs = Vec::new();
 
-    refs.extend(extract_xml_crossrefs(content));
-    refs.extend(extract_backtick_context(content, known_skills));
-    refs.extend(extract_related_tables(content));
-    refs.extend(extract_natural_language(content, known_skills));
+    // Siblings under the same prefix shadow aliases and unprefixed skills
+    let scoped: Option<std::collections::HashMap<String, String>> = skill_name
+        .rsplit_once('/')
+        .zip(known_skills)
+        .map(|((prefix, _), known)| {
+            let scope = format!("{}/", prefix);
+            let mut map = aliases.cloned().unwrap_or_default();
+            for name in known {
+                if let Some(bare) = name.strip_prefix(&scope) {
+                    map.insert(normalize_name(bare), name.clone());
+                }
+            }
+            map
+        });
+    let aliases = scoped.as_ref().or(aliases);
+
+    // Known names by normalized form, for resolving variant spellings
+    let canonical: Option<std::collections::HashMap<String, String>> = known_skills.map(|known| {
+        known
+            .iter()
+            .map(|name| (normalize_name(name), name.clone()))
+            .collect()
+    });
+
+    // Aliases count as known names so the heuristics keep them until resolution
+    let accepted: Option<HashSet<String>> = canonical.as_ref().map(|canonical| {
+        canonical
+            .keys()
+            .cloned()
+            .chain(aliases.into_iter().flat_map(|a| a.keys().cloned()))
+            .collect()
+    });
+    let known_skills = accepted.as_ref();
+
+    for detector in detectors {
+        refs.extend(detector.detect(content, skill_name, known_skills));
+    }
+
+    // Resolve aliases and spelling variants to canonical names
+    if let Some(canonical) = &canonical {
+        for r in &mut refs {
+            let key = normalize_name(&r.target);
+            r.target = aliases
+                .and_then(|a| a.get(&key))
+                .or_else(|| canonical.get(&key))
+                .cloned()
+                .unwrap_or(key);
+        }
+    }
 
-    // Filter out self-references
-    refs.into_iter()
-        .filter(|r| r.target != skill_name)
-        .collect()
+    // Tag self-references
+    for r in &mut refs {
+        r.self_reference = r.target == skill_name;
+    }
+
+    refs
 }
 
 /// Build a cross-reference map from skill name to set of referenced skill names
@@ -66,11 +253,60 @@ pub fn build_reference_map(
         .collect()
 }
 
+/// Blank out fenced code blocks, and optionally inline code spans
+///
+/// Fenced lines become empty and inline spans become spaces, so line numbers
+/// of everything outside code stay accurate. An unmatched backtick is left
+/// as-is, like in Markdown. Code is example material, not a reference, so the
+/// built-in detectors mask it; backtick-based ones need inline code intact and
+/// only lose fenced blocks.
+fn mask_code(content: &str, inline: bool) -> String {
+    let mut masked = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            masked.push(String::new());
+            continue;
+        }
+        if in_fence {
+            masked.push(String::new());
+            continue;
+        }
+        if !inline {
+            masked.push(line.to_string());
+            continue;
+        }
+
+        let ticks: Vec<usize> = line.match_indices('`').map(|(i, _)| i).collect();
+        let mut chars: Vec<char> = Vec::with_capacity(line.len());
+        let mut span = ticks.chunks_exact(2);
+        let mut current = span.next();
+        for (i, c) in line.char_indices() {
+            match current {
+                Some(&[start, end]) if i >= start && i <= end => {
+                    chars.push(' ');
+                    if i == end {
+                        current = span.next();
+                    }
+                }
+                _ => chars.push(c),
+            }
+        }
+        masked.push(chars.into_iter().collect());
+    }
+
+    masked.join("\n")
+}
+
 // --- Detection heuristics ---
 
 fn extract_xml_crossrefs(content: &str) -> Vec<CrossRef> {
     let mut refs = Vec::new();
-    let re = Regex::new(r#"<see\s+ref="([a-z0-9]+(?:-[a-z0-9]+)*)">"#).unwrap();
+    let re =
+        Regex::new(r#"<see\s+ref="((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)">"#)
+            .unwrap();
 
     for (line_num, line) in content.lines().enumerate() {
         for cap in re.captures_iter(line) {
@@ -79,6 +315,7 @@ fn extract_xml_crossrefs(content: &str) -> Vec<CrossRef> {
                     target: skill_name.as_str().to_string(),
                     line: line_num + 1,
                     method: DetectionMethod::XmlCrossref,
+                    self_reference: false,
                 });
             }
         }
@@ -96,7 +333,7 @@ fn extract_backtick_context(
     // Matches backtick-quoted skill names when adjacent to contextual words
     // Pattern: (skill|invoke|load|use) followed/preceded by `skill-name`
     let re = Regex::new(
-        r"(?i)\b(skill|invoke|load|use)\b[^\n`]*`([a-z0-9]+(?:-[a-z0-9]+)*)`|`([a-z0-9]+(?:-[a-z0-9]+)*)`[^\n`]*\b(skill|invoke|load|use)\b"
+        r"(?i)\b(skill|invoke|load|use)\b[^\n`]*`((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)`|`((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)`[^\n`]*\b(skill|invoke|load|use)\b"
     ).unwrap();
 
     for (line_num, line) in content.lines().enumerate() {
@@ -109,7 +346,7 @@ fn extract_backtick_context(
                 // If known_skills provided, only include if it's a known skill
                 // Otherwise include all matches (backward compatibility)
                 if let Some(known) = known_skills {
-                    if !known.contains(name_str) {
+                    if !known.contains(&normalize_name(name_str)) {
                         continue;
                     }
                 }
@@ -118,6 +355,7 @@ fn extract_backtick_context(
                     target: name_str.to_string(),
                     line: line_num + 1,
                     method: DetectionMethod::BacktickContext,
+                    self_reference: false,
                 });
             }
         }
@@ -129,7 +367,8 @@ fn extract_backtick_context(
 fn extract_related_tables(content: &str) -> Vec<CrossRef> {
     let mut refs = Vec::new();
     let mut in_related_section = false;
-    let skill_pattern = Regex::new(r"`([a-z0-9]+(?:-[a-z0-9]+)*)`").unwrap();
+    let skill_pattern =
+        Regex::new(r"`((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)`").unwrap();
 
     for (line_num, line) in content.lines().enumerate() {
         let line_lower = line.to_lowercase();
@@ -153,6 +392,7 @@ fn extract_related_tables(content: &str) -> Vec<CrossRef> {
                         target: name.as_str().to_string(),
                         line: line_num + 1,
                         method: DetectionMethod::RelatedTable,
+                        self_reference: false,
                     });
                 }
             }
@@ -171,10 +411,10 @@ fn extract_natural_language(
     // Patterns: "invoke the X skill", "load X first", "use X skill", etc.
     // Case-insensitive to handle "Load voice first" and "load voice first"
     let patterns = [
-        r"(?i)invoke\s+(?:the\s+)?([a-z0-9]+(?:-[a-z0-9]+)*)\s+skill",
-        r"(?i)load\s+([a-z0-9]+(?:-[a-z0-9]+)*)\s+(?:first|skill)",
-        r"(?i)use\s+(?:the\s+)?([a-z0-9]+(?:-[a-z0-9]+)*)\s+skill",
-        r"(?i)invoke\s+([a-z0-9]+(?:-[a-z0-9]+)*)\s+on",
+        r"(?i)invoke\s+(?:the\s+)?((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)\s+skill",
+        r"(?i)load\s+((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)\s+(?:first|skill)",
+        r"(?i)use\s+(?:the\s+)?((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)\s+skill",
+        r"(?i)invoke\s+((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)\s+on",
     ];
 
     for pattern in &patterns {
@@ -186,7 +426,7 @@ fn extract_natural_language(
 
                     // If known_skills provided, only include if it's a known skill
                     if let Some(known) = known_skills {
-                        if !known.contains(name_str) {
+                        if !known.contains(&normalize_name(name_str)) {
                             continue;
                         }
                     }
@@ -195,6 +435,7 @@ fn extract_natural_language(
                         target: name_str.to_string(),
                         line: line_num + 1,
                         method: DetectionMethod::NaturalLanguage,
+                        self_reference: false,
                     });
                 }
             }
@@ -204,6 +445,86 @@ fn extract_natural_language(
     refs
 }
 
+fn extract_markdown_links(
+    content: &str,
+    known_skills: Option<&std::collections::HashSet<String>>,
+) -> Vec<CrossRef> {
+    let mut refs = Vec::new();
+
+    // Matches [text](../skill-name), [text](../skill-name/) and
+    // [text](../skill-name/SKILL.md), optionally with a #fragment
+    let re = Regex::new(
+        r"\[[^\]]*\]\((?:\./)?\.\./([a-z0-9]+(?:-[a-z0-9]+)*)(?:/|/SKILL\.md)?(?:#[^)\s]*)?\)",
+    )
+    .unwrap();
+
+    for (line_num, line) in content.lines().enumerate() {
+        for cap in re.captures_iter(line) {
+            if let Some(name) = cap.get(1) {
+                let name_str = name.as_str();
+
+                // If known_skills provided, only include if it's a known skill
+                if let Some(known) = known_skills {
+                    if !known.contains(&normalize_name(name_str)) {
+                        continue;
+                    }
+                }
+
+                refs.push(CrossRef {
+                    target: name_str.to_string(),
+                    line: line_num + 1,
+                    method: DetectionMethod::MarkdownLink,
+                    self_reference: false,
+                });
+            }
+        }
+    }
+
+    refs
+}
+
+fn extract_mentions(
+    content: &str,
+    known_skills: Option<&std::collections::HashSet<String>>,
+) -> Vec<CrossRef> {
+    let mut refs = Vec::new();
+
+    // Without a known-skill set, @handles can't be told apart from references
+    let Some(known) = known_skills else {
+        return refs;
+    };
+
+    let re = Regex::new(r"@((?:[a-z0-9]+(?:-[a-z0-9]+)*/)?[a-z0-9]+(?:-[a-z0-9]+)*)").unwrap();
+
+    for (line_num, line) in content.lines().enumerate() {
+        for cap in re.captures_iter(line) {
+            let (Some(whole), Some(name)) = (cap.get(0), cap.get(1)) else {
+                continue;
+            };
+
+            // Skip emails and dotted handles: user@skill, @skill.com, @skill_x
+            let before = line[..whole.start()].chars().next_back();
+            let after = line[whole.end()..].chars().next();
+            if before.is_some_and(|c| c.is_alphanumeric() || "_.@".contains(c))
+                || after.is_some_and(|c| c.is_alphanumeric() || "_.@".contains(c))
+            {
+                continue;
+            }
+
+            if known.contains(&normalize_name(name.as_str())) {
+                refs.push(CrossRef {
+                    target: name.as_str().to_string(),
+                    line: line_num + 1,
+                    method: DetectionMethod::Mention,
+                    self_reference: false,
+                });
+            }
+        }
+    }
+
+    refs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,7 +630,293 @@ mod tests {
     }
 
     #[test]
-    fn should_filter_self_references() {
+    fn should_extract_markdown_link_with_skill_file() {
+        // Given
+        let content = "Before shipping, [see build](../build/SKILL.md) for the steps.";
+
+        // When
+        let refs = extract_markdown_links(content, None);
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "build");
+        assert_eq!(refs[0].method, DetectionMethod::MarkdownLink);
+    }
+
+    #[test]
+    fn should_extract_markdown_link_to_skill_directory() {
+        // Given
+        let content =
+            "Line 1\nSee [the release skill](../release-notes/) and [docs](https://example.com/x)";
+        let mut known = std::collections::HashSet::new();
+        known.insert("release-notes".to_string());
+
+        // When
+        let refs = extract_markdown_links(content, Some(&known));
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "release-notes");
+        assert_eq!(refs[0].line, 2);
+    }
+
+    #[test]
+    fn should_skip_markdown_link_to_unknown_skill() {
+        // Given
+        let content = "[old](../retired-skill/SKILL.md)";
+        let known = std::collections::HashSet::new();
+
+        // When
+        let refs = extract_markdown_links(content, Some(&known));
+
+        // Then
+        assert!(refs.is_empty());
+    }
+
+    #[test]
+    fn should_skip_disabled_detection_methods() {
+        // Given: one XML reference and one Markdown link
+        let content =
+            "<crossrefs><see ref=\"voice\">Tone</see></crossrefs>\nSee [build](../build/SKILL.md).";
+        let known: HashSet<String> = ["voice", "build"].iter().map(|s| s.to_string()).collect();
+        let config = crate::config::DetectionConfig {
+            markdown_link: false,
+            ..Default::default()
+        };
+
+        // When
+        let refs = extract_references_with_filter(
+            content,
+            "draft",
+            Some(&known),
+            None,
+            &config.detectors(),
+        );
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "voice");
+        assert_eq!(refs[0].method, DetectionMethod::XmlCrossref);
+    }
+
+    #[test]
+    fn should_run_custom_detector_alongside_builtins() {
+        // Given: a detector for `%% uses NAME` lines
+        struct PercentDetector;
+        impl ReferenceDetector for PercentDetector {
+            fn detect(&self, content: &str, _: &str, _: Option<&HashSet<String>>) -> Vec<CrossRef> {
+                content
+                    .lines()
+                    .enumerate()
+                    .filter_map(|(i, line)| {
+                        Some(CrossRef {
+                            target: line.strip_prefix("%% uses ")?.to_string(),
+                            line: i + 1,
+                            method: DetectionMethod::Custom("percent".to_string()),
+                            self_reference: false,
+                        })
+                    })
+                    .collect()
+            }
+        }
+        let content = "<crossrefs><see ref=\"voice\">Tone</see></crossrefs>\n%% uses Build";
+        let known: HashSet<String> = ["voice", "build"].iter().map(|s| s.to_string()).collect();
+        let mut detectors = builtin_detectors(None);
+        detectors.push(Box::new(PercentDetector));
+
+        // When
+        let refs = extract_references_with_filter(content, "draft", Some(&known), None, &detectors);
+
+        // Then: custom refs are resolved like built-in ones
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].method, DetectionMethod::XmlCrossref);
+        assert_eq!(refs[1].target, "build");
+        assert_eq!(refs[1].line, 2);
+        assert_eq!(
+            refs[1].method,
+            DetectionMethod::Custom("percent".to_string())
+        );
+    }
+
+    #[test]
+    fn should_extract_mention_of_known_skill() {
+        // Given
+        let content = "Intro\nHand the draft to @blog-edit, then mail ops@blog-edit.";
+        let mut known = std::collections::HashSet::new();
+        known.insert("blog-edit".to_string());
+
+        // When
+        let refs = extract_mentions(content, Some(&known));
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "blog-edit");
+        assert_eq!(refs[0].line, 2);
+        assert_eq!(refs[0].method, DetectionMethod::Mention);
+    }
+
+    #[test]
+    fn should_ignore_mention_in_code_fence() {
+        // Given
+        let content = "```\n@blog-edit\n```\n";
+        let mut known = std::collections::HashSet::new();
+        known.insert("blog-edit".to_string());
+
+        // When
+        let refs = extract_references_with_filter(
+            content,
+            "other",
+            Some(&known),
+            None,
+            &builtin_detectors(None),
+        );
+
+        // Then
+        assert!(refs.is_empty());
+    }
+
+    #[test]
+    fn should_ignore_mention_of_unknown_skill() {
+        // Given
+        let content = "Ping @someone about it";
+        let mut known = std::collections::HashSet::new();
+        known.insert("blog-edit".to_string());
+
+        // When
+        let refs = extract_mentions(content, Some(&known));
+
+        // Then
+        assert!(refs.is_empty());
+    }
+
+    #[test]
+    fn should_ignore_crossref_inside_code_fence() {
+        // Given
+        let content = "Example:\n```xml\n<see ref=\"foo\">Example</see>\n```\n";
+
+        // When
+        let refs = extract_references(content, "my-skill");
+
+        // Then
+        assert!(refs.is_empty());
+    }
+
+    #[test]
+    fn should_keep_crossref_outside_code_with_accurate_line() {
+        // Given
+        let content = "```\n<see ref=\"foo\">x</see>\n```\nWrite `<see ref=\"bar\">` tags.\n<see ref=\"baz\">Real</see>\n";
+
+        // When
+        let refs = extract_references(content, "my-skill");
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "baz");
+        assert_eq!(refs[0].line, 5);
+    }
+
+    #[test]
+    fn should_mask_inline_code_but_keep_unmatched_backtick() {
+        // Given
+        let line = "a `b` c ` d";
+
+        // When
+        let masked = mask_code(line, true);
+
+        // Then
+        assert_eq!(masked, "a     c ` d");
+    }
+
+    #[test]
+    fn should_resolve_alias_to_canonical_name() {
+        // Given
+        let content = "Invoke the blog-editor skill, then <see ref=\"copyedit\">polish</see>.";
+        let known: HashSet<String> = ["blog-edit".to_string()].into_iter().collect();
+        let mut aliases = std::collections::HashMap::new();
+        aliases.insert("blog-editor".to_string(), "blog-edit".to_string());
+        aliases.insert("copyedit".to_string(), "blog-edit".to_string());
+
+        // When
+        let refs = extract_references_with_filter(
+            content,
+            "draft",
+            Some(&known),
+            Some(&aliases),
+            &builtin_detectors(None),
+        );
+
+        // Then
+        assert_eq!(refs.len(), 2);
+        assert!(refs.iter().all(|r| r.target == "blog-edit"));
+    }
+
+    #[test]
+    fn should_resolve_reference_differing_only_in_case() {
+        // Given
+        let content = "Load Voice first before editing articles";
+        let known: HashSet<String> = ["voice".to_string()].into_iter().collect();
+
+        // When
+        let refs = extract_references_with_filter(
+            content,
+            "draft",
+            Some(&known),
+            None,
+            &builtin_detectors(None),
+        );
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "voice");
+    }
+
+    #[test]
+    fn should_resolve_alias_declared_with_other_separators() {
+        // Given: the alias is declared as Blog_Editor
+        let content = "Invoke the blog-editor skill first";
+        let known: HashSet<String> = ["blog-edit".to_string()].into_iter().collect();
+        let mut aliases = std::collections::HashMap::new();
+        aliases.insert(normalize_name("Blog_Editor"), "blog-edit".to_string());
+
+        // When
+        let refs = extract_references_with_filter(
+            content,
+            "draft",
+            Some(&known),
+            Some(&aliases),
+            &builtin_detectors(None),
+        );
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "blog-edit");
+    }
+
+    #[test]
+    fn should_resolve_bare_references_within_source_prefix() {
+        // Given
+        let content = "Use the `foo` skill here.\nThen load b/foo first.";
+        let known: HashSet<String> = ["a/foo", "a/bar", "b/foo", "foo"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        // When
+        let refs = extract_references_with_filter(
+            content,
+            "a/bar",
+            Some(&known),
+            None,
+            &builtin_detectors(None),
+        );
+
+        // Then
+        let targets: Vec<&str> = refs.iter().map(|r| r.target.as_str()).collect();
+        assert_eq!(targets, vec!["a/foo", "b/foo"]);
+    }
+
+    #[test]
+    fn should_tag_self_references() {
         // Given
         let content = r#"
   <crossrefs>
@@ -322,8 +929,11 @@ mod tests {
         let refs = extract_references(content, "skill-craft");
 
         // Then
-        assert_eq!(refs.len(), 1);
-        assert_eq!(refs[0].target, "other-skill");
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].target, "skill-craft");
+        assert!(refs[0].self_reference);
+        assert_eq!(refs[1].target, "other-skill");
+        assert!(!refs[1].self_reference);
     }
 
     #[test]
@@ -337,11 +947,13 @@ mod tests {
                         target: "skill-b".to_string(),
                         line: 1,
                         method: DetectionMethod::XmlCrossref,
+                        self_reference: false,
                     },
                     CrossRef {
                         target: "skill-c".to_string(),
                         line: 2,
                         method: DetectionMethod::XmlCrossref,
+                        self_reference: false,
                     },
                 ],
             ),
@@ -12,8 +12,56 @@ use thiserror::Error;
 const NAME_PATTERN: &str = r"^[a-z0-9]+(-[a-z0-9]+)*$";
 const MIN_NAME_LENGTH: usize = 1;
 const MAX_NAME_LENGTH: usize = 64;
-const MIN_DESCRIPTION_LENGTH: usize = 1;
-const MAX_DESCRIPTION_LENGTH: usize = 1024;
+/// Default longest `description` accepted without a warning, in chars
+pub const DEFAULT_MAX_DESCRIPTION_LENGTH: usize = 1024;
+const TOOL_NAME_PATTERN: &str = r"^[A-Za-z][A-Za-z0-9_]*$";
+
+/// Tool names recognized in the `allowed-tools` field
+///
+/// MCP tools (`mcp__<server>__<tool>`) are accepted without being listed.
+pub const KNOWN_TOOLS: &[&str] = &[
+    "Bash",
+    "BashOutput",
+    "Edit",
+    "Glob",
+    "Grep",
+    "KillShell",
+    "LS",
+    "MultiEdit",
+    "NotebookEdit",
+    "NotebookRead",
+    "Read",
+    "Skill",
+    "SlashCommand",
+    "Task",
+    "TodoWrite",
+    "WebFetch",
+    "WebSearch",
+    "Write",
+];
+
+/// A problem with a single `allowed-tools` entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolIssue {
+    /// The entry doesn't follow the `Tool` or `Tool(args)` grammar
+    Malformed { entry: String, reason: &'static str },
+
+    /// The entry is well-formed but names a tool not in [`KNOWN_TOOLS`]
+    Unknown {
+        entry: String,
+        suggestion: Option<&'static str>,
+    },
+}
+
+/// A problem with a skill's `description`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptionIssue {
+    /// The description is empty or whitespace only
+    Empty,
+
+    /// The description is longer than the configured limit
+    TooLong { len: usize, max: usize },
+}
 
 /// Errors that can occur during frontmatter parsing and validation
 #[derive(Error, Debug)]
@@ -33,9 +81,6 @@ pub enum FrontmatterError {
     #[error("Invalid skill name length: {0} (must be {MIN_NAME_LENGTH}-{MAX_NAME_LENGTH} chars)")]
     InvalidNameLength(usize),
 
-    #[error("Invalid description length: {0} (must be {MIN_DESCRIPTION_LENGTH}-{MAX_DESCRIPTION_LENGTH} chars)")]
-    InvalidDescriptionLength(usize),
-
     #[error("Skill name '{found}' does not match directory name '{expected}'")]
     NameMismatch { expected: String, found: String },
 
@@ -70,6 +115,22 @@ pub struct PipelineStage {
     pub before: Option<Vec<String>>,
 }
 
+/// Deprecation notice for a skill that is being phased out
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeprecationInfo {
+    /// Version or date the skill was deprecated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+
+    /// Skill to use instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+
+    /// Why the skill is deprecated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 /// SKILL.md frontmatter
 ///
 /// This struct represents the union of all supported frontmatter fields
@@ -112,6 +173,7 @@ pub struct Frontmatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub license: Option<String>,
 
+    /// Comma-separated target names the skill supports, e.g. `claude-code, opencode`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
 
@@ -123,9 +185,17 @@ pub struct Frontmatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
 
+    /// Alternative names that references may use for this skill (matched case-insensitively)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+
     /// Pipeline/workflow participation with stage ordering
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline: Option<HashMap<String, PipelineStage>>,
+
+    /// Marks the skill as being phased out, optionally naming a replacement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<DeprecationInfo>,
 }
 
 impl Frontmatter {
@@ -149,10 +219,37 @@ impl Frontmatter {
         Ok(frontmatter)
     }
 
+    /// Whether `compatibility` lists `target` (case-insensitively)
+    ///
+    /// Skills that declare no compatibility are compatible with every target.
+    pub fn is_compatible_with(&self, target: &str) -> bool {
+        self.compatibility.as_ref().is_none_or(|declared| {
+            declared
+                .split(',')
+                .any(|name| name.trim().eq_ignore_ascii_case(target.trim()))
+        })
+    }
+
+    /// Frontmatter keys in SKILL.md content that no field accepts
+    ///
+    /// Parsing ignores such keys; this reports them by dotted path (e.g.
+    /// `descriptoin` or `pipeline.blog.stge`) so typos can be caught.
+    pub fn unknown_fields(content: &str) -> Result<Vec<String>> {
+        let yaml_content = extract_yaml(content)?;
+
+        let mut unknown = Vec::new();
+        let _: Frontmatter =
+            serde_ignored::deserialize(serde_yaml::Deserializer::from_str(&yaml_content), |path| {
+                unknown.push(path.to_string())
+            })
+            .map_err(|e| FrontmatterError::InvalidYaml(e.to_string()))?;
+
+        Ok(unknown)
+    }
+
     /// Validate frontmatter fields
     pub fn validate(&self) -> Result<()> {
         self.validate_name()?;
-        self.validate_description()?;
         self.validate_tags()?;
         self.validate_pipeline()?;
         Ok(())
@@ -215,14 +312,32 @@ impl Frontmatter {
         Ok(())
     }
 
-    /// Validate the description
-    fn validate_description(&self) -> Result<()> {
-        let desc_len = self.description.trim().len();
-        if !(MIN_DESCRIPTION_LENGTH..=MAX_DESCRIPTION_LENGTH).contains(&desc_len) {
-            return Err(FrontmatterError::InvalidDescriptionLength(desc_len).into());
+    /// Check the description is present and at most `max_len` chars
+    ///
+    /// Leading and trailing whitespace is not counted.
+    pub fn description_issue(&self, max_len: usize) -> Option<DescriptionIssue> {
+        let len = self.description.trim().chars().count();
+        if len == 0 {
+            Some(DescriptionIssue::Empty)
+        } else if len > max_len {
+            Some(DescriptionIssue::TooLong { len, max: max_len })
+        } else {
+            None
         }
+    }
 
-        Ok(())
+    /// Check each `allowed-tools` entry against the tool grammar and known tools
+    ///
+    /// Entries are separated by commas or whitespace outside parentheses.
+    pub fn allowed_tools_issues(&self) -> Vec<ToolIssue> {
+        let Some(allowed) = &self.allowed_tools else {
+            return Vec::new();
+        };
+
+        split_tool_entries(allowed)
+            .into_iter()
+            .filter_map(|entry| check_tool_entry(&entry))
+            .collect()
     }
 
     /// Validate that frontmatter name matches the expected directory name
@@ -238,6 +353,107 @@ impl Frontmatter {
     }
 }
 
+/// Split an `allowed-tools` value into entries at top-level commas and whitespace
+fn split_tool_entries(allowed: &str) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+
+    for c in allowed.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if depth == 0 && (c == ',' || c.is_whitespace()) {
+            if !current.is_empty() {
+                entries.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        entries.push(current);
+    }
+
+    entries
+}
+
+/// Check one `Tool` or `Tool(args)` entry, returning the issue if any
+fn check_tool_entry(entry: &str) -> Option<ToolIssue> {
+    let malformed = |reason| {
+        Some(ToolIssue::Malformed {
+            entry: entry.to_string(),
+            reason,
+        })
+    };
+
+    let name = match entry.find('(') {
+        Some(open) => {
+            if !entry.ends_with(')') {
+                return malformed("expected ')' at end of entry");
+            }
+            let args = &entry[open + 1..entry.len() - 1];
+            if args.trim().is_empty() {
+                return malformed("empty argument list");
+            }
+            let mut depth = 0i32;
+            for c in args.chars() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                if depth < 0 {
+                    return malformed("unbalanced parentheses");
+                }
+            }
+            if depth != 0 {
+                return malformed("unbalanced parentheses");
+            }
+            &entry[..open]
+        }
+        None if entry.contains(')') => return malformed("unbalanced parentheses"),
+        None => entry,
+    };
+
+    let re = Regex::new(TOOL_NAME_PATTERN).unwrap();
+    if !re.is_match(name) {
+        return malformed("tool name must be alphanumeric and start with a letter");
+    }
+
+    if KNOWN_TOOLS.contains(&name) || name.starts_with("mcp__") {
+        return None;
+    }
+
+    Some(ToolIssue::Unknown {
+        entry: entry.to_string(),
+        suggestion: KNOWN_TOOLS
+            .iter()
+            .find(|known| known.eq_ignore_ascii_case(name))
+            .copied(),
+    })
+}
+
+/// Markdown body after the closing --- delimiter
+///
+/// Content without a complete frontmatter block is returned whole.
+pub(crate) fn strip_frontmatter(content: &str) -> &str {
+    let mut offset = 0;
+    let mut delimiters = 0;
+    for line in content.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim() == "---" {
+            delimiters += 1;
+            if delimiters == 2 {
+                return &content[offset..];
+            }
+        }
+    }
+    content
+}
+
 /// Extract YAML content between --- delimiters
 fn extract_yaml(content: &str) -> Result<String> {
     let lines: Vec<&str> = content.lines().collect();
@@ -411,18 +627,94 @@ name: my-skill
     }
 
     #[test]
-    fn should_validate_description_length() {
-        // Given - description too long (1025 chars)
+    fn should_match_declared_compatibility_targets() {
+        // Given
+        let content =
+            "---\nname: test\ndescription: Test\ncompatibility: claude-code, OpenCode\n---";
+        let frontmatter = Frontmatter::parse(content).unwrap();
+
+        // When/Then
+        assert!(frontmatter.is_compatible_with("claude-code"));
+        assert!(frontmatter.is_compatible_with("opencode"));
+        assert!(!frontmatter.is_compatible_with("codex"));
+        assert!(!frontmatter.is_compatible_with("claude"));
+    }
+
+    #[test]
+    fn should_treat_missing_compatibility_as_universal() {
+        // Given
+        let frontmatter = Frontmatter::parse("---\nname: test\ndescription: Test\n---").unwrap();
+
+        // When/Then
+        assert!(frontmatter.is_compatible_with("codex"));
+    }
+
+    #[test]
+    fn should_report_unknown_frontmatter_fields() {
+        // Given
+        let content = "---\nname: test\ndescription: Test\nfoo: bar\n---\nBody\n";
+
+        // When
+        let unknown = Frontmatter::unknown_fields(content).unwrap();
+
+        // Then
+        assert_eq!(unknown, vec!["foo"]);
+        assert!(Frontmatter::parse(content).is_ok());
+    }
+
+    #[test]
+    fn should_report_no_unknown_fields_for_known_keys() {
+        // Given
+        let content = "---\nname: test\ndescription: Test\ntags: [blog]\n---\n";
+
+        // When/Then
+        assert!(Frontmatter::unknown_fields(content).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_report_description_over_limit() {
+        // Given - description too long (1025 chars) still parses
         let long_desc = "a".repeat(1025);
         let content = format!("---\nname: test\ndescription: {}\n---", long_desc);
+        let frontmatter = Frontmatter::parse(&content).unwrap();
 
         // When
-        let result = Frontmatter::parse(&content);
+        let issue = frontmatter.description_issue(DEFAULT_MAX_DESCRIPTION_LENGTH);
 
         // Then
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(err.to_string().contains("description length"));
+        assert_eq!(
+            issue,
+            Some(DescriptionIssue::TooLong {
+                len: 1025,
+                max: 1024
+            })
+        );
+    }
+
+    #[test]
+    fn should_report_empty_description() {
+        // Given
+        let frontmatter = Frontmatter::parse("---\nname: test\ndescription: \"  \"\n---").unwrap();
+
+        // When/Then
+        assert_eq!(
+            frontmatter.description_issue(DEFAULT_MAX_DESCRIPTION_LENGTH),
+            Some(DescriptionIssue::Empty)
+        );
+    }
+
+    #[test]
+    fn should_accept_description_within_limit() {
+        // Given
+        let frontmatter =
+            Frontmatter::parse("---\nname: test\ndescription: Drafts posts\n---").unwrap();
+
+        // When/Then
+        assert_eq!(frontmatter.description_issue(12), None);
+        assert_eq!(
+            frontmatter.description_issue(11),
+            Some(DescriptionIssue::TooLong { len: 12, max: 11 })
+        );
     }
 
     #[test]
@@ -677,4 +969,57 @@ description: A test skill
         let err = result.unwrap_err();
         assert!(err.to_string().contains("order must be >= 1"));
     }
+
+    #[test]
+    fn should_accept_known_and_mcp_allowed_tools() {
+        // Given
+        let content = "---\nname: my-skill\ndescription: Test\nallowed-tools: Read, Grep Bash(git add:*) mcp__github__search\n---\n";
+
+        // When
+        let frontmatter = Frontmatter::parse(content).unwrap();
+
+        // Then
+        assert!(frontmatter.allowed_tools_issues().is_empty());
+    }
+
+    #[test]
+    fn should_flag_unknown_tool_with_case_suggestion() {
+        // Given
+        let content =
+            "---\nname: my-skill\ndescription: Test\nallowed-tools: bash(ls), Teleport\n---\n";
+
+        // When
+        let issues = Frontmatter::parse(content).unwrap().allowed_tools_issues();
+
+        // Then
+        assert_eq!(
+            issues,
+            vec![
+                ToolIssue::Unknown {
+                    entry: "bash(ls)".to_string(),
+                    suggestion: Some("Bash"),
+                },
+                ToolIssue::Unknown {
+                    entry: "Teleport".to_string(),
+                    suggestion: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_flag_malformed_tool_entries() {
+        // Given
+        let content =
+            "---\nname: my-skill\ndescription: Test\nallowed-tools: Read(), 9Tool, Bash(ls\n---\n";
+
+        // When
+        let issues = Frontmatter::parse(content).unwrap().allowed_tools_issues();
+
+        // Then
+        assert_eq!(issues.len(), 3);
+        assert!(issues
+            .iter()
+            .all(|issue| matches!(issue, ToolIssue::Malformed { .. })));
+    }
 }
@@ -1,22 +1,40 @@
 //! Skill discovery, resolution, and frontmatter validation
 
+#[cfg(feature = "archives")]
+pub mod archive;
+pub mod argument_hint;
+pub mod cache;
 pub mod crossref;
 pub mod frontmatter;
+pub mod sections;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use thiserror::Error;
 use walkdir::{DirEntry, WalkDir};
 
+use crate::config::SkillSource;
+
+pub use argument_hint::{parse_argument_hint, HintArg, HintError};
+pub use cache::{discover_all_cached, CrossRefMap};
 pub use crossref::{
-    build_reference_map, extract_references, extract_references_with_filter, CrossRef,
-    DetectionMethod,
+    build_reference_map, builtin_detectors, extract_references, extract_references_with_filter,
+    BacktickContextDetector, CrossRef, DetectionMethod, MarkdownLinkDetector, MentionDetector,
+    NaturalLanguageDetector, ReferenceDetector, RelatedTableDetector, XmlCrossrefDetector,
+};
+pub use frontmatter::{
+    DeprecationInfo, DescriptionIssue, Frontmatter, PipelineStage, ToolIssue,
+    DEFAULT_MAX_DESCRIPTION_LENGTH, KNOWN_TOOLS,
 };
-pub use frontmatter::{Frontmatter, PipelineStage};
 
-const SKILL_FILE_NAME: &str = "SKILL.md";
+pub(crate) const SKILL_FILE_NAME: &str = "SKILL.md";
+
+/// Gitignore-syntax file at a source root listing directories to skip
+const SKILL_IGNORE_FILE_NAME: &str = ".skillignore";
 
 /// Errors that can occur during skill resolution
 #[derive(Error, Debug)]
@@ -32,12 +50,18 @@ pub enum SkillError {
         path: PathBuf,
         source: walkdir::Error,
     },
+
+    #[error("Invalid ignore file {path}: {source}")]
+    IgnoreFile {
+        path: PathBuf,
+        source: ignore::Error,
+    },
 }
 
 /// A discovered skill with its metadata
 #[derive(Debug, Clone)]
 pub struct Skill {
-    /// The skill name (from frontmatter)
+    /// The skill name (from frontmatter, qualified by the source prefix if any)
     pub name: String,
 
     /// Path to the skill directory (containing SKILL.md)
@@ -48,6 +72,11 @@ pub struct Skill {
 
     /// Parsed frontmatter
     pub frontmatter: Frontmatter,
+
+    /// SKILL.md contents held in memory, for skills read from an archive
+    ///
+    /// `None` for skills on disk, whose `skill_file` is read on demand.
+    pub content: Option<String>,
 }
 
 impl Skill {
@@ -59,37 +88,70 @@ impl Skill {
             return Err(SkillError::MissingSkillFile(path.to_path_buf()).into());
         }
 
+        // A name that differs from the directory is reported by `validate` and
+        // `check` rather than hiding the skill from discovery
         let frontmatter = Frontmatter::from_file(&skill_file)?;
 
-        // Validate that the directory name matches the skill name
-        if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
-            frontmatter.validate_directory_name(dir_name)?;
-        }
-
         Ok(Skill {
             name: frontmatter.name.clone(),
             path: path.to_path_buf(),
             skill_file,
             frontmatter,
+            content: None,
         })
     }
+
+    /// Markdown sections of the SKILL.md body as `(heading_level, heading_text, body)`
+    ///
+    /// The frontmatter is stripped first; see [`sections::parse_sections`].
+    pub fn sections(&self) -> Result<Vec<(u8, String, String)>> {
+        let content = self.read_content()?;
+        Ok(sections::parse_sections(frontmatter::strip_frontmatter(
+            &content,
+        )))
+    }
+
+    /// The SKILL.md contents, from memory for archived skills or else from disk
+    pub fn read_content(&self) -> Result<String> {
+        match &self.content {
+            Some(content) => Ok(content.clone()),
+            None => fs::read_to_string(&self.skill_file)
+                .context(format!("Failed to read {}", self.skill_file.display())),
+        }
+    }
 }
 
 /// Walk source directories to discover all skills
 ///
 /// Skills are discovered by recursively walking each source directory
 /// looking for directories containing SKILL.md files.
-pub fn discover_all(sources: &[PathBuf]) -> Result<Vec<Skill>> {
+pub fn discover_all(sources: &[SkillSource]) -> Result<Vec<Skill>> {
     let mut skills = Vec::new();
 
     for source in sources {
-        let discovered = discover_in_directory(source)?;
+        let discovered = discover_in_source(source)?;
         skills.extend(discovered);
     }
 
     Ok(skills)
 }
 
+/// Discover skills within a configured source, applying its prefix
+pub fn discover_in_source(source: &SkillSource) -> Result<Vec<Skill>> {
+    #[cfg(feature = "archives")]
+    let skills = if archive::is_archive(&source.path) {
+        archive::discover_in_archive(&source.path)?
+    } else {
+        discover_in_directory(&source.path)?
+    };
+    #[cfg(not(feature = "archives"))]
+    let skills = discover_in_directory(&source.path)?;
+    Ok(skills
+        .into_iter()
+        .map(|skill| qualify(skill, source.prefix.as_deref()))
+        .collect())
+}
+
 /// Discover skills within a single source directory
 pub fn discover_in_directory(source: &Path) -> Result<Vec<Skill>> {
     if !source.exists() {
@@ -99,12 +161,28 @@ pub fn discover_in_directory(source: &Path) -> Result<Vec<Skill>> {
 
     let mut skills = Vec::new();
 
-    let walker = WalkDir::new(source)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(is_not_hidden);
+    for skill_dir in find_skill_dirs(source)? {
+        match Skill::from_directory(&skill_dir) {
+            Ok(skill) => skills.push(skill),
+            Err(e) => {
+                // Log error but continue discovering other skills
+                eprintln!(
+                    "Warning: Failed to load skill from {}: {}",
+                    skill_dir.display(),
+                    e
+                );
+            }
+        }
+    }
 
-    for entry in walker {
+    Ok(skills)
+}
+
+/// Directories containing a SKILL.md within a source, in walk order
+fn find_skill_dirs(source: &Path) -> Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+
+    for entry in walk_source(source)? {
         let entry = entry.map_err(|e| SkillError::WalkError {
             path: source.to_path_buf(),
             source: e,
@@ -112,49 +190,54 @@ pub fn discover_in_directory(source: &Path) -> Result<Vec<Skill>> {
 
         if is_skill_file(&entry) {
             if let Some(skill_dir) = entry.path().parent() {
-                match Skill::from_directory(skill_dir) {
-                    Ok(skill) => skills.push(skill),
-                    Err(e) => {
-                        // Log error but continue discovering other skills
-                        eprintln!(
-                            "Warning: Failed to load skill from {}: {}",
-                            skill_dir.display(),
-                            e
-                        );
-                    }
-                }
+                dirs.push(skill_dir.to_path_buf());
             }
         }
     }
 
-    Ok(skills)
+    Ok(dirs)
 }
 
 /// Resolve a skill by name from source directories
 ///
-/// Searches sources in order and returns the first match.
-pub fn resolve(sources: &[PathBuf], name: &str) -> Result<Skill> {
+/// Searches sources in order and returns the first match. A prefixed source
+/// only matches names qualified with its prefix.
+pub fn resolve(sources: &[SkillSource], name: &str) -> Result<Skill> {
     for source in sources {
-        if let Some(skill) = find_in_directory(source, name)? {
-            return Ok(skill);
+        let local = match &source.prefix {
+            Some(prefix) => match name
+                .strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+            {
+                Some(rest) => rest,
+                None => continue,
+            },
+            None => name,
+        };
+
+        if let Some(skill) = find_in_directory(&source.path, local)? {
+            return Ok(qualify(skill, source.prefix.as_deref()));
         }
     }
 
     Err(SkillError::NotFound(name.to_string()).into())
 }
 
+/// Namespace a skill's name under its source prefix
+fn qualify(mut skill: Skill, prefix: Option<&str>) -> Skill {
+    if let Some(prefix) = prefix {
+        skill.name = format!("{}/{}", prefix, skill.name);
+    }
+    skill
+}
+
 /// Find a skill by name within a single source directory
 fn find_in_directory(source: &Path, name: &str) -> Result<Option<Skill>> {
     if !source.exists() {
         return Ok(None);
     }
 
-    let walker = WalkDir::new(source)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(is_not_hidden);
-
-    for entry in walker {
+    for entry in walk_source(source)? {
         let entry = entry.map_err(|e| SkillError::WalkError {
             path: source.to_path_buf(),
             source: e,
@@ -163,7 +246,7 @@ fn find_in_directory(source: &Path, name: &str) -> Result<Option<Skill>> {
         if is_skill_file(&entry) {
             if let Some(skill_dir) = entry.path().parent() {
                 if let Some(dir_name) = skill_dir.file_name().and_then(|n| n.to_str()) {
-                    if dir_name == name {
+                    if normalize_name(dir_name) == normalize_name(name) {
                         return Ok(Some(Skill::from_directory(skill_dir)?));
                     }
                 }
@@ -174,9 +257,192 @@ fn find_in_directory(source: &Path, name: &str) -> Result<Option<Skill>> {
     Ok(None)
 }
 
+/// Canonical form used to match skill names
+///
+/// Lowercases, trims, and collapses runs of `-`, `_` and whitespace into a
+/// single `-`, so `Build-Tool`, `build_tool` and `build  tool` all match
+/// `build-tool`. A source prefix (`prefix/name`) is normalized per segment.
+/// Only matching uses this form; display keeps the name as written.
+pub fn normalize_name(name: &str) -> String {
+    name.trim()
+        .split('/')
+        .map(|segment| {
+            segment
+                .split(|c: char| c == '-' || c == '_' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join("-")
+                .to_lowercase()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 /// Build a map of skill names to Skill objects
+///
+/// When sources define the same name, the first skill wins, matching
+/// [`resolve`]'s source order.
 pub fn build_skill_map(skills: Vec<Skill>) -> HashMap<String, Skill> {
-    skills.into_iter().map(|s| (s.name.clone(), s)).collect()
+    let mut skill_map = HashMap::new();
+    for skill in skills {
+        skill_map.entry(skill.name.clone()).or_insert(skill);
+    }
+    skill_map
+}
+
+/// Build a map from normalized alias (see [`normalize_name`]) to canonical skill name
+///
+/// Canonical names win over aliases, and the first skill declaring an alias
+/// wins over later ones.
+pub fn build_alias_map(skills: &[Skill]) -> HashMap<String, String> {
+    let mut aliases = HashMap::new();
+    for skill in skills {
+        for alias in skill.frontmatter.aliases.iter().flatten() {
+            aliases
+                .entry(normalize_name(alias))
+                .or_insert_with(|| skill.name.clone());
+        }
+    }
+    for skill in skills {
+        aliases.remove(&normalize_name(&skill.name));
+    }
+    aliases
+}
+
+/// Pipeline dependencies as `(dependent, dependency)` pairs
+///
+/// `after: [x]` makes the declaring skill depend on `x`; `before: [y]` makes
+/// `y` depend on the declaring skill. Pairs come in skill order (pipelines by
+/// name) without duplicates, and may name skills that don't exist.
+pub fn pipeline_edges(skills: &[Skill]) -> Vec<(String, String)> {
+    let mut edges = Vec::new();
+    let mut seen = HashSet::new();
+
+    for skill in skills {
+        let Some(pipeline) = &skill.frontmatter.pipeline else {
+            continue;
+        };
+        let mut names: Vec<&String> = pipeline.keys().collect();
+        names.sort();
+
+        for stage in names.into_iter().map(|name| &pipeline[name]) {
+            let after = stage.after.iter().flatten();
+            let before = stage.before.iter().flatten();
+            let pairs = after
+                .map(|dep| (skill.name.clone(), dep.clone()))
+                .chain(before.map(|dep| (dep.clone(), skill.name.clone())));
+
+            for pair in pairs {
+                if seen.insert(pair.clone()) {
+                    edges.push(pair);
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+/// Skills participating in a pipeline, with their stage, sorted by order
+///
+/// Skills sharing an order keep their discovery order. Empty when no skill
+/// declares the pipeline.
+pub fn pipeline_stages(skills: &[Skill], pipeline: &str) -> Vec<(String, PipelineStage)> {
+    let mut stages: Vec<(String, PipelineStage)> = skills
+        .iter()
+        .filter_map(|s| {
+            let stage = s.frontmatter.pipeline.as_ref()?.get(pipeline)?;
+            Some((s.name.clone(), stage.clone()))
+        })
+        .collect();
+    stages.sort_by_key(|(_, stage)| stage.order);
+    stages
+}
+
+/// Discover all skills and extract their cross-references in one call
+///
+/// Returns the skills in discovery order alongside a map from each referring
+/// skill's name to its references, with skills that reference nothing left
+/// out. The pair feeds directly into `SkillGraph::from_skills`. Plain paths
+/// convert with `.into()`. `detectors` is passed to `extract_all_references`.
+pub fn analyze(
+    sources: &[SkillSource],
+    detectors: &[Box<dyn ReferenceDetector>],
+) -> Result<(Vec<Skill>, CrossRefMap)> {
+    let skills = discover_all(sources)?;
+    let crossrefs = extract_all_references(&skills, detectors)?;
+    Ok((skills, crossrefs))
+}
+
+/// Read every skill's SKILL.md and extract its references to known skills
+///
+/// Skills without references are left out of the map. References come from
+/// `detectors` (see `builtin_detectors`). With the `parallel` feature, files
+/// are read and scanned on rayon's thread pool.
+pub fn extract_all_references(
+    skills: &[Skill],
+    detectors: &[Box<dyn ReferenceDetector>],
+) -> Result<HashMap<String, Vec<CrossRef>>> {
+    let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    let aliases = build_alias_map(skills);
+
+    let extract = |skill: &Skill| -> Result<(String, Vec<CrossRef>)> {
+        let content = skill.read_content()?;
+        let refs = extract_references_with_filter(
+            &content,
+            &skill.name,
+            Some(&known_skills),
+            Some(&aliases),
+            detectors,
+        );
+        Ok((skill.name.clone(), refs))
+    };
+
+    #[cfg(feature = "parallel")]
+    let extracted: Vec<(String, Vec<CrossRef>)> = {
+        use rayon::prelude::*;
+        skills.par_iter().map(extract).collect::<Result<_>>()?
+    };
+    #[cfg(not(feature = "parallel"))]
+    let extracted: Vec<(String, Vec<CrossRef>)> =
+        skills.iter().map(extract).collect::<Result<_>>()?;
+
+    Ok(extracted
+        .into_iter()
+        .filter(|(_, refs)| !refs.is_empty())
+        .collect())
+}
+
+/// Walk a source directory, skipping hidden entries and `.skillignore` matches
+///
+/// The source root may hold a `.skillignore` in gitignore syntax; matching
+/// directories are pruned before anything inside them is read.
+fn walk_source(source: &Path) -> Result<impl Iterator<Item = walkdir::Result<DirEntry>>> {
+    let ignore_file = source.join(SKILL_IGNORE_FILE_NAME);
+    let ignored = if ignore_file.is_file() {
+        let mut builder = GitignoreBuilder::new(source);
+        let invalid = |e| SkillError::IgnoreFile {
+            path: ignore_file.clone(),
+            source: e,
+        };
+        if let Some(e) = builder.add(&ignore_file) {
+            return Err(invalid(e).into());
+        }
+        builder.build().map_err(invalid)?
+    } else {
+        Gitignore::empty()
+    };
+
+    Ok(WalkDir::new(source)
+        .follow_links(true)
+        .into_iter()
+        .filter_entry(move |entry| {
+            is_not_hidden(entry)
+                && (entry.depth() == 0
+                    || !ignored
+                        .matched(entry.path(), entry.file_type().is_dir())
+                        .is_ignore())
+        }))
 }
 
 /// Check if a directory entry is a SKILL.md file
@@ -217,6 +483,26 @@ mod tests {
         assert!(skill.skill_file.ends_with("SKILL.md"));
     }
 
+    #[test]
+    fn should_load_skill_whose_name_differs_from_directory() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("foo-bar");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: foobar\ndescription: Mismatched name\n---\n",
+        )
+        .unwrap();
+
+        // When
+        let skill = Skill::from_directory(&skill_dir).unwrap();
+
+        // Then
+        assert_eq!(skill.name, "foobar");
+        assert_eq!(skill.path, skill_dir);
+    }
+
     #[test]
     fn should_return_error_when_directory_missing_skill_file() {
         // Given
@@ -266,8 +552,8 @@ mod tests {
     fn should_resolve_skill_from_first_matching_source() {
         // Given
         let sources = vec![
-            PathBuf::from("tests/fixtures/skills"),
-            PathBuf::from("tests/fixtures/other-skills"),
+            PathBuf::from("tests/fixtures/skills").into(),
+            PathBuf::from("tests/fixtures/other-skills").into(),
         ];
 
         // When
@@ -281,7 +567,7 @@ mod tests {
     #[test]
     fn should_return_error_when_skill_not_found() {
         // Given
-        let sources = vec![PathBuf::from("tests/fixtures/skills")];
+        let sources = vec![PathBuf::from("tests/fixtures/skills").into()];
 
         // When
         let result = resolve(&sources, "nonexistent-skill");
@@ -309,8 +595,8 @@ mod tests {
     fn should_discover_all_skills_from_multiple_sources() {
         // Given
         let sources = vec![
-            PathBuf::from("tests/fixtures/skills"),
-            PathBuf::from("/nonexistent/source"),
+            PathBuf::from("tests/fixtures/skills").into(),
+            PathBuf::from("/nonexistent/source").into(),
         ];
 
         // When
@@ -320,6 +606,84 @@ mod tests {
         assert!(skills.len() >= 3);
     }
 
+    // Helper to create a source directory holding a single `foo` skill
+    fn source_with_foo(root: &Path, dir: &str, prefix: &str) -> SkillSource {
+        let path = root.join(dir);
+        std::fs::create_dir_all(path.join("foo")).unwrap();
+        std::fs::write(
+            path.join("foo/SKILL.md"),
+            "---\nname: foo\ndescription: Test\n---\n",
+        )
+        .unwrap();
+        SkillSource {
+            path,
+            prefix: Some(prefix.to_string()),
+        }
+    }
+
+    #[test]
+    fn should_qualify_skill_names_with_source_prefix() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let sources = vec![
+            source_with_foo(temp.path(), "vendor-a", "a"),
+            source_with_foo(temp.path(), "vendor-b", "b"),
+        ];
+
+        // When
+        let skills = discover_all(&sources).unwrap();
+
+        // Then
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["a/foo", "b/foo"]);
+        assert_eq!(skills[0].frontmatter.name, "foo");
+    }
+
+    #[test]
+    fn should_skip_directories_matched_by_skillignore() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for dir in ["draft", "examples/demo"] {
+            let name = dir.rsplit('/').next().unwrap();
+            let dir = source.join(dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join(SKILL_FILE_NAME),
+                format!("---\nname: {}\ndescription: Test\n---\n", name),
+            )
+            .unwrap();
+        }
+        std::fs::write(source.join(SKILL_IGNORE_FILE_NAME), "examples/\n").unwrap();
+        let sources = vec![source.into()];
+
+        // When
+        let skills = discover_all(&sources).unwrap();
+
+        // Then
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["draft"]);
+        assert!(resolve(&sources, "demo").is_err());
+    }
+
+    #[test]
+    fn should_resolve_prefixed_name_in_matching_source() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let sources = vec![
+            source_with_foo(temp.path(), "vendor-a", "a"),
+            source_with_foo(temp.path(), "vendor-b", "b"),
+        ];
+
+        // When
+        let skill = resolve(&sources, "b/foo").unwrap();
+
+        // Then
+        assert_eq!(skill.name, "b/foo");
+        assert!(skill.path.starts_with(temp.path().join("vendor-b")));
+        assert!(resolve(&sources, "foo").is_err());
+    }
+
     #[test]
     fn should_build_skill_map() {
         // Given
@@ -336,6 +700,166 @@ mod tests {
         assert_eq!(skill_map.len(), 3);
     }
 
+    #[test]
+    fn should_keep_first_source_skill_in_skill_map() {
+        // Given: team and personal sources both defining shared-skill
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut sources: Vec<SkillSource> = Vec::new();
+        for source in ["team", "personal"] {
+            let skill_dir = temp.path().join(source).join("shared-skill");
+            fs::create_dir_all(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                "---\nname: shared-skill\ndescription: Shared skill\n---\n",
+            )
+            .unwrap();
+            sources.push(temp.path().join(source).into());
+        }
+
+        // When
+        let skill_map = build_skill_map(discover_all(&sources).unwrap());
+
+        // Then
+        assert_eq!(
+            skill_map["shared-skill"].path,
+            temp.path().join("team/shared-skill")
+        );
+        assert_eq!(
+            resolve(&sources, "shared-skill").unwrap().path,
+            skill_map["shared-skill"].path
+        );
+    }
+
+    #[test]
+    fn should_build_alias_map_from_frontmatter() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for (name, aliases) in [("blog-edit", "[Blog-Editor, draft]"), ("draft", "[]")] {
+            let dir = source.join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("SKILL.md"),
+                format!(
+                    "---\nname: {}\ndescription: Test\naliases: {}\n---\n",
+                    name, aliases
+                ),
+            )
+            .unwrap();
+        }
+        let skills = discover_in_directory(&source).unwrap();
+
+        // When
+        let aliases = build_alias_map(&skills);
+
+        // Then
+        assert_eq!(aliases.get("blog-editor"), Some(&"blog-edit".to_string()));
+        assert!(!aliases.contains_key("draft")); // canonical name wins
+    }
+
+    #[test]
+    fn should_normalize_case_and_separator_variants() {
+        // Given
+        let variants = [
+            "build-tool",
+            "Build-Tool",
+            " build_tool ",
+            "BUILD  tool",
+            "build--tool-",
+        ];
+
+        // When
+        let normalized: Vec<String> = variants.iter().map(|v| normalize_name(v)).collect();
+
+        // Then
+        assert!(normalized.iter().all(|n| n == "build-tool"));
+        assert_eq!(normalize_name("Vendor/Build_Tool"), "vendor/build-tool");
+    }
+
+    #[test]
+    fn should_find_skill_directory_by_variant_name() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        let dir = source.join("build-tool");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            "---\nname: build-tool\ndescription: Test\n---\n",
+        )
+        .unwrap();
+
+        // When
+        let skill = find_in_directory(&source, "Build_Tool").unwrap();
+
+        // Then
+        assert_eq!(skill.map(|s| s.name), Some("build-tool".to_string()));
+    }
+
+    #[test]
+    fn should_extract_references_for_every_skill_with_any() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for (name, body) in [
+            ("draft", "Invoke the edit skill next."),
+            (
+                "edit",
+                "Use the `draft` skill first, then load publish first.",
+            ),
+            ("publish", "No references here."),
+        ] {
+            let dir = source.join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test\n---\n{}\n", name, body),
+            )
+            .unwrap();
+        }
+        let skills = discover_in_directory(&source).unwrap();
+
+        // When
+        let crossrefs = extract_all_references(&skills, &builtin_detectors(None)).unwrap();
+
+        // Then
+        assert_eq!(crossrefs.len(), 2);
+        let targets = |name: &str| -> Vec<String> {
+            crossrefs[name].iter().map(|r| r.target.clone()).collect()
+        };
+        assert_eq!(targets("draft"), vec!["edit"]);
+        assert_eq!(targets("edit"), vec!["draft", "publish"]);
+    }
+
+    #[test]
+    fn should_analyze_sources_into_skills_and_references() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for (name, body) in [
+            ("draft", "<see ref=\"edit\">next</see>"),
+            ("edit", "No references here."),
+        ] {
+            let dir = source.join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test\n---\n{}\n", name, body),
+            )
+            .unwrap();
+        }
+
+        // When
+        let (skills, crossrefs) = analyze(&[source.into()], &builtin_detectors(None)).unwrap();
+
+        // Then
+        let mut names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["draft", "edit"]);
+        assert_eq!(crossrefs.len(), 1);
+        assert_eq!(crossrefs["draft"][0].target, "edit");
+    }
+
     #[test]
     fn should_find_skill_by_name_in_directory() {
         // Given
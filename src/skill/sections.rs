@@ -0,0 +1,73 @@
+//! Markdown section parsing for SKILL.md bodies
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+/// Split Markdown into `(heading_level, heading_text, body)` sections
+///
+/// A section's body runs from its heading to the next heading of any level,
+/// trimmed. Text before the first heading belongs to no section.
+pub fn parse_sections(markdown: &str) -> Vec<(u8, String, String)> {
+    let mut sections = Vec::new();
+    // Heading level, text, and where its body starts
+    let mut open: Option<(u8, String, usize)> = None;
+    let mut heading: Option<(u8, String)> = None;
+
+    for (event, range) in Parser::new(markdown).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                if let Some((level, text, start)) = open.take() {
+                    let body = markdown[start..range.start].trim().to_string();
+                    sections.push((level, text, body));
+                }
+                heading = Some((level as u8, String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, heading_text)) = &mut heading {
+                    heading_text.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, text)) = heading.take() {
+                    open = Some((level, text.trim().to_string(), range.end));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((level, text, start)) = open {
+        sections.push((level, text, markdown[start..].trim().to_string()));
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_two_headings_with_bodies() {
+        // Given
+        let markdown =
+            "Intro text\n\n# Draft `posts`\n\nWrite it.\n\n## Usage\n\nRun it.\nTwice.\n";
+
+        // When
+        let sections = parse_sections(markdown);
+
+        // Then
+        assert_eq!(
+            sections,
+            vec![
+                (1, "Draft posts".to_string(), "Write it.".to_string()),
+                (2, "Usage".to_string(), "Run it.\nTwice.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_return_no_sections_without_headings() {
+        // Given/When/Then
+        assert!(parse_sections("Just a paragraph.\n").is_empty());
+    }
+}